@@ -0,0 +1,900 @@
+use crate::error::AvsRegistryError;
+use alloy_contract::{CallBuilder, CallDecoder};
+use alloy_network::{EthereumWallet, TxSigner};
+use alloy_primitives::{Address, Bytes, U256};
+use alloy_provider::Provider;
+use alloy_signer::Signature;
+use alloy_signer_local::PrivateKeySigner;
+use alloy_sol_types::SolEventInterface;
+use alloy_transport::Transport;
+use eigen_chainio_txmanager::{
+    simple_tx_manager::SimpleTxManager,
+    tx_manager::{TxManager, TxOutcome},
+};
+use eigen_logging::logger::SharedLogger;
+use eigen_types::operator::StrategyParams;
+use eigen_utils::binding::RegistryCoordinator::{
+    self, OperatorSetParam, RegistryCoordinatorEvents,
+};
+use eigen_utils::binding::ServiceManagerBase::ServiceManagerBaseEvents;
+use eigen_utils::binding::StakeRegistry::StakeRegistryEvents;
+use eigen_utils::{
+    binding::{IPauserRegistry, ServiceManagerBase, StakeRegistry},
+    get_shared_provider, SharedProvider,
+};
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::info;
+
+/// AvsRegistry admin writer, used by the owner, ejector and pausers of an AVS to manage its
+/// registry coordinator, stake registry and service manager.
+///
+/// Every transaction is simulated before being sent, and the role of the signer is checked
+/// beforehand so that a missing role surfaces as a clear error instead of a revert. The
+/// transactions are then sent through a [`TxManager`], and the writes return once mined.
+#[derive(Debug)]
+pub struct AvsRegistryAdminWriter {
+    logger: SharedLogger,
+    service_manager_addr: Address,
+    registry_coordinator_addr: Address,
+    stake_registry_addr: Address,
+    provider: SharedProvider,
+    /// Signs and sends the transactions
+    tx_manager: Arc<dyn TxManager>,
+}
+
+impl AvsRegistryAdminWriter {
+    /// build avs registry admin writer instance
+    ///
+    /// # Arguments
+    ///
+    /// * `logger` - SharedLogger used for logging
+    /// * `provider` - provider string
+    /// * `signer` - private key of the admin
+    /// * `registry_coordinator_addr` - registry coordinator address
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self, AvsRegistryError>` - a new AvsRegistryAdminWriter
+    ///
+    /// # Errors
+    ///
+    /// * `AvsRegistryError` - if the private key or the rpc url is invalid or the addresses of
+    ///   the registry contracts can't be fetched
+    pub async fn build_avs_registry_admin_writer(
+        logger: SharedLogger,
+        provider: String,
+        signer: String,
+        registry_coordinator_addr: Address,
+    ) -> Result<Self, AvsRegistryError> {
+        let signer =
+            PrivateKeySigner::from_str(&signer).map_err(|_| AvsRegistryError::InvalidPrivateKey)?;
+        Self::build_avs_registry_admin_writer_with_signer(
+            logger,
+            provider,
+            signer,
+            registry_coordinator_addr,
+        )
+        .await
+    }
+
+    /// build avs registry admin writer instance signing its transactions with any
+    /// [`TxSigner`], e.g. an AWS KMS key or a Web3Signer
+    ///
+    /// # Arguments
+    ///
+    /// * `logger` - SharedLogger used for logging
+    /// * `provider` - provider string
+    /// * `signer` - signer of the admin transactions
+    /// * `registry_coordinator_addr` - registry coordinator address
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self, AvsRegistryError>` - a new AvsRegistryAdminWriter
+    ///
+    /// # Errors
+    ///
+    /// * `AvsRegistryError` - if the rpc url is invalid or the addresses of the registry
+    ///   contracts can't be fetched
+    pub async fn build_avs_registry_admin_writer_with_signer<S>(
+        logger: SharedLogger,
+        provider: String,
+        signer: S,
+        registry_coordinator_addr: Address,
+    ) -> Result<Self, AvsRegistryError>
+    where
+        S: TxSigner<Signature> + Send + Sync + 'static,
+    {
+        Self::build_avs_registry_admin_writer_with_provider(
+            logger,
+            get_shared_provider(&provider)
+                .map_err(|_| AvsRegistryError::InvalidRpcUrl(provider.clone()))?,
+            signer,
+            registry_coordinator_addr,
+        )
+        .await
+    }
+
+    /// build avs registry admin writer instance sending its transactions through `provider`
+    ///
+    /// # Arguments
+    ///
+    /// * `logger` - SharedLogger used for logging
+    /// * `provider` - provider, shared with the other clients built with it
+    /// * `signer` - signer of the admin transactions
+    /// * `registry_coordinator_addr` - registry coordinator address
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self, AvsRegistryError>` - a new AvsRegistryAdminWriter
+    ///
+    /// # Errors
+    ///
+    /// * `AvsRegistryError` - if the addresses of the registry contracts can't be fetched
+    pub async fn build_avs_registry_admin_writer_with_provider<S>(
+        logger: SharedLogger,
+        provider: SharedProvider,
+        signer: S,
+        registry_coordinator_addr: Address,
+    ) -> Result<Self, AvsRegistryError>
+    where
+        S: TxSigner<Signature> + Send + Sync + 'static,
+    {
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(registry_coordinator_addr, &provider);
+
+        let RegistryCoordinator::serviceManagerReturn {
+            _0: service_manager,
+        } = contract_registry_coordinator
+            .serviceManager()
+            .call()
+            .await
+            .map_err(AvsRegistryError::revert("RegistryCoordinator"))?;
+        let RegistryCoordinator::stakeRegistryReturn { _0: stake_registry } =
+            contract_registry_coordinator
+                .stakeRegistry()
+                .call()
+                .await
+                .map_err(AvsRegistryError::revert("RegistryCoordinator"))?;
+
+        let tx_manager = SimpleTxManager::new_with_provider(
+            logger.clone(),
+            1.0,
+            EthereumWallet::from(signer),
+            provider.clone(),
+        );
+
+        Ok(AvsRegistryAdminWriter {
+            logger,
+            service_manager_addr: service_manager,
+            registry_coordinator_addr,
+            stake_registry_addr: stake_registry,
+            provider,
+            tx_manager: Arc::new(tx_manager),
+        })
+    }
+
+    /// Send the transactions through `tx_manager` instead of the default [`SimpleTxManager`]
+    ///
+    /// The roles are checked against the sender of `tx_manager`.
+    pub fn with_tx_manager(mut self, tx_manager: Arc<dyn TxManager>) -> Self {
+        self.tx_manager = tx_manager;
+        self
+    }
+
+    /// Get the address of the admin signing the transactions
+    pub fn signer_address(&self) -> Address {
+        self.tx_manager.sender()
+    }
+
+    /// Create a new quorum
+    ///
+    /// # Arguments
+    ///
+    /// * `operator_set_params` - The operator set params of the quorum
+    /// * `minimum_stake` - The minimum stake an operator needs to register for the quorum
+    /// * `strategy_params` - The strategies whose shares count towards the stake of the quorum
+    ///
+    /// # Returns
+    ///
+    /// * `TxOutcome<RegistryCoordinatorEvents>` - The receipt and events of the mined create quorum transaction
+    pub async fn create_quorum(
+        &self,
+        operator_set_params: OperatorSetParam,
+        minimum_stake: U256,
+        strategy_params: Vec<StrategyParams>,
+    ) -> Result<TxOutcome<RegistryCoordinatorEvents>, AvsRegistryError> {
+        info!(minimum_stake = %minimum_stake, "creating quorum");
+        self.ensure_registry_coordinator_owner().await?;
+        let minimum_stake = to_uint96(minimum_stake)?;
+        let strategy_params = strategy_params
+            .into_iter()
+            .map(|params| {
+                Ok(RegistryCoordinator::StrategyParams {
+                    strategy: params.strategy,
+                    multiplier: to_uint96(params.multiplier)?,
+                })
+            })
+            .collect::<Result<Vec<_>, AvsRegistryError>>()?;
+
+        let provider = &self.provider;
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let contract_call = contract_registry_coordinator.createQuorum(
+            operator_set_params,
+            minimum_stake,
+            strategy_params,
+        );
+        let outcome = self
+            .send_tx(
+                contract_call,
+                self.registry_coordinator_addr,
+                "RegistryCoordinator",
+            )
+            .await?;
+
+        info!(tx_hash = %outcome.tx_hash(), "successfully created quorum");
+        Ok(outcome)
+    }
+
+    /// Set the operator set params of a quorum
+    ///
+    /// # Arguments
+    ///
+    /// * `quorum_number` - The quorum number
+    /// * `operator_set_params` - The new operator set params
+    ///
+    /// # Returns
+    ///
+    /// * `TxOutcome<RegistryCoordinatorEvents>` - The receipt and events of the mined set operator set params transaction
+    pub async fn set_operator_set_params(
+        &self,
+        quorum_number: u8,
+        operator_set_params: OperatorSetParam,
+    ) -> Result<TxOutcome<RegistryCoordinatorEvents>, AvsRegistryError> {
+        info!(quorum_number, "setting operator set params");
+        self.ensure_registry_coordinator_owner().await?;
+
+        let provider = &self.provider;
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let contract_call =
+            contract_registry_coordinator.setOperatorSetParams(quorum_number, operator_set_params);
+        let outcome = self
+            .send_tx(
+                contract_call,
+                self.registry_coordinator_addr,
+                "RegistryCoordinator",
+            )
+            .await?;
+
+        info!(tx_hash = %outcome.tx_hash(), quorum_number, "successfully set operator set params");
+        Ok(outcome)
+    }
+
+    /// Set the churn approver, whose signature is required to register with churn
+    ///
+    /// # Arguments
+    ///
+    /// * `churn_approver` - The address of the new churn approver
+    ///
+    /// # Returns
+    ///
+    /// * `TxOutcome<RegistryCoordinatorEvents>` - The receipt and events of the mined set churn approver transaction
+    pub async fn set_churn_approver(
+        &self,
+        churn_approver: Address,
+    ) -> Result<TxOutcome<RegistryCoordinatorEvents>, AvsRegistryError> {
+        info!(churn_approver = %churn_approver, "setting churn approver");
+        self.ensure_registry_coordinator_owner().await?;
+
+        let provider = &self.provider;
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let contract_call = contract_registry_coordinator.setChurnApprover(churn_approver);
+        let outcome = self
+            .send_tx(
+                contract_call,
+                self.registry_coordinator_addr,
+                "RegistryCoordinator",
+            )
+            .await?;
+
+        info!(tx_hash = %outcome.tx_hash(), churn_approver = %churn_approver, "successfully set churn approver");
+        Ok(outcome)
+    }
+
+    /// Set the ejector, allowed to eject operators from quorums
+    ///
+    /// # Arguments
+    ///
+    /// * `ejector` - The address of the new ejector
+    ///
+    /// # Returns
+    ///
+    /// * `TxOutcome<RegistryCoordinatorEvents>` - The receipt and events of the mined set ejector transaction
+    pub async fn set_ejector(
+        &self,
+        ejector: Address,
+    ) -> Result<TxOutcome<RegistryCoordinatorEvents>, AvsRegistryError> {
+        info!(ejector = %ejector, "setting ejector");
+        self.ensure_registry_coordinator_owner().await?;
+
+        let provider = &self.provider;
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let contract_call = contract_registry_coordinator.setEjector(ejector);
+        let outcome = self
+            .send_tx(
+                contract_call,
+                self.registry_coordinator_addr,
+                "RegistryCoordinator",
+            )
+            .await?;
+
+        info!(tx_hash = %outcome.tx_hash(), ejector = %ejector, "successfully set ejector");
+        Ok(outcome)
+    }
+
+    /// Eject an operator from quorums. The signer must be the ejector.
+    ///
+    /// # Arguments
+    ///
+    /// * `operator` - The address of the operator to eject
+    /// * `quorum_numbers` - The quorums to eject the operator from
+    ///
+    /// # Returns
+    ///
+    /// * `TxOutcome<RegistryCoordinatorEvents>` - The receipt and events of the mined eject operator transaction
+    pub async fn eject_operator(
+        &self,
+        operator: Address,
+        quorum_numbers: Bytes,
+    ) -> Result<TxOutcome<RegistryCoordinatorEvents>, AvsRegistryError> {
+        info!(operator = %operator, quorum_numbers = %quorum_numbers, "ejecting operator");
+        let provider = &self.provider;
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+
+        let RegistryCoordinator::ejectorReturn { _0: ejector } = contract_registry_coordinator
+            .ejector()
+            .call()
+            .await
+            .map_err(AvsRegistryError::revert_or(
+                "RegistryCoordinator",
+                AvsRegistryError::GetEjector,
+            ))?;
+        if ejector != self.signer_address() {
+            return Err(AvsRegistryError::NotEjector(self.signer_address()));
+        }
+
+        let contract_call =
+            contract_registry_coordinator.ejectOperator(operator, quorum_numbers.clone());
+        let outcome = self
+            .send_tx(
+                contract_call,
+                self.registry_coordinator_addr,
+                "RegistryCoordinator",
+            )
+            .await?;
+
+        info!(tx_hash = %outcome.tx_hash(), operator = %operator, quorum_numbers = %quorum_numbers, "successfully ejected operator");
+        Ok(outcome)
+    }
+
+    /// Pause the functionalities of the registry coordinator flagged in `new_paused_status`.
+    /// The signer must be a pauser.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_paused_status` - The new paused status bitmap, it must keep the currently paused
+    ///   bits set
+    ///
+    /// # Returns
+    ///
+    /// * `TxOutcome<RegistryCoordinatorEvents>` - The receipt and events of the mined pause transaction
+    pub async fn pause(
+        &self,
+        new_paused_status: U256,
+    ) -> Result<TxOutcome<RegistryCoordinatorEvents>, AvsRegistryError> {
+        info!(new_paused_status = %new_paused_status, "pausing registry coordinator");
+        self.ensure_pauser().await?;
+
+        let provider = &self.provider;
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let contract_call = contract_registry_coordinator.pause(new_paused_status);
+        let outcome = self
+            .send_tx(
+                contract_call,
+                self.registry_coordinator_addr,
+                "RegistryCoordinator",
+            )
+            .await?;
+
+        info!(tx_hash = %outcome.tx_hash(), "successfully paused registry coordinator");
+        Ok(outcome)
+    }
+
+    /// Pause all the functionalities of the registry coordinator. The signer must be a pauser.
+    ///
+    /// # Returns
+    ///
+    /// * `TxOutcome<RegistryCoordinatorEvents>` - The receipt and events of the mined pause all transaction
+    pub async fn pause_all(
+        &self,
+    ) -> Result<TxOutcome<RegistryCoordinatorEvents>, AvsRegistryError> {
+        info!("pausing all registry coordinator functionalities");
+        self.ensure_pauser().await?;
+
+        let provider = &self.provider;
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let contract_call = contract_registry_coordinator.pauseAll();
+        let outcome = self
+            .send_tx(
+                contract_call,
+                self.registry_coordinator_addr,
+                "RegistryCoordinator",
+            )
+            .await?;
+
+        info!(tx_hash = %outcome.tx_hash(), "successfully paused all registry coordinator functionalities");
+        Ok(outcome)
+    }
+
+    /// Unpause the functionalities of the registry coordinator cleared in `new_paused_status`.
+    /// The signer must be the unpauser.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_paused_status` - The new paused status bitmap, it must only clear currently paused
+    ///   bits
+    ///
+    /// # Returns
+    ///
+    /// * `TxOutcome<RegistryCoordinatorEvents>` - The receipt and events of the mined unpause transaction
+    pub async fn unpause(
+        &self,
+        new_paused_status: U256,
+    ) -> Result<TxOutcome<RegistryCoordinatorEvents>, AvsRegistryError> {
+        info!(new_paused_status = %new_paused_status, "unpausing registry coordinator");
+        let provider = &self.provider;
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+
+        let pauser_registry =
+            IPauserRegistry::new(self.pauser_registry_addr().await?, &self.provider);
+        let IPauserRegistry::unpauserReturn { _0: unpauser } = pauser_registry
+            .unpauser()
+            .call()
+            .await
+            .map_err(AvsRegistryError::revert_or(
+                "IPauserRegistry",
+                AvsRegistryError::GetUnpauser,
+            ))?;
+        if unpauser != self.signer_address() {
+            return Err(AvsRegistryError::NotUnpauser(self.signer_address()));
+        }
+
+        let contract_call = contract_registry_coordinator.unpause(new_paused_status);
+        let outcome = self
+            .send_tx(
+                contract_call,
+                self.registry_coordinator_addr,
+                "RegistryCoordinator",
+            )
+            .await?;
+
+        info!(tx_hash = %outcome.tx_hash(), "successfully unpaused registry coordinator");
+        Ok(outcome)
+    }
+
+    /// Set the minimum stake an operator needs to register for a quorum
+    ///
+    /// # Arguments
+    ///
+    /// * `quorum_number` - The quorum number
+    /// * `minimum_stake` - The new minimum stake
+    ///
+    /// # Returns
+    ///
+    /// * `TxOutcome<StakeRegistryEvents>` - The receipt and events of the mined set minimum stake transaction
+    pub async fn set_minimum_stake_for_quorum(
+        &self,
+        quorum_number: u8,
+        minimum_stake: U256,
+    ) -> Result<TxOutcome<StakeRegistryEvents>, AvsRegistryError> {
+        info!(quorum_number, minimum_stake = %minimum_stake, "setting minimum stake for quorum");
+        self.ensure_registry_coordinator_owner().await?;
+        let minimum_stake = to_uint96(minimum_stake)?;
+
+        let provider = &self.provider;
+        let contract_stake_registry = StakeRegistry::new(self.stake_registry_addr, provider);
+        let contract_call =
+            contract_stake_registry.setMinimumStakeForQuorum(quorum_number, minimum_stake);
+        let outcome = self
+            .send_tx(contract_call, self.stake_registry_addr, "StakeRegistry")
+            .await?;
+
+        info!(tx_hash = %outcome.tx_hash(), quorum_number, "successfully set minimum stake for quorum");
+        Ok(outcome)
+    }
+
+    /// Add strategies to a quorum
+    ///
+    /// # Arguments
+    ///
+    /// * `quorum_number` - The quorum number
+    /// * `strategy_params` - The strategies to add, along with their multipliers
+    ///
+    /// # Returns
+    ///
+    /// * `TxOutcome<StakeRegistryEvents>` - The receipt and events of the mined add strategies transaction
+    pub async fn add_strategies(
+        &self,
+        quorum_number: u8,
+        strategy_params: Vec<StrategyParams>,
+    ) -> Result<TxOutcome<StakeRegistryEvents>, AvsRegistryError> {
+        info!(quorum_number, strategy_params = ?strategy_params, "adding strategies to quorum");
+        self.ensure_registry_coordinator_owner().await?;
+        let strategy_params = strategy_params
+            .into_iter()
+            .map(|params| {
+                Ok(StakeRegistry::StrategyParams {
+                    strategy: params.strategy,
+                    multiplier: to_uint96(params.multiplier)?,
+                })
+            })
+            .collect::<Result<Vec<_>, AvsRegistryError>>()?;
+
+        let provider = &self.provider;
+        let contract_stake_registry = StakeRegistry::new(self.stake_registry_addr, provider);
+        let contract_call = contract_stake_registry.addStrategies(quorum_number, strategy_params);
+        let outcome = self
+            .send_tx(contract_call, self.stake_registry_addr, "StakeRegistry")
+            .await?;
+
+        info!(tx_hash = %outcome.tx_hash(), quorum_number, "successfully added strategies to quorum");
+        Ok(outcome)
+    }
+
+    /// Remove strategies from a quorum
+    ///
+    /// # Arguments
+    ///
+    /// * `quorum_number` - The quorum number
+    /// * `indices_to_remove` - The indices of the strategies to remove in the strategy params of
+    ///   the quorum
+    ///
+    /// # Returns
+    ///
+    /// * `TxOutcome<StakeRegistryEvents>` - The receipt and events of the mined remove strategies transaction
+    pub async fn remove_strategies(
+        &self,
+        quorum_number: u8,
+        indices_to_remove: Vec<U256>,
+    ) -> Result<TxOutcome<StakeRegistryEvents>, AvsRegistryError> {
+        info!(quorum_number, indices_to_remove = ?indices_to_remove, "removing strategies from quorum");
+        self.ensure_registry_coordinator_owner().await?;
+
+        let provider = &self.provider;
+        let contract_stake_registry = StakeRegistry::new(self.stake_registry_addr, provider);
+        let contract_call =
+            contract_stake_registry.removeStrategies(quorum_number, indices_to_remove);
+        let outcome = self
+            .send_tx(contract_call, self.stake_registry_addr, "StakeRegistry")
+            .await?;
+
+        info!(tx_hash = %outcome.tx_hash(), quorum_number, "successfully removed strategies from quorum");
+        Ok(outcome)
+    }
+
+    /// Modify the multipliers of strategies of a quorum
+    ///
+    /// # Arguments
+    ///
+    /// * `quorum_number` - The quorum number
+    /// * `strategy_indices` - The indices of the strategies to modify in the strategy params of
+    ///   the quorum
+    /// * `new_multipliers` - The new multipliers, one per strategy index
+    ///
+    /// # Returns
+    ///
+    /// * `TxOutcome<StakeRegistryEvents>` - The receipt and events of the mined modify strategy params transaction
+    pub async fn modify_strategy_params(
+        &self,
+        quorum_number: u8,
+        strategy_indices: Vec<U256>,
+        new_multipliers: Vec<U256>,
+    ) -> Result<TxOutcome<StakeRegistryEvents>, AvsRegistryError> {
+        info!(quorum_number, strategy_indices = ?strategy_indices, "modifying strategy params of quorum");
+        self.ensure_registry_coordinator_owner().await?;
+        let new_multipliers = new_multipliers
+            .into_iter()
+            .map(to_uint96)
+            .collect::<Result<Vec<_>, AvsRegistryError>>()?;
+
+        let provider = &self.provider;
+        let contract_stake_registry = StakeRegistry::new(self.stake_registry_addr, provider);
+        let contract_call = contract_stake_registry.modifyStrategyParams(
+            quorum_number,
+            strategy_indices,
+            new_multipliers,
+        );
+        let outcome = self
+            .send_tx(contract_call, self.stake_registry_addr, "StakeRegistry")
+            .await?;
+
+        info!(tx_hash = %outcome.tx_hash(), quorum_number, "successfully modified strategy params of quorum");
+        Ok(outcome)
+    }
+
+    /// Update the metadata URI of the AVS on the AVS directory
+    ///
+    /// # Arguments
+    ///
+    /// * `metadata_uri` - The new metadata URI
+    ///
+    /// # Returns
+    ///
+    /// * `TxOutcome<ServiceManagerBaseEvents>` - The receipt and events of the mined update AVS metadata URI transaction
+    pub async fn update_avs_metadata_uri(
+        &self,
+        metadata_uri: String,
+    ) -> Result<TxOutcome<ServiceManagerBaseEvents>, AvsRegistryError> {
+        info!(metadata_uri, "updating avs metadata uri");
+        let provider = &self.provider;
+        let contract_service_manager = ServiceManagerBase::new(self.service_manager_addr, provider);
+
+        let ServiceManagerBase::ownerReturn { _0: owner } = contract_service_manager
+            .owner()
+            .call()
+            .await
+            .map_err(AvsRegistryError::revert_or(
+                "ServiceManagerBase",
+                AvsRegistryError::GetOwner,
+            ))?;
+        if owner != self.signer_address() {
+            return Err(AvsRegistryError::NotServiceManagerOwner(
+                self.signer_address(),
+            ));
+        }
+
+        let contract_call = contract_service_manager.updateAVSMetadataURI(metadata_uri);
+        let outcome = self
+            .send_tx(
+                contract_call,
+                self.service_manager_addr,
+                "ServiceManagerBase",
+            )
+            .await?;
+
+        info!(tx_hash = %outcome.tx_hash(), "successfully updated avs metadata uri");
+        Ok(outcome)
+    }
+
+    /// Simulate a transaction from the signer, so that a revert surfaces before anything is
+    /// sent, then send it through the tx manager and wait for it to be mined
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_call` - the call to send
+    /// * `contract_addr` - the called contract, whose events are decoded from the receipt
+    /// * `contract` - name of the called contract, to decode its revert reasons
+    ///
+    /// # Errors
+    ///
+    /// * `AvsRegistryError::ContractReverted` or `AvsRegistryError::SimulateTransaction` - if
+    ///   the simulation fails
+    /// * `AvsRegistryError::TxReverted` - if the transaction reverted
+    async fn send_tx<E, T, P, D>(
+        &self,
+        contract_call: CallBuilder<T, P, D>,
+        contract_addr: Address,
+        contract: &'static str,
+    ) -> Result<TxOutcome<E>, AvsRegistryError>
+    where
+        E: SolEventInterface,
+        T: Transport + Clone,
+        P: Provider<T>,
+        D: CallDecoder + Send + Sync + Unpin,
+    {
+        let contract_call = contract_call.from(self.signer_address());
+        contract_call
+            .call()
+            .await
+            .map_err(AvsRegistryError::revert_or_else(
+                contract,
+                AvsRegistryError::SimulateTransaction,
+            ))?;
+        let receipt = self
+            .tx_manager
+            .send_tx(&mut contract_call.into_transaction_request())
+            .await
+            .map_err(AvsRegistryError::tx_revert(contract))?;
+        if !receipt.status() {
+            return Err(AvsRegistryError::TxReverted(receipt.transaction_hash));
+        }
+        Ok(TxOutcome::new(receipt, contract_addr))
+    }
+
+    /// Check the signer owns the registry coordinator, which also owns the stake registry
+    /// configuration
+    async fn ensure_registry_coordinator_owner(&self) -> Result<(), AvsRegistryError> {
+        let provider = &self.provider;
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let RegistryCoordinator::ownerReturn { _0: owner } =
+            contract_registry_coordinator.owner().call().await.map_err(
+                AvsRegistryError::revert_or("RegistryCoordinator", AvsRegistryError::GetOwner),
+            )?;
+        if owner != self.signer_address() {
+            self.logger.error(
+                &format!(
+                    "signer {} is not the registry coordinator owner {}",
+                    self.signer_address(),
+                    owner
+                ),
+                "eigen-client-avsregistry.admin.ensure_registry_coordinator_owner",
+            );
+            return Err(AvsRegistryError::NotRegistryCoordinatorOwner(
+                self.signer_address(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check the signer is a pauser of the registry coordinator
+    async fn ensure_pauser(&self) -> Result<(), AvsRegistryError> {
+        let pauser_registry =
+            IPauserRegistry::new(self.pauser_registry_addr().await?, &self.provider);
+        let IPauserRegistry::isPauserReturn { _0: is_pauser } = pauser_registry
+            .isPauser(self.signer_address())
+            .call()
+            .await
+            .map_err(AvsRegistryError::revert_or(
+                "IPauserRegistry",
+                AvsRegistryError::IsPauser,
+            ))?;
+        if !is_pauser {
+            return Err(AvsRegistryError::NotPauser(self.signer_address()));
+        }
+        Ok(())
+    }
+
+    /// Get the address of the pauser registry of the registry coordinator
+    async fn pauser_registry_addr(&self) -> Result<Address, AvsRegistryError> {
+        let provider = &self.provider;
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let RegistryCoordinator::pauserRegistryReturn {
+            _0: pauser_registry,
+        } = contract_registry_coordinator
+            .pauserRegistry()
+            .call()
+            .await
+            .map_err(AvsRegistryError::revert_or(
+                "RegistryCoordinator",
+                AvsRegistryError::GetPauserRegistry,
+            ))?;
+        Ok(pauser_registry)
+    }
+}
+
+/// Convert a value to a solidity `uint96`, the type of stakes and strategy multipliers
+fn to_uint96(value: U256) -> Result<u128, AvsRegistryError> {
+    if value.bit_len() > 96 {
+        return Err(AvsRegistryError::Uint96Overflow(value));
+    }
+    Ok(value.to::<u128>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::AvsRegistryChainReader;
+    use eigen_logging::get_test_logger;
+    use eigen_testing_utils::anvil_constants::{
+        get_operator_state_retriever_address, get_registry_coordinator_address, ANVIL_HTTP_URL,
+    };
+    use eigen_testing_utils::transaction::get_transaction_status;
+
+    /// Deployer of the anvil contracts, owner of the registry coordinator
+    const OWNER_PRIVATE_KEY: &str =
+        "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+    async fn build_avs_registry_admin_writer(private_key: &str) -> AvsRegistryAdminWriter {
+        AvsRegistryAdminWriter::build_avs_registry_admin_writer(
+            get_test_logger(),
+            ANVIL_HTTP_URL.to_string(),
+            private_key.to_string(),
+            get_registry_coordinator_address().await,
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_build_with_invalid_rpc_url() {
+        let result = AvsRegistryAdminWriter::build_avs_registry_admin_writer(
+            get_test_logger(),
+            "not a url".to_string(),
+            OWNER_PRIVATE_KEY.to_string(),
+            Address::ZERO,
+        )
+        .await;
+
+        assert!(matches!(result, Err(AvsRegistryError::InvalidRpcUrl(url)) if url == "not a url"));
+    }
+
+    #[test]
+    fn test_to_uint96() {
+        assert_eq!(to_uint96(U256::from(42)).unwrap(), 42);
+        let max = (U256::from(1) << 96) - U256::from(1);
+        assert_eq!(to_uint96(max).unwrap(), (1u128 << 96) - 1);
+        assert!(matches!(
+            to_uint96(U256::from(1) << 96),
+            Err(AvsRegistryError::Uint96Overflow(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin_writer_methods() {
+        let admin_writer = build_avs_registry_admin_writer(OWNER_PRIVATE_KEY).await;
+        let avs_reader = AvsRegistryChainReader::new(
+            get_test_logger(),
+            get_registry_coordinator_address().await,
+            get_operator_state_retriever_address().await,
+            ANVIL_HTTP_URL.to_string(),
+        )
+        .await
+        .unwrap();
+
+        let operator_set_params = avs_reader.get_operator_set_params(0).await.unwrap();
+        let outcome = admin_writer
+            .set_operator_set_params(0, operator_set_params)
+            .await
+            .unwrap();
+        assert!(get_transaction_status(outcome.tx_hash()).await);
+        assert!(outcome.events.iter().any(|event| matches!(
+            event,
+            RegistryCoordinatorEvents::OperatorSetParamsUpdated(updated) if updated.quorumNumber == 0
+        )));
+
+        let outcome = admin_writer
+            .set_ejector(admin_writer.signer_address())
+            .await
+            .unwrap();
+        assert!(get_transaction_status(outcome.tx_hash()).await);
+        assert!(outcome.events.iter().any(|event| matches!(
+            event,
+            RegistryCoordinatorEvents::EjectorUpdated(updated)
+                if updated.newEjector == admin_writer.signer_address()
+        )));
+
+        let outcome = admin_writer
+            .update_avs_metadata_uri("https://example.com/metadata.json".to_string())
+            .await
+            .unwrap();
+        assert!(get_transaction_status(outcome.tx_hash()).await);
+    }
+
+    #[tokio::test]
+    async fn test_admin_writer_rejects_non_owner() {
+        let admin_writer = build_avs_registry_admin_writer(
+            "59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d",
+        )
+        .await;
+
+        let result = admin_writer.set_ejector(Address::ZERO).await;
+        assert!(matches!(
+            result,
+            Err(AvsRegistryError::NotRegistryCoordinatorOwner(signer)) if signer == admin_writer.signer_address()
+        ));
+        let result = admin_writer
+            .eject_operator(Address::ZERO, Bytes::from([0]))
+            .await;
+        assert!(matches!(result, Err(AvsRegistryError::NotEjector(_))));
+    }
+}
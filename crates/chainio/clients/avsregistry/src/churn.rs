@@ -0,0 +1,367 @@
+use crate::error::AvsRegistryError;
+use alloy_primitives::{keccak256, Address, Bytes, FixedBytes, U256};
+use alloy_signer::Signer;
+use alloy_signer_local::PrivateKeySigner;
+use async_trait::async_trait;
+use eigen_utils::{
+    binding::RegistryCoordinator::{self, OperatorKickParam, SignatureWithSaltAndExpiry},
+    get_shared_provider, SharedProvider,
+};
+use std::fmt::Debug;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Denominator of the churn ratios of the [`RegistryCoordinator`], in basis points
+pub const BIPS_DENOMINATOR: u64 = 10_000;
+
+/// Registration of an operator that needs the churn approver signature
+#[derive(Debug, Clone)]
+pub struct ChurnApprovalRequest {
+    /// Address of the registering operator
+    pub operator_address: Address,
+    /// Id of the registering operator
+    pub operator_id: FixedBytes<32>,
+    /// Quorums the operator registers for
+    pub quorum_numbers: Bytes,
+    /// Operators to kick, one per quorum number
+    pub operator_kick_params: Vec<OperatorKickParam>,
+}
+
+/// Source of the churn approver signature needed by `registerOperatorWithChurn`
+#[async_trait]
+pub trait ChurnApproverSignatureSource: Debug + Send + Sync {
+    /// Get the churn approver signature of a registration
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The registration to approve
+    ///
+    /// # Returns
+    ///
+    /// The signature of the churn approval digest, with the salt and expiry it was computed with
+    async fn churn_approver_signature(
+        &self,
+        request: &ChurnApprovalRequest,
+    ) -> Result<SignatureWithSaltAndExpiry, AvsRegistryError>;
+}
+
+/// [`ChurnApproverSignatureSource`] signing with the churn approver private key
+#[derive(Debug, Clone)]
+pub struct LocalChurnApprover {
+    signer: PrivateKeySigner,
+    registry_coordinator_addr: Address,
+    provider: SharedProvider,
+    signature_validity_secs: u64,
+}
+
+impl LocalChurnApprover {
+    /// Create a new local churn approver
+    ///
+    /// # Arguments
+    ///
+    /// * `private_key` - private key of the churn approver set on the registry coordinator
+    /// * `registry_coordinator_addr` - registry coordinator address
+    /// * `provider` - provider string
+    /// * `signature_validity_secs` - number of seconds the signatures are valid for
+    pub fn new(
+        private_key: &str,
+        registry_coordinator_addr: Address,
+        provider: String,
+        signature_validity_secs: u64,
+    ) -> Result<Self, AvsRegistryError> {
+        let signer = PrivateKeySigner::from_str(private_key)
+            .map_err(|_| AvsRegistryError::InvalidPrivateKey)?;
+        Ok(Self {
+            signer,
+            registry_coordinator_addr,
+            provider: get_shared_provider(&provider)
+                .map_err(|_| AvsRegistryError::InvalidRpcUrl(provider.clone()))?,
+            signature_validity_secs,
+        })
+    }
+}
+
+#[async_trait]
+impl ChurnApproverSignatureSource for LocalChurnApprover {
+    async fn churn_approver_signature(
+        &self,
+        request: &ChurnApprovalRequest,
+    ) -> Result<SignatureWithSaltAndExpiry, AvsRegistryError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| AvsRegistryError::ChurnApproverSignature)?;
+        let expiry = U256::from(now.as_secs() + self.signature_validity_secs);
+        let salt = new_churn_approver_salt(request.operator_id);
+
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, &self.provider);
+        let RegistryCoordinator::calculateOperatorChurnApprovalDigestHashReturn { _0: digest } =
+            contract_registry_coordinator
+                .calculateOperatorChurnApprovalDigestHash(
+                    request.operator_address,
+                    request.operator_id,
+                    request.operator_kick_params.clone(),
+                    salt,
+                    expiry,
+                )
+                .call()
+                .await
+                .map_err(AvsRegistryError::revert_or(
+                    "RegistryCoordinator",
+                    AvsRegistryError::ChurnApproverSignature,
+                ))?;
+
+        let signature = self
+            .signer
+            .sign_hash(&digest)
+            .await
+            .map_err(|_| AvsRegistryError::ChurnApproverSignature)?;
+        Ok(SignatureWithSaltAndExpiry {
+            signature: signature.as_bytes().into(),
+            salt,
+            expiry,
+        })
+    }
+}
+
+/// Create a new churn approver salt for a registration. Salts can only be used once, so they
+/// are derived from the registering operator id and the current time.
+///
+/// # Arguments
+///
+/// * `operator_id` - The id of the registering operator
+pub fn new_churn_approver_salt(operator_id: FixedBytes<32>) -> FixedBytes<32> {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    keccak256([operator_id.as_slice(), &nanos.to_be_bytes()].concat())
+}
+
+/// State of a quorum the churn of an operator is computed from
+#[derive(Debug, Clone)]
+pub struct QuorumChurnState {
+    /// The quorum number
+    pub quorum_number: u8,
+    /// Maximum number of operators of the quorum
+    pub max_operator_count: u32,
+    /// Stake the registering operator needs relative to the kicked one, in basis points
+    pub kick_bips_of_operator_stake: u16,
+    /// Stake below which an operator can be kicked, relative to the total stake, in basis points
+    pub kick_bips_of_total_stake: u16,
+    /// Current operators of the quorum and their stake
+    pub operators: Vec<(Address, U256)>,
+    /// Current total stake of the quorum
+    pub total_stake: U256,
+}
+
+/// Select the operator to kick from a quorum so that `registering_operator` can register,
+/// following the checks of the [`RegistryCoordinator`]:
+/// - the registering operator stake must be above `kick_bips_of_operator_stake` of the stake
+///   of the kicked operator
+/// - the stake of the kicked operator must be below `kick_bips_of_total_stake` of the total
+///   stake of the quorum, once the registering operator joined
+///
+/// The operator with the lowest stake is the one most likely to meet both, so it is selected.
+///
+/// # Arguments
+///
+/// * `quorum` - The state of the quorum
+/// * `registering_operator` - The address of the registering operator
+/// * `registering_operator_stake` - The stake the registering operator would have in the quorum
+///
+/// # Returns
+///
+/// The kick param of the quorum. Its operator is the zero address when the quorum is not full.
+pub fn select_operator_to_kick(
+    quorum: &QuorumChurnState,
+    registering_operator: Address,
+    registering_operator_stake: U256,
+) -> Result<OperatorKickParam, AvsRegistryError> {
+    let quorum_number = quorum.quorum_number;
+    if quorum.operators.len() < quorum.max_operator_count as usize {
+        return Ok(OperatorKickParam {
+            quorumNumber: quorum_number,
+            operator: Address::ZERO,
+        });
+    }
+
+    let (operator, operator_stake) = quorum
+        .operators
+        .iter()
+        .filter(|(operator, _)| *operator != registering_operator)
+        .min_by_key(|(_, stake)| *stake)
+        .ok_or(AvsRegistryError::NoOperatorToKick(quorum_number))?;
+
+    check_kick_criteria(quorum, registering_operator_stake, *operator_stake)?;
+
+    Ok(OperatorKickParam {
+        quorumNumber: quorum_number,
+        operator: *operator,
+    })
+}
+
+/// Validate the operator a registration kicks from a quorum, with the same checks as
+/// [`select_operator_to_kick`]. The kick param of a quorum that is not full is not used by the
+/// [`RegistryCoordinator`], so any operator is accepted.
+///
+/// # Arguments
+///
+/// * `quorum` - The state of the quorum
+/// * `registering_operator` - The address of the registering operator
+/// * `registering_operator_stake` - The stake the registering operator would have in the quorum
+/// * `kick_param` - The kick param to validate
+pub fn validate_operator_to_kick(
+    quorum: &QuorumChurnState,
+    registering_operator: Address,
+    registering_operator_stake: U256,
+    kick_param: &OperatorKickParam,
+) -> Result<(), AvsRegistryError> {
+    let quorum_number = quorum.quorum_number;
+    if kick_param.quorumNumber != quorum_number {
+        return Err(AvsRegistryError::InvalidOperatorToKick(quorum_number));
+    }
+    if quorum.operators.len() < quorum.max_operator_count as usize {
+        return Ok(());
+    }
+
+    let (_, operator_stake) = quorum
+        .operators
+        .iter()
+        .find(|(operator, _)| *operator == kick_param.operator)
+        .filter(|(operator, _)| *operator != registering_operator)
+        .ok_or(AvsRegistryError::InvalidOperatorToKick(quorum_number))?;
+    check_kick_criteria(quorum, registering_operator_stake, *operator_stake)
+}
+
+/// Check the churn ratios of a quorum allow an operator to be kicked by the registering one
+fn check_kick_criteria(
+    quorum: &QuorumChurnState,
+    registering_operator_stake: U256,
+    operator_stake: U256,
+) -> Result<(), AvsRegistryError> {
+    let bips_denominator = U256::from(BIPS_DENOMINATOR);
+    let individual_kick_threshold =
+        operator_stake * U256::from(quorum.kick_bips_of_operator_stake) / bips_denominator;
+    if registering_operator_stake <= individual_kick_threshold {
+        return Err(AvsRegistryError::InsufficientStakeForChurn(
+            quorum.quorum_number,
+        ));
+    }
+
+    let total_stake = quorum.total_stake + registering_operator_stake;
+    let total_kick_threshold =
+        total_stake * U256::from(quorum.kick_bips_of_total_stake) / bips_denominator;
+    if operator_stake >= total_kick_threshold {
+        return Err(AvsRegistryError::OperatorToKickStakeTooHigh(
+            quorum.quorum_number,
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    const REGISTERING_OPERATOR: Address = address!("f39fd6e51aad88f6f4ce6ab8827279cfffb92266");
+    const SMALL_OPERATOR: Address = address!("70997970c51812dc3a010c7d01b50e0d17dc79c8");
+    const BIG_OPERATOR: Address = address!("3c44cdddb6a900fa2b585dd299e03d12fa4293bc");
+
+    fn full_quorum() -> QuorumChurnState {
+        QuorumChurnState {
+            quorum_number: 1,
+            max_operator_count: 2,
+            // the registering operator needs 110% of the kicked stake
+            kick_bips_of_operator_stake: 11_000,
+            // the kicked operator must hold less than 10% of the total stake
+            kick_bips_of_total_stake: 1_000,
+            operators: vec![
+                (BIG_OPERATOR, U256::from(900)),
+                (SMALL_OPERATOR, U256::from(50)),
+            ],
+            total_stake: U256::from(950),
+        }
+    }
+
+    #[test]
+    fn test_select_operator_to_kick_quorum_not_full() {
+        let quorum = QuorumChurnState {
+            max_operator_count: 3,
+            ..full_quorum()
+        };
+        let kick_param =
+            select_operator_to_kick(&quorum, REGISTERING_OPERATOR, U256::from(100)).unwrap();
+        assert_eq!(kick_param.quorumNumber, 1);
+        assert_eq!(kick_param.operator, Address::ZERO);
+    }
+
+    #[test]
+    fn test_select_operator_to_kick_lowest_stake() {
+        let kick_param =
+            select_operator_to_kick(&full_quorum(), REGISTERING_OPERATOR, U256::from(100)).unwrap();
+        assert_eq!(kick_param.quorumNumber, 1);
+        assert_eq!(kick_param.operator, SMALL_OPERATOR);
+    }
+
+    #[test]
+    fn test_select_operator_to_kick_insufficient_stake() {
+        // 55 is exactly 110% of 50, and the stake has to be strictly above it
+        assert!(matches!(
+            select_operator_to_kick(&full_quorum(), REGISTERING_OPERATOR, U256::from(55)),
+            Err(AvsRegistryError::InsufficientStakeForChurn(1))
+        ));
+    }
+
+    #[test]
+    fn test_select_operator_to_kick_stake_too_high() {
+        let quorum = QuorumChurnState {
+            operators: vec![
+                (BIG_OPERATOR, U256::from(900)),
+                (SMALL_OPERATOR, U256::from(200)),
+            ],
+            total_stake: U256::from(1100),
+            ..full_quorum()
+        };
+        assert!(matches!(
+            select_operator_to_kick(&quorum, REGISTERING_OPERATOR, U256::from(300)),
+            Err(AvsRegistryError::OperatorToKickStakeTooHigh(1))
+        ));
+    }
+
+    #[test]
+    fn test_validate_operator_to_kick() {
+        let kick_param = |operator| OperatorKickParam {
+            quorumNumber: 1,
+            operator,
+        };
+        let quorum = full_quorum();
+        validate_operator_to_kick(
+            &quorum,
+            REGISTERING_OPERATOR,
+            U256::from(100),
+            &kick_param(SMALL_OPERATOR),
+        )
+        .unwrap();
+        // not in the quorum
+        assert!(matches!(
+            validate_operator_to_kick(
+                &quorum,
+                REGISTERING_OPERATOR,
+                U256::from(100),
+                &kick_param(REGISTERING_OPERATOR)
+            ),
+            Err(AvsRegistryError::InvalidOperatorToKick(1))
+        ));
+        assert!(matches!(
+            validate_operator_to_kick(
+                &quorum,
+                REGISTERING_OPERATOR,
+                U256::from(100),
+                &kick_param(BIG_OPERATOR)
+            ),
+            Err(AvsRegistryError::InsufficientStakeForChurn(1))
+        ));
+    }
+}
@@ -1,6 +1,10 @@
 use alloy_contract::Error as AlloyError;
+use alloy_primitives::{Address, TxHash, U256};
+use eigen_chainio_txmanager::simple_tx_manager::TxManagerError;
+use eigen_chainio_utils::{log_scanner::LogScannerError, multicall::MulticallError};
 use eigen_client_elcontracts::error::ElContractsError;
 use eigen_crypto_bls::error::BlsError;
+use eigen_utils::revert::ContractRevert;
 use thiserror::Error;
 
 /// Error returned by AvsRegistry
@@ -22,6 +26,10 @@ pub enum AvsRegistryError {
     #[error("Failed to get oeprator state with registry coordinator and operator id ")]
     GetOperatorStateWithRegistryCoordinatorAndOperatorId,
 
+    /// The rpc url can't be parsed
+    #[error("invalid rpc url {0}")]
+    InvalidRpcUrl(String),
+
     /// Failed to get current block number
     #[error("Failed to get current block number")]
     GetBlockNumber,
@@ -70,6 +78,30 @@ pub enum AvsRegistryError {
     #[error("Get Operator Status")]
     GetOperatorStatus,
 
+    /// Get Operator Set Params
+    #[error("Failed to get operator set params")]
+    GetOperatorSetParams,
+
+    /// Get Minimum Stake For Quorum
+    #[error("Failed to get minimum stake for quorum")]
+    GetMinimumStakeForQuorum,
+
+    /// Get Strategy Params
+    #[error("Failed to get strategy params")]
+    GetStrategyParams,
+
+    /// Get Total Stake History
+    #[error("Failed to get total stake history")]
+    GetTotalStakeHistory,
+
+    /// Get Stake History
+    #[error("Failed to get stake history")]
+    GetStakeHistory,
+
+    /// Get Quorum Bitmap History
+    #[error("Failed to get quorum bitmap history")]
+    GetQuorumBitmapHistory,
+
     /// Eth logs query
     #[error("Failed to get eth_logs")]
     GetEthLogs,
@@ -110,6 +142,111 @@ pub enum AvsRegistryError {
     #[error("Failed to register operatror")]
     RegisterOperator,
 
+    /// No operator can be kicked from a full quorum
+    #[error("No operator to kick from full quorum {0}")]
+    NoOperatorToKick(u8),
+
+    /// Registering operator stake is too low to kick an operator
+    #[error("Operator stake is not enough to kick an operator from quorum {0}")]
+    InsufficientStakeForChurn(u8),
+
+    /// Operator to kick holds too much of the total stake
+    #[error("Lowest stake operator of quorum {0} holds too much of the total stake to be kicked")]
+    OperatorToKickStakeTooHigh(u8),
+
+    /// Operator to kick is not a valid candidate
+    #[error("Invalid operator to kick from quorum {0}")]
+    InvalidOperatorToKick(u8),
+
+    /// Failed to get the operator of a bls public key
+    #[error("Failed to get operator from pubkey hash")]
+    GetOperatorFromPubkeyHash,
+
+    /// Failed to get the latest block
+    #[error("Failed to get latest block")]
+    GetLatestBlock,
+
+    /// Failed to check the churn approver salt
+    #[error("Failed to check if the churn approver salt is used")]
+    IsChurnApproverSaltUsed,
+
+    /// Failed to calculate the operator churn approval digest hash
+    #[error("Failed to calculate operator churn approval digest hash")]
+    CalculateOperatorChurnApprovalDigestHash,
+
+    /// Failed to get the churn approver signature
+    #[error("Failed to get churn approver signature")]
+    ChurnApproverSignature,
+
+    /// The churn approver server answered the churn request with an error status
+    #[error("Churn approver responded with status {status}: {message}")]
+    ChurnApproverRejected {
+        /// HTTP status of the response, 4xx when the request is not eligible for churn
+        status: u16,
+        /// Error message returned by the server
+        message: String,
+    },
+
+    /// Operator is not registered
+    #[error("Operator {0} is not registered")]
+    OperatorNotRegistered(Address),
+
+    /// Operator is already registered for some quorums
+    #[error("Operator already registered for quorums {0:?}")]
+    OperatorAlreadyInQuorums(Vec<u8>),
+
+    /// Bls apk registry knows a different public key for the operator
+    #[error("Bls public key doesn't match the one registered for the operator")]
+    BlsPubKeyMismatch,
+
+    /// Failed to get the owner
+    #[error("Failed to get owner")]
+    GetOwner,
+
+    /// Failed to get the ejector
+    #[error("Failed to get ejector")]
+    GetEjector,
+
+    /// Failed to get the pauser registry
+    #[error("Failed to get pauser registry")]
+    GetPauserRegistry,
+
+    /// Failed to check if an address is a pauser
+    #[error("Failed to check pauser")]
+    IsPauser,
+
+    /// Failed to get the unpauser
+    #[error("Failed to get unpauser")]
+    GetUnpauser,
+
+    /// Signer is not the registry coordinator owner
+    #[error("Signer {0} is not the registry coordinator owner")]
+    NotRegistryCoordinatorOwner(Address),
+
+    /// Signer is not the service manager owner
+    #[error("Signer {0} is not the service manager owner")]
+    NotServiceManagerOwner(Address),
+
+    /// Signer is not the ejector
+    #[error("Signer {0} is not the ejector")]
+    NotEjector(Address),
+
+    /// Signer is not a pauser
+    #[error("Signer {0} is not a pauser")]
+    NotPauser(Address),
+
+    /// Signer is not the unpauser
+    #[error("Signer {0} is not the unpauser")]
+    NotUnpauser(Address),
+
+    /// Transaction reverted when simulated
+    #[error("Transaction simulation failed: {0}")]
+    SimulateTransaction(AlloyError),
+
+    /// Value does not fit in a uint96
+    #[error("Value {0} does not fit in a uint96")]
+    Uint96Overflow(U256),
+
     /// Update Operator for Quorum
     #[error("Failed to update operator for quorum")]
     UpdateOperatorForQuorum,
@@ -138,6 +275,14 @@ pub enum AvsRegistryError {
     #[error("Alloy contract error: {0}")]
     AlloyContractError(#[from] AlloyError),
 
+    /// Failed to scan logs
+    #[error("Log scanner error: {0}")]
+    LogScannerError(#[from] LogScannerError),
+
+    /// Failed to execute a batch of calls
+    #[error("Multicall error: {0}")]
+    MulticallError(#[from] MulticallError),
+
     /// ElContractsError compatibility
     #[error("ElContractsError: {0}")]
     ElContractsError(String),
@@ -154,9 +299,27 @@ pub enum AvsRegistryError {
     #[error("Invalid private key")]
     InvalidPrivateKey,
 
+    /// The writer signer can't sign the operator registration digest
+    #[error(
+        "signer of the writer can only sign transactions, not the operator registration digest"
+    )]
+    MessageSignerUnavailable,
+
     /// Invalid Signature
     #[error("Invalid signature")]
     InvalidSignature,
+
+    /// The tx manager failed to send a transaction or to get its receipt
+    #[error("tx manager error: {0}")]
+    TxManager(#[from] TxManagerError),
+
+    /// Transaction mined but reverted
+    #[error("transaction {0} reverted")]
+    TxReverted(TxHash),
+
+    /// A contract call or transaction reverted
+    #[error("{0}")]
+    ContractReverted(#[from] ContractRevert),
 }
 
 impl From<ElContractsError> for AvsRegistryError {
@@ -170,3 +333,61 @@ impl From<BlsError> for AvsRegistryError {
         AvsRegistryError::BlsError(err.to_string())
     }
 }
+
+impl AvsRegistryError {
+    /// Map the error of a call to `contract` to [`AvsRegistryError::ContractReverted`] if it
+    /// reverted, or to `otherwise`
+    pub(crate) fn revert_or(
+        contract: &'static str,
+        otherwise: Self,
+    ) -> impl FnOnce(AlloyError) -> Self {
+        move |err| {
+            ContractRevert::from_call_error(contract, &err)
+                .map_or(otherwise, Self::ContractReverted)
+        }
+    }
+
+    /// Map the error of a call to `contract` to [`AvsRegistryError::ContractReverted`] if it
+    /// reverted, or to [`AvsRegistryError::AlloyContractError`]
+    pub(crate) fn revert(contract: &'static str) -> impl FnOnce(AlloyError) -> Self {
+        Self::revert_or_else(contract, Self::AlloyContractError)
+    }
+
+    /// Map the error of a call to `contract` to [`AvsRegistryError::ContractReverted`] if it
+    /// reverted, or with `otherwise`
+    pub(crate) fn revert_or_else(
+        contract: &'static str,
+        otherwise: impl FnOnce(AlloyError) -> Self,
+    ) -> impl FnOnce(AlloyError) -> Self {
+        move |err| match ContractRevert::from_call_error(contract, &err) {
+            Some(revert) => Self::ContractReverted(revert),
+            None => otherwise(err),
+        }
+    }
+
+    /// Map the error of a call to `contract` batched in a multicall to
+    /// [`AvsRegistryError::ContractReverted`] if it reverted, or to `otherwise`
+    pub(crate) fn multicall_revert_or(
+        contract: &'static str,
+        otherwise: Self,
+    ) -> impl FnOnce(MulticallError) -> Self {
+        move |err| match err {
+            MulticallError::CallReverted { data, .. } => {
+                Self::ContractReverted(ContractRevert::decode(contract, &data))
+            }
+            _ => otherwise,
+        }
+    }
+
+    /// Map the error of the tx manager sending a transaction to `contract` to
+    /// [`AvsRegistryError::ContractReverted`] if the transaction reverts, or to
+    /// [`AvsRegistryError::TxManager`]
+    pub(crate) fn tx_revert(contract: &'static str) -> impl FnOnce(TxManagerError) -> Self {
+        move |err| match err {
+            TxManagerError::Reverted(data) => {
+                Self::ContractReverted(ContractRevert::decode(contract, &data))
+            }
+            err => Self::TxManager(err),
+        }
+    }
+}
@@ -13,9 +13,18 @@ pub mod reader;
 /// Writer module
 pub mod writer;
 
+/// Admin writer module
+pub mod admin;
+
 /// Avs registry error message
 pub mod error;
 
+/// Operator churn for full quorums
+pub mod churn;
+
+/// Checks run before registering an operator
+pub mod preflight;
+
 #[allow(dead_code)]
 /// Fake avs registry module
 pub mod fake_reader;
@@ -0,0 +1,499 @@
+use crate::error::AvsRegistryError;
+use crate::reader::AvsRegistryChainReader;
+use alloy_primitives::{Address, Bytes, FixedBytes, U256};
+use alloy_provider::Provider;
+use alloy_rpc_types::BlockNumberOrTag;
+use eigen_client_elcontracts::reader::ELChainReader;
+use eigen_crypto_bls::BlsG1Point;
+use eigen_logging::logger::SharedLogger;
+use eigen_types::operator::{bitmap_to_quorum_ids, operator_id_from_g1_pub_key};
+use eigen_utils::{
+    binding::{
+        AVSDirectory, BLSApkRegistry, DelegationManager, RegistryCoordinator, ServiceManagerBase,
+        StakeRegistry,
+    },
+    get_shared_provider, SharedProvider,
+};
+use thiserror::Error;
+
+/// Check that `registerOperator` of the [`RegistryCoordinator`] would fail
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum RegistrationCheckFailure {
+    /// The operator is not registered in the DelegationManager
+    #[error("operator {0} is not registered in the DelegationManager")]
+    NotDelegationManagerOperator(Address),
+
+    /// The operator is already registered for some of the quorums
+    #[error("operator is already registered for quorums {0:?}")]
+    AlreadyRegisteredForQuorums(Vec<u8>),
+
+    /// Some of the quorums were not created in the registry coordinator
+    #[error("quorums {quorum_numbers:?} don't exist, the quorum count is {quorum_count}")]
+    QuorumsNotFound {
+        /// The quorums that don't exist
+        quorum_numbers: Vec<u8>,
+        /// Number of quorums of the registry coordinator
+        quorum_count: u8,
+    },
+
+    /// The operator stake is below the minimum stake of a quorum
+    #[error("stake {stake} is below the minimum stake {minimum_stake} of quorum {quorum_number}")]
+    StakeBelowMinimum {
+        /// The quorum number
+        quorum_number: u8,
+        /// Weight of the operator for the quorum
+        stake: U256,
+        /// Minimum stake of the quorum
+        minimum_stake: U256,
+    },
+
+    /// The operator already used the salt of the signature in the AVSDirectory
+    #[error("salt {0} is already spent in the AVSDirectory")]
+    SaltSpent(FixedBytes<32>),
+
+    /// The signature expires before the registration can be mined
+    #[error("signature expiry {expiry} is not after the latest block timestamp {block_timestamp}")]
+    SignatureExpired {
+        /// Expiry of the signature
+        expiry: U256,
+        /// Timestamp of the latest block
+        block_timestamp: u64,
+    },
+
+    /// The bls public key is registered to another operator
+    #[error("bls public key is registered to operator {0}")]
+    BlsKeyRegisteredToOtherOperator(Address),
+}
+
+/// Result of [`RegistrationPreflight::check`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegistrationPreflightReport {
+    /// Address of the operator
+    pub operator: Address,
+    /// Id of the operator, derived from its bls public key
+    pub operator_id: FixedBytes<32>,
+    /// Quorums the operator would register for
+    pub quorum_numbers: Bytes,
+    /// Every failed check, empty if the registration can go ahead
+    pub failures: Vec<RegistrationCheckFailure>,
+}
+
+impl RegistrationPreflightReport {
+    /// Whether every check passed
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Checks an operator registration before sending `registerOperator`, so that failures show up
+/// in a report instead of as a reverted transaction
+#[derive(Debug, Clone)]
+pub struct RegistrationPreflight {
+    avs_reader: AvsRegistryChainReader,
+    el_reader: ELChainReader,
+}
+
+impl RegistrationPreflight {
+    /// Create a new registration preflight
+    ///
+    /// # Arguments
+    ///
+    /// * `avs_reader` - reader of the avs registry contracts
+    /// * `el_reader` - reader of the eigenlayer contracts
+    pub fn new(avs_reader: AvsRegistryChainReader, el_reader: ELChainReader) -> Self {
+        Self {
+            avs_reader,
+            el_reader,
+        }
+    }
+
+    /// Build a registration preflight, reading the eigenlayer contract addresses from the avs
+    /// registry contracts
+    ///
+    /// # Arguments
+    ///
+    /// * `logger` - SharedLogger used for logging
+    /// * `registry_coordinator_addr` - registry coordinator address
+    /// * `operator_state_retriever_addr` - operator state retriever address
+    /// * `provider` - provider string
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self, AvsRegistryError>` - a new RegistrationPreflight
+    pub async fn build(
+        logger: SharedLogger,
+        registry_coordinator_addr: Address,
+        operator_state_retriever_addr: Address,
+        provider: String,
+    ) -> Result<Self, AvsRegistryError> {
+        Self::build_with_provider(
+            logger,
+            registry_coordinator_addr,
+            operator_state_retriever_addr,
+            get_shared_provider(&provider)
+                .map_err(|_| AvsRegistryError::InvalidRpcUrl(provider.clone()))?,
+        )
+        .await
+    }
+
+    /// Build a registration preflight reading through `provider`, shared with the other clients
+    /// built with it
+    ///
+    /// # Arguments
+    ///
+    /// * `logger` - SharedLogger used for logging
+    /// * `registry_coordinator_addr` - registry coordinator address
+    /// * `operator_state_retriever_addr` - operator state retriever address
+    /// * `provider` - the shared provider
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self, AvsRegistryError>` - a new RegistrationPreflight
+    pub async fn build_with_provider(
+        logger: SharedLogger,
+        registry_coordinator_addr: Address,
+        operator_state_retriever_addr: Address,
+        provider: SharedProvider,
+    ) -> Result<Self, AvsRegistryError> {
+        let avs_reader = AvsRegistryChainReader::new_with_provider(
+            logger.clone(),
+            registry_coordinator_addr,
+            operator_state_retriever_addr,
+            provider.clone(),
+        )
+        .await?;
+
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(registry_coordinator_addr, &provider);
+
+        let RegistryCoordinator::serviceManagerReturn {
+            _0: service_manager,
+        } = contract_registry_coordinator
+            .serviceManager()
+            .call()
+            .await
+            .map_err(AvsRegistryError::revert_or(
+                "RegistryCoordinator",
+                AvsRegistryError::GetServiceManager,
+            ))?;
+        let RegistryCoordinator::stakeRegistryReturn { _0: stake_registry } =
+            contract_registry_coordinator
+                .stakeRegistry()
+                .call()
+                .await
+                .map_err(AvsRegistryError::revert_or(
+                    "RegistryCoordinator",
+                    AvsRegistryError::GetStakeRegistry,
+                ))?;
+
+        let StakeRegistry::delegationReturn {
+            _0: delegation_manager,
+        } = StakeRegistry::new(stake_registry, &provider)
+            .delegation()
+            .call()
+            .await
+            .map_err(AvsRegistryError::revert_or(
+                "StakeRegistry",
+                AvsRegistryError::GetDelegation,
+            ))?;
+        let ServiceManagerBase::avsDirectoryReturn { _0: avs_directory } =
+            ServiceManagerBase::new(service_manager, &provider)
+                .avsDirectory()
+                .call()
+                .await
+                .map_err(AvsRegistryError::revert("ServiceManagerBase"))?;
+
+        let el_reader =
+            ELChainReader::build_with_provider(logger, delegation_manager, avs_directory, provider)
+                .await
+                .map_err(|_| AvsRegistryError::BuildElChainReader)?;
+
+        Ok(Self::new(avs_reader, el_reader))
+    }
+
+    /// Run every check of an operator registration with
+    /// [`crate::writer::AvsRegistryChainWriter::register_operator_in_quorum_with_avs_registry_coordinator`]
+    ///
+    /// The checks don't stop at the first failure, the report holds every failed check. They
+    /// all read the state of the latest block, batched through Multicall3.
+    ///
+    /// # Arguments
+    ///
+    /// * `operator` - address of the operator
+    /// * `bls_public_key` - G1 bls public key of the operator
+    /// * `operator_to_avs_registration_sig_salt` - salt for the signature
+    /// * `operator_to_avs_registration_sig_expiry` - expiry for the signature
+    /// * `quorum_numbers` - quorum numbers to register for
+    ///
+    /// # Returns
+    ///
+    /// * `Result<RegistrationPreflightReport, AvsRegistryError>` - the failed checks
+    ///
+    /// # Errors
+    ///
+    /// * `AvsRegistryError` - if a contract call fails, which doesn't count as a failed check
+    pub async fn check(
+        &self,
+        operator: Address,
+        bls_public_key: BlsG1Point,
+        operator_to_avs_registration_sig_salt: FixedBytes<32>,
+        operator_to_avs_registration_sig_expiry: U256,
+        quorum_numbers: Bytes,
+    ) -> Result<RegistrationPreflightReport, AvsRegistryError> {
+        let operator_id: FixedBytes<32> = operator_id_from_g1_pub_key(bls_public_key)
+            .map_err(|_| AvsRegistryError::GetOperatorId)?
+            .into();
+
+        // every check reads the state of the same block
+        let block = self
+            .avs_reader
+            .provider()
+            .get_block_by_number(BlockNumberOrTag::Latest, false)
+            .await
+            .ok()
+            .flatten()
+            .ok_or(AvsRegistryError::GetLatestBlock)?;
+        let block_number = block
+            .header
+            .number
+            .ok_or(AvsRegistryError::GetLatestBlock)?;
+        let block_timestamp = block.header.timestamp;
+
+        let registry_coordinator = self.avs_reader.registry_coordinator_address();
+        let stake_registry = self.avs_reader.stake_registry_address();
+        let mut multicall = self.avs_reader.multicall();
+        let is_operator = multicall.add_call(
+            self.el_reader.delegation_manager_address(),
+            &DelegationManager::isOperatorCall { operator },
+        );
+        let registered_operator_id = multicall.add_call(
+            registry_coordinator,
+            &RegistryCoordinator::getOperatorIdCall { operator },
+        );
+        let quorum_count = multicall.add_call(
+            registry_coordinator,
+            &RegistryCoordinator::quorumCountCall {},
+        );
+        // the stake registry reverts when weighting an operator for a quorum that doesn't
+        // exist, which only fails these calls and not the batch
+        let stakes: Vec<_> = quorum_numbers
+            .iter()
+            .map(|&quorum_number| {
+                (
+                    multicall.add_call(
+                        stake_registry,
+                        &StakeRegistry::weightOfOperatorForQuorumCall {
+                            quorumNumber: quorum_number,
+                            operator,
+                        },
+                    ),
+                    multicall.add_call(
+                        stake_registry,
+                        &StakeRegistry::minimumStakeForQuorumCall { _0: quorum_number },
+                    ),
+                )
+            })
+            .collect();
+        let salt_spent = multicall.add_call(
+            self.el_reader.avs_directory_address(),
+            &AVSDirectory::operatorSaltIsSpentCall {
+                _0: operator,
+                _1: operator_to_avs_registration_sig_salt,
+            },
+        );
+        let pubkey_operator = multicall.add_call(
+            self.avs_reader.bls_apk_registry_address(),
+            &BLSApkRegistry::pubkeyHashToOperatorCall { _0: operator_id },
+        );
+        let results = self
+            .avs_reader
+            .call_multicall(&multicall, Some(block_number))
+            .await?;
+
+        let mut failures = Vec::new();
+
+        let DelegationManager::isOperatorReturn { _0: is_operator } = results.get(is_operator)?;
+        if !is_operator {
+            failures.push(RegistrationCheckFailure::NotDelegationManagerOperator(
+                operator,
+            ));
+        }
+
+        let RegistryCoordinator::getOperatorIdReturn {
+            _0: registered_operator_id,
+        } = results
+            .get(registered_operator_id)
+            .map_err(AvsRegistryError::multicall_revert_or(
+                "RegistryCoordinator",
+                AvsRegistryError::GetOperatorId,
+            ))?;
+        if registered_operator_id != FixedBytes::ZERO {
+            let mut multicall = self.avs_reader.multicall();
+            let quorum_bitmap = multicall.add_call(
+                registry_coordinator,
+                &RegistryCoordinator::getCurrentQuorumBitmapCall {
+                    operatorId: registered_operator_id,
+                },
+            );
+            let RegistryCoordinator::getCurrentQuorumBitmapReturn { _0: quorum_bitmap } = self
+                .avs_reader
+                .call_multicall(&multicall, Some(block_number))
+                .await?
+                .get(quorum_bitmap)
+                .map_err(AvsRegistryError::multicall_revert_or(
+                    "RegistryCoordinator",
+                    AvsRegistryError::GetCurrentQuorumBitmap,
+                ))?;
+            let registered_quorums = bitmap_to_quorum_ids(U256::from(quorum_bitmap));
+            let already_registered: Vec<u8> = quorum_numbers
+                .iter()
+                .filter(|quorum_number| registered_quorums.contains(quorum_number))
+                .copied()
+                .collect();
+            if !already_registered.is_empty() {
+                failures.push(RegistrationCheckFailure::AlreadyRegisteredForQuorums(
+                    already_registered,
+                ));
+            }
+        }
+
+        let RegistryCoordinator::quorumCountReturn { _0: quorum_count } = results
+            .get(quorum_count)
+            .map_err(AvsRegistryError::multicall_revert_or(
+                "RegistryCoordinator",
+                AvsRegistryError::GetQuorumCount,
+            ))?;
+        let mut missing_quorums = vec![];
+        for (&quorum_number, (stake, minimum_stake)) in quorum_numbers.iter().zip(stakes) {
+            if quorum_number >= quorum_count {
+                missing_quorums.push(quorum_number);
+                continue;
+            }
+            let StakeRegistry::weightOfOperatorForQuorumReturn { _0: stake } =
+                results
+                    .get(stake)
+                    .map_err(AvsRegistryError::multicall_revert_or(
+                        "StakeRegistry",
+                        AvsRegistryError::GetCurrentStake,
+                    ))?;
+            let StakeRegistry::minimumStakeForQuorumReturn { _0: minimum_stake } = results
+                .get(minimum_stake)
+                .map_err(AvsRegistryError::multicall_revert_or(
+                    "StakeRegistry",
+                    AvsRegistryError::GetMinimumStakeForQuorum,
+                ))?;
+            if stake < minimum_stake {
+                failures.push(RegistrationCheckFailure::StakeBelowMinimum {
+                    quorum_number,
+                    stake: U256::from(stake),
+                    minimum_stake: U256::from(minimum_stake),
+                });
+            }
+        }
+        if !missing_quorums.is_empty() {
+            failures.push(RegistrationCheckFailure::QuorumsNotFound {
+                quorum_numbers: missing_quorums,
+                quorum_count,
+            });
+        }
+
+        let AVSDirectory::operatorSaltIsSpentReturn { _0: salt_spent } = results.get(salt_spent)?;
+        if salt_spent {
+            failures.push(RegistrationCheckFailure::SaltSpent(
+                operator_to_avs_registration_sig_salt,
+            ));
+        }
+
+        if operator_to_avs_registration_sig_expiry <= U256::from(block_timestamp) {
+            failures.push(RegistrationCheckFailure::SignatureExpired {
+                expiry: operator_to_avs_registration_sig_expiry,
+                block_timestamp,
+            });
+        }
+
+        let BLSApkRegistry::pubkeyHashToOperatorReturn {
+            _0: pubkey_operator,
+        } = results
+            .get(pubkey_operator)
+            .map_err(AvsRegistryError::multicall_revert_or(
+                "BLSApkRegistry",
+                AvsRegistryError::GetOperatorFromPubkeyHash,
+            ))?;
+        if pubkey_operator != Address::ZERO && pubkey_operator != operator {
+            failures.push(RegistrationCheckFailure::BlsKeyRegisteredToOtherOperator(
+                pubkey_operator,
+            ));
+        }
+
+        Ok(RegistrationPreflightReport {
+            operator,
+            operator_id,
+            quorum_numbers,
+            failures,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RegistrationCheckFailure, RegistrationPreflight};
+    use alloy_primitives::{Address, Bytes, FixedBytes, U256};
+    use eigen_crypto_bls::BlsKeyPair;
+    use eigen_logging::get_test_logger;
+    use eigen_testing_utils::anvil_constants::{
+        get_operator_state_retriever_address, get_registry_coordinator_address, ANVIL_HTTP_URL,
+    };
+    use eigen_testing_utils::multicall::deploy_multicall3;
+
+    #[tokio::test]
+    async fn test_check_reports_every_failure() {
+        deploy_multicall3().await;
+        let preflight = RegistrationPreflight::build(
+            get_test_logger(),
+            get_registry_coordinator_address().await,
+            get_operator_state_retriever_address().await,
+            ANVIL_HTTP_URL.to_string(),
+        )
+        .await
+        .unwrap();
+        let bls_key_pair = BlsKeyPair::new(
+            "12248929636257230549931416853095037629726205319386239410403476017439825112537"
+                .to_string(),
+        )
+        .unwrap();
+        let operator = Address::from([0x42; 20]);
+        let quorum_count = preflight.avs_reader.get_quorum_count().await.unwrap();
+
+        let report = preflight
+            .check(
+                operator,
+                bls_key_pair.public_key(),
+                FixedBytes::from([0x02; 32]),
+                U256::ZERO,
+                Bytes::from(vec![0, quorum_count]),
+            )
+            .await
+            .unwrap();
+
+        assert!(!report.is_ok());
+        assert!(report
+            .failures
+            .contains(&RegistrationCheckFailure::NotDelegationManagerOperator(
+                operator
+            )));
+        assert!(report
+            .failures
+            .contains(&RegistrationCheckFailure::QuorumsNotFound {
+                quorum_numbers: vec![quorum_count],
+                quorum_count,
+            }));
+        assert!(report
+            .failures
+            .iter()
+            .any(|failure| matches!(failure, RegistrationCheckFailure::SignatureExpired { .. })));
+        assert!(!report
+            .failures
+            .iter()
+            .any(|failure| matches!(failure, RegistrationCheckFailure::SaltSpent(_))));
+    }
+}
@@ -1,18 +1,29 @@
 use crate::error::AvsRegistryError;
 use alloy_primitives::{Address, Bytes, FixedBytes, B256, U256};
 use alloy_provider::Provider;
-use alloy_rpc_types::Filter;
+use alloy_rpc_types::{BlockId, Filter};
 use ark_ff::Zero;
 use async_trait::async_trait;
+use eigen_chainio_utils::{
+    log_scanner::LogScanner,
+    multicall::{Multicall, MulticallResults, MULTICALL3_ADDRESS},
+};
 use eigen_crypto_bls::{
     alloy_registry_g1_point_to_g1_affine, alloy_registry_g2_point_to_g2_affine, BlsG1Point,
     BlsG2Point,
 };
 use eigen_logging::logger::SharedLogger;
-use eigen_types::operator::{bitmap_to_quorum_ids, OperatorPubKeys};
+use eigen_types::operator::{
+    bitmap_to_quorum_ids, OperatorPubKeys, OperatorStatus, QuorumBitmapUpdate, QuorumConfig,
+    StakeUpdate, StrategyParams,
+};
 use eigen_utils::{
-    binding::{BLSApkRegistry, OperatorStateRetriever, RegistryCoordinator, StakeRegistry},
-    get_provider, get_ws_provider, NEW_PUBKEY_REGISTRATION_EVENT,
+    binding::{
+        BLSApkRegistry, OperatorStateRetriever,
+        RegistryCoordinator::{self, OperatorKickParam, OperatorSetParam},
+        StakeRegistry,
+    },
+    get_shared_provider, get_ws_provider, SharedProvider,
 };
 use num_bigint::BigInt;
 use std::collections::HashMap;
@@ -26,7 +37,8 @@ pub struct AvsRegistryChainReader {
     registry_coordinator_addr: Address,
     operator_state_retriever: Address,
     stake_registry_addr: Address,
-    provider: String,
+    multicall_addr: Address,
+    provider: SharedProvider,
 }
 
 #[async_trait]
@@ -89,23 +101,20 @@ impl AvsRegistryReader for AvsRegistryChainReader {
         block_number: u32,
         quorum_numbers: Bytes,
     ) -> Result<Vec<Vec<OperatorStateRetriever::Operator>>, AvsRegistryError> {
-        let provider = get_provider(&self.provider);
+        let provider = &self.provider;
 
         let contract_operator_state_retriever =
             OperatorStateRetriever::new(self.operator_state_retriever, provider);
-        let operator_state_result = contract_operator_state_retriever
-            .getOperatorState_0(self.registry_coordinator_addr, quorum_numbers, block_number)
-            .call()
-            .await;
-
-        match operator_state_result {
-            Ok(operator_state) => {
-                let OperatorStateRetriever::getOperatorState_0Return { _0: quorum } =
-                    operator_state;
-                Ok(quorum)
-            }
-            Err(_) => Err(AvsRegistryError::GetOperatorState),
-        }
+        let OperatorStateRetriever::getOperatorState_0Return { _0: quorum } =
+            contract_operator_state_retriever
+                .getOperatorState_0(self.registry_coordinator_addr, quorum_numbers, block_number)
+                .call()
+                .await
+                .map_err(AvsRegistryError::revert_or(
+                    "OperatorStateRetriever",
+                    AvsRegistryError::GetOperatorState,
+                ))?;
+        Ok(quorum)
     }
 
     async fn get_check_signatures_indices(
@@ -114,7 +123,7 @@ impl AvsRegistryReader for AvsRegistryChainReader {
         quorum_numbers: Vec<u8>,
         non_signer_operator_ids: Vec<FixedBytes<32>>,
     ) -> Result<OperatorStateRetriever::CheckSignaturesIndices, AvsRegistryError> {
-        let provider = get_provider(&self.provider);
+        let provider = &self.provider;
 
         let contract_operator_state_retriever =
             OperatorStateRetriever::new(self.operator_state_retriever, provider);
@@ -127,7 +136,8 @@ impl AvsRegistryReader for AvsRegistryChainReader {
                 non_signer_operator_ids,
             )
             .call()
-            .await?;
+            .await
+            .map_err(AvsRegistryError::revert("OperatorStateRetriever"))?;
         let OperatorStateRetriever::getCheckSignaturesIndicesReturn { _0: indices } =
             check_signature_indices;
         Ok(indices)
@@ -137,15 +147,16 @@ impl AvsRegistryReader for AvsRegistryChainReader {
         &self,
         operator_id: [u8; 32],
     ) -> Result<Address, AvsRegistryError> {
-        let provider = get_provider(&self.provider);
+        let provider = &self.provider;
 
         let contract_registry_coordinator =
-            RegistryCoordinator::new(self.registry_coordinator_addr, &provider);
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
 
         let operator_address_return = contract_registry_coordinator
             .getOperatorFromId(operator_id.into())
             .call()
-            .await?;
+            .await
+            .map_err(AvsRegistryError::revert("RegistryCoordinator"))?;
         let RegistryCoordinator::getOperatorFromIdReturn {
             _0: operator_address,
         } = operator_address_return;
@@ -169,15 +180,40 @@ impl AvsRegistryChainReader {
         operator_state_retriever_addr: Address,
         http_provider_url: String,
     ) -> Result<AvsRegistryChainReader, AvsRegistryError> {
-        let provider = get_provider(&http_provider_url);
+        Self::new_with_provider(
+            logger,
+            registry_coordinator_addr,
+            operator_state_retriever_addr,
+            get_shared_provider(&http_provider_url)
+                .map_err(|_| AvsRegistryError::InvalidRpcUrl(http_provider_url.clone()))?,
+        )
+        .await
+    }
 
+    /// Create a new instance of the AvsRegistryChainReader reading through `provider`
+    ///
+    /// # Arguments
+    ///
+    /// * `logger` - A reference to the logger.
+    /// * `registry_coordinator_addr` - The address of the RegistryCoordinator contract.
+    /// * `operator_state_retriever_addr` - The address of the OperatorStateRetriever contract.
+    /// * `provider` - The provider, shared with the other clients built with it.
+    pub async fn new_with_provider(
+        logger: SharedLogger,
+        registry_coordinator_addr: Address,
+        operator_state_retriever_addr: Address,
+        provider: SharedProvider,
+    ) -> Result<AvsRegistryChainReader, AvsRegistryError> {
         let contract_registry_coordinator =
             RegistryCoordinator::new(registry_coordinator_addr, &provider);
         let bls_apk_registry_return = contract_registry_coordinator
             .blsApkRegistry()
             .call()
             .await
-            .map_err(|_| AvsRegistryError::GetBlsApkRegistry)?;
+            .map_err(AvsRegistryError::revert_or(
+                "RegistryCoordinator",
+                AvsRegistryError::GetBlsApkRegistry,
+            ))?;
 
         let RegistryCoordinator::blsApkRegistryReturn {
             _0: bls_apk_registry_addr,
@@ -187,7 +223,10 @@ impl AvsRegistryChainReader {
             .stakeRegistry()
             .call()
             .await
-            .map_err(|_| AvsRegistryError::GetStakeRegistry)?;
+            .map_err(AvsRegistryError::revert_or(
+                "RegistryCoordinator",
+                AvsRegistryError::GetStakeRegistry,
+            ))?;
 
         let RegistryCoordinator::stakeRegistryReturn {
             _0: stake_registry_addr,
@@ -199,17 +238,89 @@ impl AvsRegistryChainReader {
             registry_coordinator_addr,
             operator_state_retriever: operator_state_retriever_addr,
             stake_registry_addr,
-            provider: http_provider_url.clone(),
+            multicall_addr: MULTICALL3_ADDRESS,
+            provider,
         })
     }
 
+    /// Batch the calls through the Multicall3 deployed at `multicall_addr` instead of
+    /// [`MULTICALL3_ADDRESS`]
+    pub fn with_multicall_address(mut self, multicall_addr: Address) -> Self {
+        self.multicall_addr = multicall_addr;
+        self
+    }
+
+    /// Create an empty batch of calls, executed with [`Self::call_multicall`]
+    pub fn multicall(&self) -> Multicall {
+        Multicall::new(self.multicall_addr)
+    }
+
+    /// Execute a batch of calls at a single block
+    ///
+    /// # Arguments
+    ///
+    /// * `multicall` - The batch of calls.
+    /// * `block_number` - The block to read the state at, the latest block if `None`.
+    ///
+    /// # Returns
+    ///
+    /// The results of the calls, all read at the same block.
+    pub async fn call_multicall(
+        &self,
+        multicall: &Multicall,
+        block_number: Option<u64>,
+    ) -> Result<MulticallResults, AvsRegistryError> {
+        let provider = &self.provider;
+        let results = match block_number {
+            Some(block_number) => multicall.call_at_block(provider, block_number).await?,
+            None => multicall.call(provider).await?,
+        };
+        Ok(results)
+    }
+
+    /// Get the provider the reader reads through
+    ///
+    /// # Returns
+    ///
+    /// The provider, to build other clients sharing its connection.
+    pub fn provider(&self) -> &SharedProvider {
+        &self.provider
+    }
+
+    /// Get the address of the RegistryCoordinator contract
+    ///
+    /// # Returns
+    ///
+    /// The RegistryCoordinator address this reader was built with.
+    pub fn registry_coordinator_address(&self) -> Address {
+        self.registry_coordinator_addr
+    }
+
+    /// Get the address of the StakeRegistry contract
+    ///
+    /// # Returns
+    ///
+    /// The StakeRegistry address read from the RegistryCoordinator.
+    pub fn stake_registry_address(&self) -> Address {
+        self.stake_registry_addr
+    }
+
+    /// Get the address of the BLSApkRegistry contract
+    ///
+    /// # Returns
+    ///
+    /// The BLSApkRegistry address read from the RegistryCoordinator.
+    pub fn bls_apk_registry_address(&self) -> Address {
+        self.bls_apk_registry_addr
+    }
+
     /// Get quorum count
     ///
     /// # Returns
     ///
     /// The total quorum count read from the RegistryCoordinator.
     pub async fn get_quorum_count(&self) -> Result<u8, AvsRegistryError> {
-        let provider = get_provider(&self.provider);
+        let provider = &self.provider;
 
         let contract_registry_coordinator =
             RegistryCoordinator::new(self.registry_coordinator_addr, provider);
@@ -218,12 +329,631 @@ impl AvsRegistryChainReader {
             .quorumCount()
             .call()
             .await
-            .map_err(|_| AvsRegistryError::GetQuorumCount)?;
+            .map_err(AvsRegistryError::revert_or(
+                "RegistryCoordinator",
+                AvsRegistryError::GetQuorumCount,
+            ))?;
 
         let RegistryCoordinator::quorumCountReturn { _0: quorum } = quorum_count;
         Ok(quorum)
     }
 
+    /// Get the configuration of a quorum at a particular block
+    ///
+    /// # Arguments
+    ///
+    /// * `quorum_number` - The quorum number.
+    /// * `block_number` - The block number.
+    ///
+    /// # Returns
+    ///
+    /// The operator set params read from the RegistryCoordinator, and the minimum stake,
+    /// strategies and total stake history read from the StakeRegistry.
+    pub async fn get_quorum_config_at_block(
+        &self,
+        quorum_number: u8,
+        block_number: u32,
+    ) -> Result<QuorumConfig, AvsRegistryError> {
+        let mut quorum_configs = self
+            .quorum_configs_at_block(&[quorum_number], block_number)
+            .await?;
+        Ok(quorum_configs.remove(0))
+    }
+
+    /// Get the configuration of all the quorums at a particular block
+    ///
+    /// # Arguments
+    ///
+    /// * `block_number` - The block number.
+    ///
+    /// # Returns
+    ///
+    /// The configuration of each quorum that existed at `block_number`, ordered by quorum number.
+    pub async fn get_quorum_configs_at_block(
+        &self,
+        block_number: u32,
+    ) -> Result<Vec<QuorumConfig>, AvsRegistryError> {
+        let provider = &self.provider;
+
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+
+        let RegistryCoordinator::quorumCountReturn { _0: quorum_count } =
+            contract_registry_coordinator
+                .quorumCount()
+                .block(BlockId::number(block_number.into()))
+                .call()
+                .await
+                .map_err(AvsRegistryError::revert_or(
+                    "RegistryCoordinator",
+                    AvsRegistryError::GetQuorumCount,
+                ))?;
+
+        let quorum_numbers: Vec<u8> = (0..quorum_count).collect();
+        self.quorum_configs_at_block(&quorum_numbers, block_number)
+            .await
+    }
+
+    /// Read the configuration of quorums at a block, batching the calls of all the quorums
+    async fn quorum_configs_at_block(
+        &self,
+        quorum_numbers: &[u8],
+        block_number: u32,
+    ) -> Result<Vec<QuorumConfig>, AvsRegistryError> {
+        let block_number = u64::from(block_number);
+
+        // first the fixed size values, which give the length of the strategy and stake lists
+        let mut multicall = self.multicall();
+        let handles: Vec<_> = quorum_numbers
+            .iter()
+            .map(|&quorum_number| {
+                (
+                    multicall.add_call(
+                        self.registry_coordinator_addr,
+                        &RegistryCoordinator::getOperatorSetParamsCall {
+                            quorumNumber: quorum_number,
+                        },
+                    ),
+                    multicall.add_call(
+                        self.stake_registry_addr,
+                        &StakeRegistry::minimumStakeForQuorumCall { _0: quorum_number },
+                    ),
+                    multicall.add_call(
+                        self.stake_registry_addr,
+                        &StakeRegistry::strategyParamsLengthCall {
+                            quorumNumber: quorum_number,
+                        },
+                    ),
+                    multicall.add_call(
+                        self.stake_registry_addr,
+                        &StakeRegistry::getTotalStakeHistoryLengthCall {
+                            quorumNumber: quorum_number,
+                        },
+                    ),
+                )
+            })
+            .collect();
+        let results = self.call_multicall(&multicall, Some(block_number)).await?;
+
+        let mut lists_multicall = self.multicall();
+        let mut quorum_configs = Vec::with_capacity(quorum_numbers.len());
+        let mut list_handles = Vec::with_capacity(quorum_numbers.len());
+        for (&quorum_number, (params, minimum_stake, strategies_length, history_length)) in
+            quorum_numbers.iter().zip(handles)
+        {
+            let operator_set_params = results
+                .get(params)
+                .map_err(AvsRegistryError::multicall_revert_or(
+                    "RegistryCoordinator",
+                    AvsRegistryError::GetOperatorSetParams,
+                ))?
+                ._0;
+            let minimum_stake = results
+                .get(minimum_stake)
+                .map_err(AvsRegistryError::multicall_revert_or(
+                    "StakeRegistry",
+                    AvsRegistryError::GetMinimumStakeForQuorum,
+                ))?
+                ._0;
+            let strategies_length = results
+                .get(strategies_length)
+                .map_err(AvsRegistryError::multicall_revert_or(
+                    "StakeRegistry",
+                    AvsRegistryError::GetStrategyParams,
+                ))?
+                ._0;
+            let history_length = results
+                .get(history_length)
+                .map_err(AvsRegistryError::multicall_revert_or(
+                    "StakeRegistry",
+                    AvsRegistryError::GetTotalStakeHistory,
+                ))?
+                ._0;
+
+            let strategy_handles: Vec<_> = (0..strategies_length.to::<u64>())
+                .map(|index| {
+                    lists_multicall.add_call(
+                        self.stake_registry_addr,
+                        &StakeRegistry::strategyParamsByIndexCall {
+                            quorumNumber: quorum_number,
+                            index: U256::from(index),
+                        },
+                    )
+                })
+                .collect();
+            let history_handles: Vec<_> = (0..history_length.to::<u64>())
+                .map(|index| {
+                    lists_multicall.add_call(
+                        self.stake_registry_addr,
+                        &StakeRegistry::getTotalStakeUpdateAtIndexCall {
+                            quorumNumber: quorum_number,
+                            index: U256::from(index),
+                        },
+                    )
+                })
+                .collect();
+            list_handles.push((strategy_handles, history_handles));
+
+            quorum_configs.push(QuorumConfig {
+                quorum_number,
+                max_operator_count: operator_set_params.maxOperatorCount,
+                kick_bips_of_operator_stake: operator_set_params.kickBIPsOfOperatorStake,
+                kick_bips_of_total_stake: operator_set_params.kickBIPsOfTotalStake,
+                minimum_stake: U256::from(minimum_stake),
+                strategy_params: vec![],
+                total_stake_history: vec![],
+            });
+        }
+
+        let results = self
+            .call_multicall(&lists_multicall, Some(block_number))
+            .await?;
+        for (quorum_config, (strategy_handles, history_handles)) in
+            quorum_configs.iter_mut().zip(list_handles)
+        {
+            for handle in strategy_handles {
+                let params = results
+                    .get(handle)
+                    .map_err(AvsRegistryError::multicall_revert_or(
+                        "StakeRegistry",
+                        AvsRegistryError::GetStrategyParams,
+                    ))?
+                    ._0;
+                quorum_config.strategy_params.push(StrategyParams {
+                    strategy: params.strategy,
+                    multiplier: U256::from(params.multiplier),
+                });
+            }
+            for handle in history_handles {
+                let update = results
+                    .get(handle)
+                    .map_err(AvsRegistryError::multicall_revert_or(
+                        "StakeRegistry",
+                        AvsRegistryError::GetTotalStakeHistory,
+                    ))?
+                    ._0;
+                quorum_config.total_stake_history.push(StakeUpdate {
+                    update_block_number: update.updateBlockNumber,
+                    next_update_block_number: update.nextUpdateBlockNumber,
+                    stake: U256::from(update.stake),
+                });
+            }
+        }
+        Ok(quorum_configs)
+    }
+
+    /// Get the operator set params of a quorum
+    ///
+    /// # Arguments
+    ///
+    /// * `quorum_number` - The quorum number.
+    ///
+    /// # Returns
+    ///
+    /// The maximum operator count and churn ratios of the quorum read from the
+    /// RegistryCoordinator.
+    pub async fn get_operator_set_params(
+        &self,
+        quorum_number: u8,
+    ) -> Result<OperatorSetParam, AvsRegistryError> {
+        let provider = &self.provider;
+
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+
+        let RegistryCoordinator::getOperatorSetParamsReturn {
+            _0: operator_set_params,
+        } = contract_registry_coordinator
+            .getOperatorSetParams(quorum_number)
+            .call()
+            .await
+            .map_err(AvsRegistryError::revert_or(
+                "RegistryCoordinator",
+                AvsRegistryError::GetOperatorSetParams,
+            ))?;
+        Ok(operator_set_params)
+    }
+
+    /// Get the current total stake of a quorum
+    ///
+    /// # Arguments
+    ///
+    /// * `quorum_number` - The quorum number.
+    ///
+    /// # Returns
+    ///
+    /// The total stake of the quorum read from the StakeRegistry.
+    pub async fn get_current_total_stake(
+        &self,
+        quorum_number: u8,
+    ) -> Result<U256, AvsRegistryError> {
+        let provider = &self.provider;
+
+        let contract_stake_registry = StakeRegistry::new(self.stake_registry_addr, provider);
+
+        let StakeRegistry::getCurrentTotalStakeReturn { _0: total_stake } = contract_stake_registry
+            .getCurrentTotalStake(quorum_number)
+            .call()
+            .await
+            .map_err(AvsRegistryError::revert_or(
+                "StakeRegistry",
+                AvsRegistryError::GetCurrentStake,
+            ))?;
+        Ok(U256::from(total_stake))
+    }
+
+    /// Get the weight of an operator in a quorum, the stake it has or would have once
+    /// registered
+    ///
+    /// # Arguments
+    ///
+    /// * `quorum_number` - The quorum number.
+    /// * `operator` - The operator address.
+    ///
+    /// # Returns
+    ///
+    /// The weight of the operator read from the StakeRegistry.
+    pub async fn weight_of_operator_for_quorum(
+        &self,
+        quorum_number: u8,
+        operator: Address,
+    ) -> Result<U256, AvsRegistryError> {
+        let provider = &self.provider;
+
+        let contract_stake_registry = StakeRegistry::new(self.stake_registry_addr, provider);
+
+        let StakeRegistry::weightOfOperatorForQuorumReturn { _0: weight } = contract_stake_registry
+            .weightOfOperatorForQuorum(quorum_number, operator)
+            .call()
+            .await
+            .map_err(AvsRegistryError::revert_or(
+                "StakeRegistry",
+                AvsRegistryError::GetCurrentStake,
+            ))?;
+        Ok(U256::from(weight))
+    }
+
+    /// Get the weights of operators in a quorum at a block, batching the calls
+    ///
+    /// # Arguments
+    ///
+    /// * `quorum_number` - The quorum number.
+    /// * `operators` - The operator addresses.
+    /// * `block_number` - The block to read the weights at.
+    ///
+    /// # Returns
+    ///
+    /// The weight of each operator read from the StakeRegistry, in the order of `operators`.
+    pub async fn weights_of_operators_for_quorum_at_block(
+        &self,
+        quorum_number: u8,
+        operators: &[Address],
+        block_number: u32,
+    ) -> Result<Vec<U256>, AvsRegistryError> {
+        let mut multicall = self.multicall();
+        let weights: Vec<_> = operators
+            .iter()
+            .map(|&operator| {
+                multicall.add_call(
+                    self.stake_registry_addr,
+                    &StakeRegistry::weightOfOperatorForQuorumCall {
+                        quorumNumber: quorum_number,
+                        operator,
+                    },
+                )
+            })
+            .collect();
+        let results = self
+            .call_multicall(&multicall, Some(u64::from(block_number)))
+            .await?;
+
+        weights
+            .into_iter()
+            .map(|weight| {
+                let StakeRegistry::weightOfOperatorForQuorumReturn { _0: weight } = results
+                    .get(weight)
+                    .map_err(AvsRegistryError::multicall_revert_or(
+                        "StakeRegistry",
+                        AvsRegistryError::GetCurrentStake,
+                    ))?;
+                Ok(U256::from(weight))
+            })
+            .collect()
+    }
+
+    /// Get the minimum stake an operator needs to register for a quorum
+    ///
+    /// # Arguments
+    ///
+    /// * `quorum_number` - The quorum number.
+    ///
+    /// # Returns
+    ///
+    /// The minimum stake read from the StakeRegistry.
+    pub async fn get_minimum_stake_for_quorum(
+        &self,
+        quorum_number: u8,
+    ) -> Result<U256, AvsRegistryError> {
+        let provider = &self.provider;
+
+        let contract_stake_registry = StakeRegistry::new(self.stake_registry_addr, provider);
+
+        let StakeRegistry::minimumStakeForQuorumReturn { _0: minimum_stake } =
+            contract_stake_registry
+                .minimumStakeForQuorum(quorum_number)
+                .call()
+                .await
+                .map_err(AvsRegistryError::revert_or(
+                    "StakeRegistry",
+                    AvsRegistryError::GetMinimumStakeForQuorum,
+                ))?;
+        Ok(U256::from(minimum_stake))
+    }
+
+    /// Get the quorums an operator is currently registered for
+    ///
+    /// # Arguments
+    ///
+    /// * `operator_id` - The operator id.
+    ///
+    /// # Returns
+    ///
+    /// The quorum numbers of the current quorum bitmap of the operator.
+    pub async fn get_operator_quorums_at_current_block(
+        &self,
+        operator_id: FixedBytes<32>,
+    ) -> Result<Vec<u8>, AvsRegistryError> {
+        let provider = &self.provider;
+
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+
+        let RegistryCoordinator::getCurrentQuorumBitmapReturn { _0: quorum_bitmap } =
+            contract_registry_coordinator
+                .getCurrentQuorumBitmap(operator_id)
+                .call()
+                .await
+                .map_err(AvsRegistryError::revert_or(
+                    "RegistryCoordinator",
+                    AvsRegistryError::GetCurrentQuorumBitmap,
+                ))?;
+        Ok(bitmap_to_quorum_ids(U256::from(quorum_bitmap)))
+    }
+
+    /// Get the operator that registered a bls public key
+    ///
+    /// # Arguments
+    ///
+    /// * `pubkey_hash` - The hash of the G1 public key, i.e. the operator id.
+    ///
+    /// # Returns
+    ///
+    /// The address read from the BLSApkRegistry, zero if no operator registered the key.
+    pub async fn get_operator_from_pubkey_hash(
+        &self,
+        pubkey_hash: FixedBytes<32>,
+    ) -> Result<Address, AvsRegistryError> {
+        let provider = &self.provider;
+
+        let contract_bls_apk_registry = BLSApkRegistry::new(self.bls_apk_registry_addr, provider);
+
+        let BLSApkRegistry::pubkeyHashToOperatorReturn { _0: operator } = contract_bls_apk_registry
+            .pubkeyHashToOperator(pubkey_hash)
+            .call()
+            .await
+            .map_err(AvsRegistryError::revert_or(
+                "BLSApkRegistry",
+                AvsRegistryError::GetOperatorFromPubkeyHash,
+            ))?;
+        Ok(operator)
+    }
+
+    /// Check if a churn approver salt was already used
+    ///
+    /// # Arguments
+    ///
+    /// * `salt` - The salt.
+    ///
+    /// # Returns
+    ///
+    /// Whether a registration with churn already used the salt.
+    pub async fn is_churn_approver_salt_used(
+        &self,
+        salt: FixedBytes<32>,
+    ) -> Result<bool, AvsRegistryError> {
+        let provider = &self.provider;
+
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+
+        let RegistryCoordinator::isChurnApproverSaltUsedReturn { _0: is_used } =
+            contract_registry_coordinator
+                .isChurnApproverSaltUsed(salt)
+                .call()
+                .await
+                .map_err(AvsRegistryError::revert_or(
+                    "RegistryCoordinator",
+                    AvsRegistryError::IsChurnApproverSaltUsed,
+                ))?;
+        Ok(is_used)
+    }
+
+    /// Calculate the digest the churn approver signs to approve a registration with churn
+    ///
+    /// # Arguments
+    ///
+    /// * `registering_operator` - The address of the registering operator.
+    /// * `registering_operator_id` - The id of the registering operator.
+    /// * `operator_kick_params` - The operators to kick, one per quorum number.
+    /// * `salt` - The salt of the approval.
+    /// * `expiry` - The expiry of the approval.
+    ///
+    /// # Returns
+    ///
+    /// The digest hash computed by the RegistryCoordinator.
+    pub async fn calculate_operator_churn_approval_digest_hash(
+        &self,
+        registering_operator: Address,
+        registering_operator_id: FixedBytes<32>,
+        operator_kick_params: Vec<OperatorKickParam>,
+        salt: FixedBytes<32>,
+        expiry: U256,
+    ) -> Result<FixedBytes<32>, AvsRegistryError> {
+        let provider = &self.provider;
+
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+
+        let RegistryCoordinator::calculateOperatorChurnApprovalDigestHashReturn { _0: digest } =
+            contract_registry_coordinator
+                .calculateOperatorChurnApprovalDigestHash(
+                    registering_operator,
+                    registering_operator_id,
+                    operator_kick_params,
+                    salt,
+                    expiry,
+                )
+                .call()
+                .await
+                .map_err(AvsRegistryError::revert_or(
+                    "RegistryCoordinator",
+                    AvsRegistryError::CalculateOperatorChurnApprovalDigestHash,
+                ))?;
+        Ok(digest)
+    }
+
+    /// Get the stake history of an operator in a quorum
+    ///
+    /// # Arguments
+    ///
+    /// * `operator_id` - The operator id.
+    /// * `quorum_number` - The quorum number.
+    ///
+    /// # Returns
+    ///
+    /// The stake updates of the operator in the quorum read from the StakeRegistry, oldest first.
+    pub async fn get_operator_stake_history(
+        &self,
+        operator_id: B256,
+        quorum_number: u8,
+    ) -> Result<Vec<StakeUpdate>, AvsRegistryError> {
+        let provider = &self.provider;
+
+        let contract_stake_registry = StakeRegistry::new(self.stake_registry_addr, provider);
+
+        let StakeRegistry::getStakeHistoryReturn { _0: stake_history } = contract_stake_registry
+            .getStakeHistory(operator_id, quorum_number)
+            .call()
+            .await
+            .map_err(AvsRegistryError::revert_or(
+                "StakeRegistry",
+                AvsRegistryError::GetStakeHistory,
+            ))?;
+
+        Ok(stake_history
+            .into_iter()
+            .map(|update| StakeUpdate {
+                update_block_number: update.updateBlockNumber,
+                next_update_block_number: update.nextUpdateBlockNumber,
+                stake: U256::from(update.stake),
+            })
+            .collect())
+    }
+
+    /// Get the stake history of an operator in several quorums
+    ///
+    /// # Arguments
+    ///
+    /// * `operator_id` - The operator id.
+    /// * `quorum_numbers` - The quorum numbers.
+    ///
+    /// # Returns
+    ///
+    /// The stake updates of the operator in each quorum, oldest first.
+    pub async fn get_operator_stake_history_in_quorums(
+        &self,
+        operator_id: B256,
+        quorum_numbers: &[u8],
+    ) -> Result<HashMap<u8, Vec<StakeUpdate>>, AvsRegistryError> {
+        let mut stake_histories = HashMap::new();
+        for quorum_number in quorum_numbers {
+            let stake_history = self
+                .get_operator_stake_history(operator_id, *quorum_number)
+                .await?;
+            stake_histories.insert(*quorum_number, stake_history);
+        }
+        Ok(stake_histories)
+    }
+
+    /// Get the quorum bitmap history of an operator
+    ///
+    /// # Arguments
+    ///
+    /// * `operator_id` - The operator id.
+    ///
+    /// # Returns
+    ///
+    /// The updates of the quorums the operator is registered for read from the
+    /// RegistryCoordinator, oldest first.
+    pub async fn get_operator_quorum_bitmap_history(
+        &self,
+        operator_id: B256,
+    ) -> Result<Vec<QuorumBitmapUpdate>, AvsRegistryError> {
+        let provider = &self.provider;
+
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+
+        let RegistryCoordinator::getQuorumBitmapHistoryLengthReturn { _0: history_length } =
+            contract_registry_coordinator
+                .getQuorumBitmapHistoryLength(operator_id)
+                .call()
+                .await
+                .map_err(AvsRegistryError::revert_or(
+                    "RegistryCoordinator",
+                    AvsRegistryError::GetQuorumBitmapHistory,
+                ))?;
+
+        let mut quorum_bitmap_history = vec![];
+        for index in 0..history_length.to::<u64>() {
+            let RegistryCoordinator::getQuorumBitmapUpdateByIndexReturn { _0: update } =
+                contract_registry_coordinator
+                    .getQuorumBitmapUpdateByIndex(operator_id, U256::from(index))
+                    .call()
+                    .await
+                    .map_err(AvsRegistryError::revert_or(
+                        "RegistryCoordinator",
+                        AvsRegistryError::GetQuorumBitmapHistory,
+                    ))?;
+            quorum_bitmap_history.push(QuorumBitmapUpdate {
+                update_block_number: update.updateBlockNumber,
+                next_update_block_number: update.nextUpdateBlockNumber,
+                quorum_bitmap: U256::from(update.quorumBitmap),
+            });
+        }
+        Ok(quorum_bitmap_history)
+    }
+
     /// Get operators stake in quorums at block operator id
     ///
     /// # Arguments
@@ -241,7 +971,7 @@ impl AvsRegistryChainReader {
         block_number: u32,
         operator_id: B256,
     ) -> Result<(U256, Vec<Vec<OperatorStateRetriever::Operator>>), AvsRegistryError> {
-        let provider = get_provider(&self.provider);
+        let provider = &self.provider;
 
         let contract_operator_state_retriever =
             OperatorStateRetriever::new(self.operator_state_retriever, provider);
@@ -251,9 +981,10 @@ impl AvsRegistryChainReader {
                 .getOperatorState_1(self.registry_coordinator_addr, operator_id, block_number)
                 .call()
                 .await
-                .map_err(|_| {
-                    AvsRegistryError::GetOperatorStateWithRegistryCoordinatorAndOperatorId
-                })?;
+                .map_err(AvsRegistryError::revert_or(
+                    "OperatorStateRetriever",
+                    AvsRegistryError::GetOperatorStateWithRegistryCoordinatorAndOperatorId,
+                ))?;
 
         let OperatorStateRetriever::getOperatorState_1Return {
             _0: stake,
@@ -276,7 +1007,7 @@ impl AvsRegistryChainReader {
         &self,
         quorum_numbers: Bytes,
     ) -> Result<Vec<Vec<OperatorStateRetriever::Operator>>, AvsRegistryError> {
-        let provider = get_provider(&self.provider);
+        let provider = &self.provider;
 
         let current_block_number = provider
             .get_block_number()
@@ -289,7 +1020,10 @@ impl AvsRegistryChainReader {
 
         self.get_operators_stake_in_quorums_at_block(current_block_number as u32, quorum_numbers)
             .await
-            .map_err(|_| AvsRegistryError::GetOperatorStakeInQuorumAtBlockNumber)
+            .map_err(|err| match err {
+                AvsRegistryError::ContractReverted(_) => err,
+                _ => AvsRegistryError::GetOperatorStakeInQuorumAtBlockNumber,
+            })
     }
 
     /// Get operators stake in quorums of operator at block
@@ -313,7 +1047,10 @@ impl AvsRegistryChainReader {
         let (quorum_bitmaps, operator_stakes) = self
             .get_operators_stake_in_quorums_at_block_operator_id(block_number, operator_id)
             .await
-            .map_err(|_| AvsRegistryError::GetOperatorStakeInQuorumAtBlockOperatorId)?;
+            .map_err(|err| match err {
+                AvsRegistryError::ContractReverted(_) => err,
+                _ => AvsRegistryError::GetOperatorStakeInQuorumAtBlockOperatorId,
+            })?;
 
         let quorums = bitmap_to_quorum_ids(quorum_bitmaps);
         let s = (quorums, operator_stakes);
@@ -335,7 +1072,7 @@ impl AvsRegistryChainReader {
         &self,
         operator_id: B256,
     ) -> Result<(Vec<u8>, Vec<Vec<OperatorStateRetriever::Operator>>), AvsRegistryError> {
-        let provider = get_provider(&self.provider);
+        let provider = &self.provider;
 
         let current_block_number = provider.get_block_number().await.map_err(|e| {
             AvsRegistryError::AlloyContractError(alloy_contract::Error::TransportError(e))
@@ -366,31 +1103,56 @@ impl AvsRegistryChainReader {
         &self,
         operator_id: B256,
     ) -> Result<HashMap<u8, BigInt>, AvsRegistryError> {
-        let provider = get_provider(&self.provider);
-
-        let registry_coordinator =
-            RegistryCoordinator::new(self.registry_coordinator_addr, &provider);
-
-        let quorum_bitmap = registry_coordinator
-            .getCurrentQuorumBitmap(operator_id)
-            .call()
-            .await?;
+        let provider = &self.provider;
+        let block_number = provider
+            .get_block_number()
+            .await
+            .map_err(|_| AvsRegistryError::GetBlockNumber)?;
 
-        let RegistryCoordinator::getCurrentQuorumBitmapReturn { _0: quo } = quorum_bitmap;
+        let mut multicall = self.multicall();
+        let quorum_bitmap = multicall.add_call(
+            self.registry_coordinator_addr,
+            &RegistryCoordinator::getCurrentQuorumBitmapCall {
+                operatorId: operator_id,
+            },
+        );
+        let RegistryCoordinator::getCurrentQuorumBitmapReturn { _0: quo } = self
+            .call_multicall(&multicall, Some(block_number))
+            .await?
+            .get(quorum_bitmap)
+            .map_err(AvsRegistryError::multicall_revert_or(
+                "RegistryCoordinator",
+                AvsRegistryError::GetCurrentQuorumBitmap,
+            ))?;
 
         let quorums = bitmap_to_quorum_ids(quo);
 
-        let mut quorum_stakes: HashMap<u8, BigInt> = HashMap::new();
+        // the stakes are read at the block of the bitmap
+        let mut multicall = self.multicall();
+        let stakes: Vec<_> = quorums
+            .iter()
+            .map(|&quorum| {
+                multicall.add_call(
+                    self.stake_registry_addr,
+                    &StakeRegistry::getCurrentStakeCall {
+                        operatorId: operator_id,
+                        quorumNumber: quorum,
+                    },
+                )
+            })
+            .collect();
+        let results = self.call_multicall(&multicall, Some(block_number)).await?;
 
-        let stake_registry = StakeRegistry::new(self.stake_registry_addr, &provider);
-        for quorum in quorums.iter() {
-            let stakes_result = stake_registry
-                .getCurrentStake(operator_id, *quorum)
-                .call()
-                .await?;
-
-            let StakeRegistry::getCurrentStakeReturn { _0: c_stake } = stakes_result;
-            quorum_stakes.insert(*quorum, c_stake.into());
+        let mut quorum_stakes: HashMap<u8, BigInt> = HashMap::new();
+        for (quorum, stake) in quorums.into_iter().zip(stakes) {
+            let StakeRegistry::getCurrentStakeReturn { _0: c_stake } =
+                results
+                    .get(stake)
+                    .map_err(AvsRegistryError::multicall_revert_or(
+                        "StakeRegistry",
+                        AvsRegistryError::GetCurrentStake,
+                    ))?;
+            quorum_stakes.insert(quorum, c_stake.into());
         }
         Ok(quorum_stakes)
     }
@@ -408,7 +1170,7 @@ impl AvsRegistryChainReader {
         &self,
         operator_address: Address,
     ) -> Result<FixedBytes<32>, AvsRegistryError> {
-        let provider = get_provider(&self.provider);
+        let provider = &self.provider;
 
         let contract_registry_coordinator =
             RegistryCoordinator::new(self.registry_coordinator_addr, provider);
@@ -416,7 +1178,8 @@ impl AvsRegistryChainReader {
         let operator_id_return = contract_registry_coordinator
             .getOperatorId(operator_address)
             .call()
-            .await?;
+            .await
+            .map_err(AvsRegistryError::revert("RegistryCoordinator"))?;
         let RegistryCoordinator::getOperatorIdReturn { _0: operator_id } = operator_id_return;
         Ok(operator_id)
     }
@@ -434,7 +1197,7 @@ impl AvsRegistryChainReader {
         &self,
         operator_address: Address,
     ) -> Result<bool, AvsRegistryError> {
-        let provider = get_provider(&self.provider);
+        let provider = &self.provider;
 
         let contract_registry_coordinator =
             RegistryCoordinator::new(self.registry_coordinator_addr, provider);
@@ -442,13 +1205,14 @@ impl AvsRegistryChainReader {
         let operator_status_return = contract_registry_coordinator
             .getOperatorStatus(operator_address)
             .call()
-            .await?;
+            .await
+            .map_err(AvsRegistryError::revert("RegistryCoordinator"))?;
 
         let RegistryCoordinator::getOperatorStatusReturn {
             _0: operator_status,
         } = operator_status_return;
 
-        Ok(operator_status == 1)
+        Ok(operator_status == OperatorStatus::Registered as u8)
     }
 
     /// Queries existing operators from for a particular block range.
@@ -473,57 +1237,45 @@ impl AvsRegistryChainReader {
             AvsRegistryError::AlloyContractError(alloy_contract::Error::TransportError(e))
         })?;
 
-        let query_block_range = 1024;
-        let current_block_number = provider.get_block_number().await.map_err(|e| {
-            AvsRegistryError::AlloyContractError(alloy_contract::Error::TransportError(e))
-        })?;
         if stop_block.is_zero() {
-            stop_block = current_block_number;
-        }
-        let mut i = start_block;
-        let mut operator_addresses: Vec<Address> = vec![];
-        let mut operator_pub_keys: Vec<OperatorPubKeys> = vec![];
-
-        while i <= stop_block {
-            let to_block = std::cmp::min(i + (query_block_range - 1), stop_block);
-            let filter = Filter::new()
-                .select(i..to_block)
-                .event(NEW_PUBKEY_REGISTRATION_EVENT)
-                .address(self.bls_apk_registry_addr);
-
-            let logs = provider.get_logs(&filter).await.map_err(|e| {
+            stop_block = provider.get_block_number().await.map_err(|e| {
                 AvsRegistryError::AlloyContractError(alloy_contract::Error::TransportError(e))
             })?;
+        }
 
-            let len = logs.len();
-            self.logger.debug(
-                &format!("numTransactionLogs: {len}, fromBlock: {i}, toBlock: {to_block}",),
-                "eigen-client-avsregistry.reader.query_existing_registered_operator_pub_keys",
-            );
-
-            for pub_key_reg in logs
-                .iter()
-                .map(|v| v.log_decode::<BLSApkRegistry::NewPubkeyRegistration>())
-                .filter_map(Result::ok)
-            {
-                let data = pub_key_reg.data();
-                let operator_addr = data.operator;
-                operator_addresses.push(operator_addr);
-                let g1_pub_key = data.pubkeyG1.clone();
-                let g2_pub_key = data.pubkeyG2.clone();
-                let operator_pub_key = OperatorPubKeys {
-                    g1_pub_key: BlsG1Point::new(alloy_registry_g1_point_to_g1_affine(g1_pub_key)),
-                    g2_pub_key: BlsG2Point::new(alloy_registry_g2_point_to_g2_affine(g2_pub_key)),
-                };
-                operator_pub_keys.push(operator_pub_key);
-            }
-            i += query_block_range;
+        let pub_key_registrations = LogScanner::new()
+            .scan_events::<BLSApkRegistry::NewPubkeyRegistration, _, _>(
+                &provider,
+                &Filter::new().address(self.bls_apk_registry_addr),
+                start_block,
+                stop_block,
+            )
+            .await?;
+        self.logger.debug(
+            &format!(
+                "numTransactionLogs: {}, fromBlock: {start_block}, toBlock: {stop_block}",
+                pub_key_registrations.len()
+            ),
+            "eigen-client-avsregistry.reader.query_existing_registered_operator_pub_keys",
+        );
+
+        let mut operator_addresses: Vec<Address> = vec![];
+        let mut operator_pub_keys: Vec<OperatorPubKeys> = vec![];
+        for pub_key_reg in pub_key_registrations {
+            let data = pub_key_reg.data();
+            operator_addresses.push(data.operator);
+            let g1_pub_key = data.pubkeyG1.clone();
+            let g2_pub_key = data.pubkeyG2.clone();
+            let operator_pub_key = OperatorPubKeys {
+                g1_pub_key: BlsG1Point::new(alloy_registry_g1_point_to_g1_affine(g1_pub_key)),
+                g2_pub_key: BlsG2Point::new(alloy_registry_g2_point_to_g2_affine(g2_pub_key)),
+            };
+            operator_pub_keys.push(operator_pub_key);
         }
 
         Ok((operator_addresses, operator_pub_keys))
     }
 
-    /// TODO: Update bindings and then update this function
     /// Query existing operator sockets
     ///
     /// # Arguments
@@ -540,53 +1292,38 @@ impl AvsRegistryChainReader {
         start_block: u64,
         stop_block: u64,
     ) -> Result<HashMap<FixedBytes<32>, String>, AvsRegistryError> {
-        let provider = get_provider(&self.provider);
-
-        let mut operator_id_to_socket = HashMap::new();
-
-        let query_block_range = 10000;
-
-        let mut i = start_block;
+        let provider = &self.provider;
 
-        while i <= stop_block {
-            let mut to_block = i + (query_block_range - 1);
-            if to_block > stop_block {
-                to_block = stop_block;
-            }
-
-            let mut filter = Filter::new()
-                .select(start_block..to_block)
-                .event("OperatorSocketUpdate(bytes32,string)")
-                .address(self.registry_coordinator_addr);
-            if stop_block == 0 {
-                let current_block_number = provider.get_block_number().await.map_err(|e| {
-                    AvsRegistryError::AlloyContractError(alloy_contract::Error::TransportError(e))
-                })?;
-                filter = filter.clone().select(start_block..current_block_number);
-            };
-
-            let logs = provider.get_logs(&filter).await.map_err(|e| {
+        let stop_block = if stop_block == 0 {
+            provider.get_block_number().await.map_err(|e| {
                 AvsRegistryError::AlloyContractError(alloy_contract::Error::TransportError(e))
-            })?;
-
-            for v_log in logs.iter() {
-                let socket_update_filter_option = v_log
-                    .log_decode::<RegistryCoordinator::OperatorSocketUpdate>()
-                    .ok();
-                if let Some(socket_update_filter) = socket_update_filter_option {
-                    let data = socket_update_filter.data();
-                    let operator_id = data.operatorId;
-                    let socket = &data.socket;
-                    operator_id_to_socket.insert(operator_id, socket.clone());
-                }
-            }
-            let len = logs.len();
-            self.logger.debug(
-                &format!("num_transaction_logs : {len} , from_block: {i} , to_block: {to_block}"),
-                "eigen-client-avsregistry.reader.query_existing_registered_operator_sockets",
-            );
-
-            i += query_block_range;
+            })?
+        } else {
+            stop_block
+        };
+
+        let socket_updates = LogScanner::new()
+            .with_chunk_size(10000)
+            .scan_events::<RegistryCoordinator::OperatorSocketUpdate, _, _>(
+                provider,
+                &Filter::new().address(self.registry_coordinator_addr),
+                start_block,
+                stop_block,
+            )
+            .await?;
+        self.logger.debug(
+            &format!(
+                "num_transaction_logs : {} , from_block: {start_block} , to_block: {stop_block}",
+                socket_updates.len()
+            ),
+            "eigen-client-avsregistry.reader.query_existing_registered_operator_sockets",
+        );
+
+        // later updates override the earlier ones
+        let mut operator_id_to_socket = HashMap::new();
+        for socket_update in socket_updates {
+            let data = socket_update.data();
+            operator_id_to_socket.insert(data.operatorId, data.socket.clone());
         }
         Ok(operator_id_to_socket)
     }
@@ -596,6 +1333,11 @@ impl AvsRegistryChainReader {
 mod tests {
     use super::*;
     use eigen_logging::get_test_logger;
+    use eigen_testing_utils::anvil_constants::{
+        get_operator_state_retriever_address, get_registry_coordinator_address, ANVIL_HTTP_URL,
+        ANVIL_RPC_URL,
+    };
+    use eigen_testing_utils::multicall::deploy_multicall3;
     use hex::FromHex;
     use std::str::FromStr;
     const HOLESKY_REGISTRY_COORDINATOR: &str = "0x53012C69A189cfA2D9d29eb6F19B32e0A2EA3490";
@@ -625,6 +1367,42 @@ mod tests {
         let _ = avs_reader.get_quorum_count().await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_get_quorum_configs_at_block() {
+        let avs_reader = build_avs_registry_chain_reader().await;
+
+        let quorum_configs = avs_reader
+            .get_quorum_configs_at_block(1245063)
+            .await
+            .unwrap();
+        for (quorum_number, quorum_config) in quorum_configs.iter().enumerate() {
+            assert_eq!(quorum_config.quorum_number as usize, quorum_number);
+            assert!(!quorum_config.strategy_params.is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_operator_stake_and_quorum_bitmap_history() {
+        let avs_reader = build_avs_registry_chain_reader().await;
+        let operator_id = U256::from_str(
+            "35344093966194310405039483339636912150346494903629410125452342281826147822033",
+        )
+        .unwrap();
+
+        let quorum_bitmap_history = avs_reader
+            .get_operator_quorum_bitmap_history(operator_id.into())
+            .await
+            .unwrap();
+        assert!(!quorum_bitmap_history.is_empty());
+
+        let quorum_numbers = quorum_bitmap_history[0].quorum_numbers();
+        let stake_histories = avs_reader
+            .get_operator_stake_history_in_quorums(operator_id.into(), &quorum_numbers)
+            .await
+            .unwrap();
+        assert_eq!(stake_histories.len(), quorum_numbers.len());
+    }
+
     #[tokio::test]
     async fn test_get_operators_stake_in_quorums_at_block() {
         let avs_reader = build_avs_registry_chain_reader().await;
@@ -676,4 +1454,34 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn test_get_quorum_configs_at_block_through_multicall3() {
+        deploy_multicall3().await;
+        let avs_reader = AvsRegistryChainReader::new(
+            get_test_logger(),
+            get_registry_coordinator_address().await,
+            get_operator_state_retriever_address().await,
+            ANVIL_HTTP_URL.to_string(),
+        )
+        .await
+        .unwrap();
+        let block_number = ANVIL_RPC_URL.get_block_number().await.unwrap();
+
+        let quorum_configs = avs_reader
+            .get_quorum_configs_at_block(block_number as u32)
+            .await
+            .unwrap();
+
+        assert!(!quorum_configs.is_empty());
+        let operator_set_params = avs_reader.get_operator_set_params(0).await.unwrap();
+        assert_eq!(
+            quorum_configs[0].max_operator_count,
+            operator_set_params.maxOperatorCount
+        );
+        assert_eq!(
+            quorum_configs[0].minimum_stake,
+            avs_reader.get_minimum_stake_for_quorum(0).await.unwrap()
+        );
+    }
 }
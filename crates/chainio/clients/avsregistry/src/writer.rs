@@ -1,28 +1,68 @@
+use crate::churn::{
+    select_operator_to_kick, ChurnApprovalRequest, ChurnApproverSignatureSource, QuorumChurnState,
+};
 use crate::error::AvsRegistryError;
-use alloy_primitives::{Address, Bytes, FixedBytes, TxHash, U256};
-use alloy_signer::Signer;
+use alloy_network::{EthereumWallet, TxSigner};
+use alloy_primitives::{Address, Bytes, FixedBytes, U256};
+use alloy_provider::Provider;
+use alloy_rpc_types::TransactionRequest;
+use alloy_signer::{Signature, Signer};
 use alloy_signer_local::PrivateKeySigner;
+use alloy_sol_types::SolEventInterface;
+use eigen_chainio_txmanager::{
+    simple_tx_manager::SimpleTxManager,
+    tx_manager::{TxManager, TxOutcome},
+};
 use eigen_client_elcontracts::reader::ELChainReader;
 use eigen_crypto_bls::{
     alloy_g1_point_to_g1_affine, convert_to_g1_point, convert_to_g2_point, BlsKeyPair,
 };
 use eigen_logging::logger::SharedLogger;
+use eigen_types::operator::{bitmap_to_quorum_ids, operator_id_from_g1_pub_key, OperatorStatus};
 use eigen_utils::binding::RegistryCoordinator::{
-    self, G1Point as RegistryG1Point, G2Point as RegistryG2Point, PubkeyRegistrationParams,
+    self, G1Point as RegistryG1Point, G2Point as RegistryG2Point, OperatorKickParam,
+    PubkeyRegistrationParams, RegistryCoordinatorEvents,
 };
+use eigen_utils::binding::StakeRegistry::StakeRegistryEvents;
 use eigen_utils::{
-    binding::{ServiceManagerBase, StakeRegistry},
-    get_provider, get_signer,
+    binding::{BLSApkRegistry, OperatorStateRetriever, ServiceManagerBase, StakeRegistry},
+    get_shared_provider, SharedProvider,
 };
+use std::fmt;
 use std::str::FromStr;
+use std::sync::Arc;
 use tracing::info;
 use RegistryCoordinator::SignatureWithSaltAndExpiry;
 
 /// Gas limit for registerOperator in [`RegistryCoordinator`]
 pub const GAS_LIMIT_REGISTER_OPERATOR_REGISTRY_COORDINATOR: u128 = 2000000;
 
-/// AvsRegistry Writer
+/// Result of [`AvsRegistryChainWriter::register_operator_in_additional_quorums`]
 #[derive(Debug)]
+pub struct QuorumRegistration {
+    /// The registration transaction
+    pub outcome: TxOutcome<RegistryCoordinatorEvents>,
+    /// Id of the operator, derived from its bls public key
+    pub operator_id: FixedBytes<32>,
+    /// Quorums the operator registered for
+    pub quorum_numbers: Bytes,
+    /// Whether the transaction registers the bls public key of the operator, `false` when the
+    /// bls apk registry already knew it
+    pub registers_pubkey: bool,
+}
+
+/// Result of [`AvsRegistryChainWriter::update_socket`]
+#[derive(Debug)]
+pub struct SocketUpdate {
+    /// The update socket transaction
+    pub outcome: TxOutcome<RegistryCoordinatorEvents>,
+    /// Id of the operator
+    pub operator_id: FixedBytes<32>,
+    /// The new socket of the operator
+    pub socket: String,
+}
+
+/// AvsRegistry Writer
 pub struct AvsRegistryChainWriter {
     logger: SharedLogger,
     service_manager_addr: Address,
@@ -31,8 +71,31 @@ pub struct AvsRegistryChainWriter {
     stake_registry_addr: Address,
     bls_apk_registry_addr: Address,
     el_reader: ELChainReader,
-    provider: String,
-    signer: String,
+    provider: SharedProvider,
+    /// Signs and sends the transactions
+    tx_manager: Arc<dyn TxManager>,
+    /// Signs the operator registration digests, unset when the writer was built with a signer
+    /// that can only sign transactions
+    signer: Option<Arc<dyn Signer + Send + Sync>>,
+}
+
+impl fmt::Debug for AvsRegistryChainWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AvsRegistryChainWriter")
+            .field("logger", &self.logger)
+            .field("service_manager_addr", &self.service_manager_addr)
+            .field("registry_coordinator_addr", &self.registry_coordinator_addr)
+            .field(
+                "operator_state_retriever_addr",
+                &self.operator_state_retriever_addr,
+            )
+            .field("stake_registry_addr", &self.stake_registry_addr)
+            .field("bls_apk_registry_addr", &self.bls_apk_registry_addr)
+            .field("el_reader", &self.el_reader)
+            .field("provider", &self.provider)
+            .field("tx_manager", &self.tx_manager)
+            .finish_non_exhaustive()
+    }
 }
 
 impl AvsRegistryChainWriter {
@@ -42,7 +105,7 @@ impl AvsRegistryChainWriter {
     ///
     /// * `logger` - SharedLogger used for logging
     /// * `provider` - provider string
-    /// * `signer` - signer string
+    /// * `signer` - private key of the operator
     /// * `registry_coordinator_addr` - registry coordinator address
     /// * `operator_state_retriever_addr` - operator state retriever address
     ///
@@ -52,7 +115,8 @@ impl AvsRegistryChainWriter {
     ///
     /// # Errors
     ///
-    /// * `AvsRegistryError` - if any error occurs
+    /// * `AvsRegistryError::InvalidPrivateKey` - if the private key can't be parsed
+    /// * `AvsRegistryError` - if any other error occurs
     pub async fn build_avs_registry_chain_writer(
         logger: SharedLogger,
         provider: String,
@@ -60,54 +124,208 @@ impl AvsRegistryChainWriter {
         registry_coordinator_addr: Address,
         operator_state_retriever_addr: Address,
     ) -> Result<Self, AvsRegistryError> {
-        let fill_provider = get_provider(&provider);
+        let signer =
+            PrivateKeySigner::from_str(&signer).map_err(|_| AvsRegistryError::InvalidPrivateKey)?;
+        Self::build_avs_registry_chain_writer_with_signer(
+            logger,
+            provider,
+            signer,
+            registry_coordinator_addr,
+            operator_state_retriever_addr,
+        )
+        .await
+    }
+
+    /// build avs registry chain writer instance with a signer of both transactions and messages,
+    /// e.g. a local or keystore key, or an AWS KMS key
+    ///
+    /// # Arguments
+    ///
+    /// * `logger` - SharedLogger used for logging
+    /// * `provider` - provider string
+    /// * `signer` - signer of the operator
+    /// * `registry_coordinator_addr` - registry coordinator address
+    /// * `operator_state_retriever_addr` - operator state retriever address
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self, AvsRegistryError>` - a new AvsRegistryChainWriter
+    ///
+    /// # Errors
+    ///
+    /// * `AvsRegistryError` - if any error occurs
+    pub async fn build_avs_registry_chain_writer_with_signer<S>(
+        logger: SharedLogger,
+        provider: String,
+        signer: S,
+        registry_coordinator_addr: Address,
+        operator_state_retriever_addr: Address,
+    ) -> Result<Self, AvsRegistryError>
+    where
+        S: Signer + TxSigner<Signature> + Clone + Send + Sync + 'static,
+    {
+        Self::build_avs_registry_chain_writer_with_provider(
+            logger,
+            get_shared_provider(&provider)
+                .map_err(|_| AvsRegistryError::InvalidRpcUrl(provider.clone()))?,
+            signer,
+            registry_coordinator_addr,
+            operator_state_retriever_addr,
+        )
+        .await
+    }
+
+    /// build avs registry chain writer instance reading and sending its transactions through
+    /// `provider`, with a signer of both transactions and messages
+    ///
+    /// # Arguments
+    ///
+    /// * `logger` - SharedLogger used for logging
+    /// * `provider` - provider, shared with the other clients built with it
+    /// * `signer` - signer of the operator
+    /// * `registry_coordinator_addr` - registry coordinator address
+    /// * `operator_state_retriever_addr` - operator state retriever address
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self, AvsRegistryError>` - a new AvsRegistryChainWriter
+    ///
+    /// # Errors
+    ///
+    /// * `AvsRegistryError` - if any error occurs
+    pub async fn build_avs_registry_chain_writer_with_provider<S>(
+        logger: SharedLogger,
+        provider: SharedProvider,
+        signer: S,
+        registry_coordinator_addr: Address,
+        operator_state_retriever_addr: Address,
+    ) -> Result<Self, AvsRegistryError>
+    where
+        S: Signer + TxSigner<Signature> + Clone + Send + Sync + 'static,
+    {
+        Self::build(
+            logger,
+            provider,
+            EthereumWallet::from(signer.clone()),
+            Some(Arc::new(signer)),
+            registry_coordinator_addr,
+            operator_state_retriever_addr,
+        )
+        .await
+    }
 
+    /// build avs registry chain writer instance with a signer that can only sign transactions,
+    /// e.g. a Web3Signer
+    ///
+    /// The writer can update stakes and sockets and deregister the operator, but registering the
+    /// operator fails with `AvsRegistryError::MessageSignerUnavailable`, as it needs the operator
+    /// to sign the registration digest.
+    ///
+    /// # Arguments
+    ///
+    /// * `logger` - SharedLogger used for logging
+    /// * `provider` - provider string
+    /// * `signer` - transaction signer of the operator
+    /// * `registry_coordinator_addr` - registry coordinator address
+    /// * `operator_state_retriever_addr` - operator state retriever address
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self, AvsRegistryError>` - a new AvsRegistryChainWriter
+    ///
+    /// # Errors
+    ///
+    /// * `AvsRegistryError` - if any error occurs
+    pub async fn build_avs_registry_chain_writer_with_tx_signer<S>(
+        logger: SharedLogger,
+        provider: String,
+        signer: S,
+        registry_coordinator_addr: Address,
+        operator_state_retriever_addr: Address,
+    ) -> Result<Self, AvsRegistryError>
+    where
+        S: TxSigner<Signature> + Send + Sync + 'static,
+    {
+        Self::build(
+            logger,
+            get_shared_provider(&provider)
+                .map_err(|_| AvsRegistryError::InvalidRpcUrl(provider.clone()))?,
+            EthereumWallet::from(signer),
+            None,
+            registry_coordinator_addr,
+            operator_state_retriever_addr,
+        )
+        .await
+    }
+
+    async fn build(
+        logger: SharedLogger,
+        provider: SharedProvider,
+        wallet: EthereumWallet,
+        signer: Option<Arc<dyn Signer + Send + Sync>>,
+        registry_coordinator_addr: Address,
+        operator_state_retriever_addr: Address,
+    ) -> Result<Self, AvsRegistryError> {
         let contract_registry_coordinator =
-            RegistryCoordinator::new(registry_coordinator_addr, &fill_provider);
+            RegistryCoordinator::new(registry_coordinator_addr, &provider);
 
         let service_manager_addr = contract_registry_coordinator
             .serviceManager()
             .call()
             .await
-            .map_err(AvsRegistryError::AlloyContractError)?;
+            .map_err(AvsRegistryError::revert("RegistryCoordinator"))?;
 
         let RegistryCoordinator::serviceManagerReturn {
             _0: service_manager,
         } = service_manager_addr;
-        let contract_service_manager_base =
-            ServiceManagerBase::new(service_manager, &fill_provider);
+        let contract_service_manager_base = ServiceManagerBase::new(service_manager, &provider);
 
         let bls_apk_registry_addr_result = contract_registry_coordinator
             .blsApkRegistry()
             .call()
             .await
-            .map_err(AvsRegistryError::AlloyContractError)?;
+            .map_err(AvsRegistryError::revert("RegistryCoordinator"))?;
 
         let RegistryCoordinator::blsApkRegistryReturn {
             _0: bls_apk_registry,
         } = bls_apk_registry_addr_result;
-        let stake_registry_addr = contract_registry_coordinator.stakeRegistry().call().await?;
+        let stake_registry_addr = contract_registry_coordinator
+            .stakeRegistry()
+            .call()
+            .await
+            .map_err(AvsRegistryError::revert("RegistryCoordinator"))?;
         let RegistryCoordinator::stakeRegistryReturn { _0: stake_registry } = stake_registry_addr;
-        let contract_stake_registry = StakeRegistry::new(stake_registry, &fill_provider);
+        let contract_stake_registry = StakeRegistry::new(stake_registry, &provider);
 
-        let delegation_manager_return = contract_stake_registry.delegation().call().await?;
+        let delegation_manager_return = contract_stake_registry
+            .delegation()
+            .call()
+            .await
+            .map_err(AvsRegistryError::revert("StakeRegistry"))?;
 
         let StakeRegistry::delegationReturn {
             _0: delegation_manager_addr,
         } = delegation_manager_return;
-        let avs_directory_addr = contract_service_manager_base.avsDirectory().call().await?;
+        let avs_directory_addr = contract_service_manager_base
+            .avsDirectory()
+            .call()
+            .await
+            .map_err(AvsRegistryError::revert("ServiceManagerBase"))?;
 
         let ServiceManagerBase::avsDirectoryReturn { _0: avs_directory } = avs_directory_addr;
 
-        let el_reader = ELChainReader::build(
+        let el_reader = ELChainReader::build_with_provider(
             logger.clone(),
             delegation_manager_addr,
             avs_directory,
-            &provider,
+            provider.clone(),
         )
         .await
         .map_err(|e| AvsRegistryError::ElContractsError(e.to_string()))?;
 
+        let tx_manager =
+            SimpleTxManager::new_with_provider(logger.clone(), 1.0, wallet, provider.clone());
+
         Ok(AvsRegistryChainWriter {
             logger,
             service_manager_addr: service_manager,
@@ -116,11 +334,61 @@ impl AvsRegistryChainWriter {
             stake_registry_addr: stake_registry,
             bls_apk_registry_addr: bls_apk_registry,
             el_reader,
-            provider: provider.clone(),
-            signer: signer.clone(),
+            provider,
+            tx_manager: Arc::new(tx_manager),
+            signer,
         })
     }
 
+    /// Send the transactions through `tx_manager` instead of the default [`SimpleTxManager`]
+    ///
+    /// The registration digests are still signed by the signer of the writer, so `tx_manager`
+    /// must send the transactions from the same operator address.
+    pub fn with_tx_manager(mut self, tx_manager: Arc<dyn TxManager>) -> Self {
+        self.tx_manager = tx_manager;
+        self
+    }
+
+    /// Get the address of the operator sending the transactions
+    pub fn signer_address(&self) -> Address {
+        self.tx_manager.sender()
+    }
+
+    /// Send a transaction through the tx manager and wait for it to be mined
+    ///
+    /// # Arguments
+    ///
+    /// * `tx` - the transaction
+    /// * `contract` - the contract whose events are decoded from the receipt
+    ///
+    /// # Errors
+    ///
+    /// * `AvsRegistryError::ContractReverted` - if the transaction reverts when estimating its gas
+    /// * `AvsRegistryError::TxReverted` - if the transaction reverted
+    async fn send_tx<E: SolEventInterface>(
+        &self,
+        mut tx: TransactionRequest,
+        contract: Address,
+    ) -> Result<TxOutcome<E>, AvsRegistryError> {
+        // every transaction of the writer calls the registry coordinator
+        let receipt = self
+            .tx_manager
+            .send_tx(&mut tx)
+            .await
+            .map_err(AvsRegistryError::tx_revert("RegistryCoordinator"))?;
+        if !receipt.status() {
+            return Err(AvsRegistryError::TxReverted(receipt.transaction_hash));
+        }
+        Ok(TxOutcome::new(receipt, contract))
+    }
+
+    /// Get the signer of the operator registration digests
+    fn message_signer(&self) -> Result<&(dyn Signer + Send + Sync), AvsRegistryError> {
+        self.signer
+            .as_deref()
+            .ok_or(AvsRegistryError::MessageSignerUnavailable)
+    }
+
     /// Register operator in quorum with avs registry coordinator
     ///
     /// # Arguments
@@ -133,7 +401,8 @@ impl AvsRegistryChainWriter {
     ///
     /// # Returns
     ///
-    /// * `Result<TxHash, AvsRegistryError>` - transaction hash of the register operator transaction
+    /// * `Result<TxOutcome<RegistryCoordinatorEvents>, AvsRegistryError>` - receipt and events of
+    ///   the mined register operator transaction
     pub async fn register_operator_in_quorum_with_avs_registry_coordinator(
         &self,
         bls_key_pair: BlsKeyPair,
@@ -141,20 +410,401 @@ impl AvsRegistryChainWriter {
         operator_to_avs_registration_sig_expiry: U256,
         quorum_numbers: Bytes,
         socket: String,
-    ) -> Result<TxHash, AvsRegistryError> {
-        let provider = get_signer(self.signer.clone(), &self.provider);
-        let wallet = PrivateKeySigner::from_str(&self.signer)
-            .map_err(|_| AvsRegistryError::InvalidPrivateKey)?;
+    ) -> Result<TxOutcome<RegistryCoordinatorEvents>, AvsRegistryError> {
+        let provider = &self.provider;
+        let wallet = self.message_signer()?;
 
         // tracing info
         info!(avs_service_manager = %self.service_manager_addr, operator= %wallet.address(),quorum_numbers = ?quorum_numbers,"quorum_numbers,registering operator with the AVS's registry coordinator");
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+
+        let (pub_key_reg_params, operator_signature_with_salt_and_expiry) = self
+            .registration_params(
+                wallet,
+                &bls_key_pair,
+                operator_to_avs_registration_sig_salt,
+                operator_to_avs_registration_sig_expiry,
+            )
+            .await?;
+
+        let contract_call = contract_registry_coordinator.registerOperator(
+            quorum_numbers.clone(),
+            socket,
+            pub_key_reg_params,
+            operator_signature_with_salt_and_expiry,
+        );
+
+        let tx_call = contract_call.gas(GAS_LIMIT_REGISTER_OPERATOR_REGISTRY_COORDINATOR);
+        let outcome = self
+            .send_tx(
+                tx_call.into_transaction_request(),
+                self.registry_coordinator_addr,
+            )
+            .await?;
+
+        info!(tx_hash = %outcome.tx_hash(), "registered operator with the AVS's registry coordinator");
+        Ok(outcome)
+    }
+
+    /// Register operator in quorum with avs registry coordinator, kicking operators from the
+    /// quorums that are full
+    ///
+    /// The operator to kick from each full quorum is the one with the lowest stake, as long as
+    /// it meets the churn ratios set on the registry coordinator.
+    ///
+    /// # Arguments
+    ///
+    /// * `bls_key_pair` - bls key pair of the operator
+    /// * `operator_to_avs_registration_sig_salt` - salt for the signature
+    /// * `operator_to_avs_registration_sig_expiry` - expiry for the signature
+    /// * `quorum_numbers` - quorum numbers
+    /// * `socket` - socket used for calling the contract with `registerOperatorWithChurn` function
+    /// * `churn_approver` - source of the churn approver signature
+    ///
+    /// # Returns
+    ///
+    /// * `Result<TxOutcome<RegistryCoordinatorEvents>, AvsRegistryError>` - receipt and events of
+    ///   the mined register operator transaction
+    ///
+    /// # Errors
+    ///
+    /// * `AvsRegistryError::InsufficientStakeForChurn` or
+    ///   `AvsRegistryError::OperatorToKickStakeTooHigh` - if no operator of a full quorum can be
+    ///   kicked, before any transaction is sent
+    pub async fn register_operator_with_churn(
+        &self,
+        bls_key_pair: BlsKeyPair,
+        operator_to_avs_registration_sig_salt: FixedBytes<32>,
+        operator_to_avs_registration_sig_expiry: U256,
+        quorum_numbers: Bytes,
+        socket: String,
+        churn_approver: &dyn ChurnApproverSignatureSource,
+    ) -> Result<TxOutcome<RegistryCoordinatorEvents>, AvsRegistryError> {
+        let provider = &self.provider;
+        let wallet = self.message_signer()?;
+
+        info!(avs_service_manager = %self.service_manager_addr, operator= %wallet.address(),quorum_numbers = ?quorum_numbers,"registering operator with churn with the AVS's registry coordinator");
+        let operator_kick_params = self
+            .operator_kick_params(wallet.address(), quorum_numbers.clone())
+            .await?;
+
+        let operator_id = operator_id_from_g1_pub_key(bls_key_pair.public_key())
+            .map_err(|_| AvsRegistryError::GetOperatorId)?;
+        let churn_approver_signature = churn_approver
+            .churn_approver_signature(&ChurnApprovalRequest {
+                operator_address: wallet.address(),
+                operator_id: operator_id.into(),
+                quorum_numbers: quorum_numbers.clone(),
+                operator_kick_params: operator_kick_params.clone(),
+            })
+            .await?;
+
+        let (pub_key_reg_params, operator_signature_with_salt_and_expiry) = self
+            .registration_params(
+                wallet,
+                &bls_key_pair,
+                operator_to_avs_registration_sig_salt,
+                operator_to_avs_registration_sig_expiry,
+            )
+            .await?;
+
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let contract_call = contract_registry_coordinator.registerOperatorWithChurn(
+            quorum_numbers,
+            socket,
+            pub_key_reg_params,
+            operator_kick_params,
+            churn_approver_signature,
+            operator_signature_with_salt_and_expiry,
+        );
+
+        let tx_call = contract_call.gas(GAS_LIMIT_REGISTER_OPERATOR_REGISTRY_COORDINATOR);
+        let outcome = self
+            .send_tx(
+                tx_call.into_transaction_request(),
+                self.registry_coordinator_addr,
+            )
+            .await?;
+
+        info!(tx_hash = %outcome.tx_hash(), "registered operator with churn with the AVS's registry coordinator");
+        Ok(outcome)
+    }
+
+    /// Build the operator kick params of a registration, one per quorum number
+    ///
+    /// # Arguments
+    ///
+    /// * `operator` - address of the registering operator
+    /// * `quorum_numbers` - quorum numbers the operator registers for
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<OperatorKickParam>` - the operator to kick from each quorum, the zero address for
+    ///   the quorums that are not full
+    pub async fn operator_kick_params(
+        &self,
+        operator: Address,
+        quorum_numbers: Bytes,
+    ) -> Result<Vec<OperatorKickParam>, AvsRegistryError> {
+        let provider = &self.provider;
+        let current_block_number = provider
+            .get_block_number()
+            .await
+            .map_err(|_| AvsRegistryError::GetBlockNumber)?;
+        let block_number: u32 = current_block_number
+            .try_into()
+            .map_err(|_| AvsRegistryError::BlockNumberOverflow)?;
+
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, &provider);
+        let contract_stake_registry = StakeRegistry::new(self.stake_registry_addr, &provider);
+        let contract_operator_state_retriever =
+            OperatorStateRetriever::new(self.operator_state_retriever_addr, &provider);
+
+        let OperatorStateRetriever::getOperatorState_0Return {
+            _0: operators_per_quorum,
+        } = contract_operator_state_retriever
+            .getOperatorState_0(
+                self.registry_coordinator_addr,
+                quorum_numbers.clone(),
+                block_number,
+            )
+            .call()
+            .await
+            .map_err(AvsRegistryError::revert_or(
+                "OperatorStateRetriever",
+                AvsRegistryError::GetOperatorState,
+            ))?;
+
+        let mut operator_kick_params = vec![];
+        for (quorum_number, operators) in quorum_numbers.iter().zip(operators_per_quorum) {
+            let RegistryCoordinator::getOperatorSetParamsReturn {
+                _0: operator_set_params,
+            } = contract_registry_coordinator
+                .getOperatorSetParams(*quorum_number)
+                .call()
+                .await
+                .map_err(AvsRegistryError::revert_or(
+                    "RegistryCoordinator",
+                    AvsRegistryError::GetOperatorSetParams,
+                ))?;
+            let StakeRegistry::getCurrentTotalStakeReturn { _0: total_stake } =
+                contract_stake_registry
+                    .getCurrentTotalStake(*quorum_number)
+                    .call()
+                    .await
+                    .map_err(AvsRegistryError::revert_or(
+                        "StakeRegistry",
+                        AvsRegistryError::GetCurrentStake,
+                    ))?;
+            let StakeRegistry::weightOfOperatorForQuorumReturn { _0: operator_stake } =
+                contract_stake_registry
+                    .weightOfOperatorForQuorum(*quorum_number, operator)
+                    .call()
+                    .await
+                    .map_err(AvsRegistryError::revert_or(
+                        "StakeRegistry",
+                        AvsRegistryError::GetCurrentStake,
+                    ))?;
+
+            let quorum = QuorumChurnState {
+                quorum_number: *quorum_number,
+                max_operator_count: operator_set_params.maxOperatorCount,
+                kick_bips_of_operator_stake: operator_set_params.kickBIPsOfOperatorStake,
+                kick_bips_of_total_stake: operator_set_params.kickBIPsOfTotalStake,
+                operators: operators
+                    .into_iter()
+                    .map(|operator| (operator.operator, U256::from(operator.stake)))
+                    .collect(),
+                total_stake: U256::from(total_stake),
+            };
+            operator_kick_params.push(select_operator_to_kick(
+                &quorum,
+                operator,
+                U256::from(operator_stake),
+            )?);
+        }
+        Ok(operator_kick_params)
+    }
+
+    /// Register operator in additional quorums with avs registry coordinator
+    ///
+    /// Unlike [`Self::register_operator_in_quorum_with_avs_registry_coordinator`], the bls public
+    /// key is only registered if the bls apk registry doesn't know it yet, e.g. when the operator
+    /// is already registered in other quorums.
+    ///
+    /// # Arguments
+    ///
+    /// * `bls_key_pair` - bls key pair of the operator
+    /// * `operator_to_avs_registration_sig_salt` - salt for the signature
+    /// * `operator_to_avs_registration_sig_expiry` - expiry for the signature
+    /// * `quorum_numbers` - quorum numbers to register for
+    /// * `socket` - socket used for calling the contract with `registerOperator` function
+    ///
+    /// # Returns
+    ///
+    /// * `Result<QuorumRegistration, AvsRegistryError>` - the registration transaction
+    ///
+    /// # Errors
+    ///
+    /// * `AvsRegistryError::OperatorAlreadyInQuorums` - if the operator is already registered for
+    ///   some of the quorums
+    /// * `AvsRegistryError::BlsPubKeyMismatch` - if the bls apk registry knows a different public
+    ///   key for the operator
+    pub async fn register_operator_in_additional_quorums(
+        &self,
+        bls_key_pair: BlsKeyPair,
+        operator_to_avs_registration_sig_salt: FixedBytes<32>,
+        operator_to_avs_registration_sig_expiry: U256,
+        quorum_numbers: Bytes,
+        socket: String,
+    ) -> Result<QuorumRegistration, AvsRegistryError> {
+        let wallet = self.message_signer()?;
+        info!(operator = %wallet.address(), quorum_numbers = %quorum_numbers, "registering operator in additional quorums with the AVS's registry coordinator");
+
+        let provider = &self.provider;
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, &provider);
+        let RegistryCoordinator::getOperatorIdReturn {
+            _0: registered_operator_id,
+        } = contract_registry_coordinator
+            .getOperatorId(wallet.address())
+            .call()
+            .await
+            .map_err(AvsRegistryError::revert_or(
+                "RegistryCoordinator",
+                AvsRegistryError::GetOperatorId,
+            ))?;
+        if registered_operator_id != FixedBytes::ZERO {
+            let RegistryCoordinator::getCurrentQuorumBitmapReturn { _0: quorum_bitmap } =
+                contract_registry_coordinator
+                    .getCurrentQuorumBitmap(registered_operator_id)
+                    .call()
+                    .await
+                    .map_err(AvsRegistryError::revert_or(
+                        "RegistryCoordinator",
+                        AvsRegistryError::GetCurrentQuorumBitmap,
+                    ))?;
+            let registered_quorums = bitmap_to_quorum_ids(U256::from(quorum_bitmap));
+            let already_registered: Vec<u8> = quorum_numbers
+                .iter()
+                .filter(|quorum_number| registered_quorums.contains(quorum_number))
+                .copied()
+                .collect();
+            if !already_registered.is_empty() {
+                return Err(AvsRegistryError::OperatorAlreadyInQuorums(
+                    already_registered,
+                ));
+            }
+        }
+
+        let operator_id: FixedBytes<32> = operator_id_from_g1_pub_key(bls_key_pair.public_key())
+            .map_err(|_| AvsRegistryError::GetOperatorId)?
+            .into();
+        let contract_bls_apk_registry = BLSApkRegistry::new(self.bls_apk_registry_addr, &provider);
+        let BLSApkRegistry::getOperatorIdReturn { _0: pubkey_hash } = contract_bls_apk_registry
+            .getOperatorId(wallet.address())
+            .call()
+            .await
+            .map_err(AvsRegistryError::revert_or(
+                "BLSApkRegistry",
+                AvsRegistryError::GetOperatorId,
+            ))?;
+        let registers_pubkey = pubkey_hash == FixedBytes::ZERO;
+        if !registers_pubkey && pubkey_hash != operator_id {
+            return Err(AvsRegistryError::BlsPubKeyMismatch);
+        }
+        let pub_key_reg_params = if registers_pubkey {
+            self.pubkey_registration_params(wallet, &bls_key_pair)
+                .await?
+        } else {
+            // ignored by the registry coordinator once the public key is registered
+            PubkeyRegistrationParams {
+                pubkeyRegistrationSignature: RegistryG1Point {
+                    X: U256::ZERO,
+                    Y: U256::ZERO,
+                },
+                pubkeyG1: RegistryG1Point {
+                    X: U256::ZERO,
+                    Y: U256::ZERO,
+                },
+                pubkeyG2: RegistryG2Point {
+                    X: [U256::ZERO; 2],
+                    Y: [U256::ZERO; 2],
+                },
+            }
+        };
+        let operator_signature_with_salt_and_expiry = self
+            .operator_signature(
+                wallet,
+                operator_to_avs_registration_sig_salt,
+                operator_to_avs_registration_sig_expiry,
+            )
+            .await?;
+
+        let contract_call = contract_registry_coordinator.registerOperator(
+            quorum_numbers.clone(),
+            socket,
+            pub_key_reg_params,
+            operator_signature_with_salt_and_expiry,
+        );
+        let tx_call = contract_call.gas(GAS_LIMIT_REGISTER_OPERATOR_REGISTRY_COORDINATOR);
+        let outcome = self
+            .send_tx(
+                tx_call.into_transaction_request(),
+                self.registry_coordinator_addr,
+            )
+            .await?;
+
+        info!(tx_hash = %outcome.tx_hash(), registers_pubkey, "registered operator in additional quorums with the AVS's registry coordinator");
+        Ok(QuorumRegistration {
+            outcome,
+            operator_id,
+            quorum_numbers,
+            registers_pubkey,
+        })
+    }
+
+    /// Build the pub key registration params and the operator signature of a registration
+    async fn registration_params(
+        &self,
+        wallet: &(dyn Signer + Send + Sync),
+        bls_key_pair: &BlsKeyPair,
+        operator_to_avs_registration_sig_salt: FixedBytes<32>,
+        operator_to_avs_registration_sig_expiry: U256,
+    ) -> Result<(PubkeyRegistrationParams, SignatureWithSaltAndExpiry), AvsRegistryError> {
+        let pub_key_reg_params = self
+            .pubkey_registration_params(wallet, bls_key_pair)
+            .await?;
+        let operator_signature_with_salt_and_expiry = self
+            .operator_signature(
+                wallet,
+                operator_to_avs_registration_sig_salt,
+                operator_to_avs_registration_sig_expiry,
+            )
+            .await?;
+        Ok((pub_key_reg_params, operator_signature_with_salt_and_expiry))
+    }
+
+    /// Build the params registering the bls public key of the operator in the bls apk registry
+    async fn pubkey_registration_params(
+        &self,
+        wallet: &(dyn Signer + Send + Sync),
+        bls_key_pair: &BlsKeyPair,
+    ) -> Result<PubkeyRegistrationParams, AvsRegistryError> {
+        let provider = &self.provider;
         let contract_registry_coordinator =
             RegistryCoordinator::new(self.registry_coordinator_addr, provider);
         let g1_hashes_msg_to_sign_return = contract_registry_coordinator
             .pubkeyRegistrationMessageHash(wallet.address())
             .call()
             .await
-            .map_err(|_| AvsRegistryError::PubKeyRegistrationMessageHash)?;
+            .map_err(AvsRegistryError::revert_or(
+                "RegistryCoordinator",
+                AvsRegistryError::PubKeyRegistrationMessageHash,
+            ))?;
 
         let RegistryCoordinator::pubkeyRegistrationMessageHashReturn {
             _0: g1_hashes_msg_to_sign,
@@ -169,11 +819,30 @@ impl AvsRegistryChainWriter {
         let g2_pub_key_bn254 = convert_to_g2_point(bls_key_pair.public_key_g2().g2())?;
 
         let pub_key_reg_params = PubkeyRegistrationParams {
-            pubkeyRegistrationSignature: alloy_g1_point_signed_msg,
-            pubkeyG1: g1_pub_key_bn254,
-            pubkeyG2: g2_pub_key_bn254,
+            pubkeyRegistrationSignature: RegistryG1Point {
+                X: alloy_g1_point_signed_msg.X,
+                Y: alloy_g1_point_signed_msg.Y,
+            },
+            pubkeyG1: RegistryG1Point {
+                X: g1_pub_key_bn254.X,
+                Y: g1_pub_key_bn254.Y,
+            },
+            pubkeyG2: RegistryG2Point {
+                X: g2_pub_key_bn254.X,
+                Y: g2_pub_key_bn254.Y,
+            },
         };
 
+        Ok(pub_key_reg_params)
+    }
+
+    /// Sign the registration of the operator to the AVS, checked by the AVS directory
+    async fn operator_signature(
+        &self,
+        wallet: &(dyn Signer + Send + Sync),
+        operator_to_avs_registration_sig_salt: FixedBytes<32>,
+        operator_to_avs_registration_sig_expiry: U256,
+    ) -> Result<SignatureWithSaltAndExpiry, AvsRegistryError> {
         let msg_to_sign = self
             .el_reader
             .calculate_operator_avs_registration_digest_hash(
@@ -194,41 +863,13 @@ impl AvsRegistryChainWriter {
             salt: operator_to_avs_registration_sig_salt,
             expiry: operator_to_avs_registration_sig_expiry,
         };
-
-        let contract_call = contract_registry_coordinator.registerOperator(
-            quorum_numbers.clone(),
-            socket,
-            PubkeyRegistrationParams {
-                pubkeyRegistrationSignature: RegistryG1Point {
-                    X: pub_key_reg_params.pubkeyRegistrationSignature.X,
-                    Y: pub_key_reg_params.pubkeyRegistrationSignature.Y,
-                },
-                pubkeyG1: RegistryG1Point {
-                    X: pub_key_reg_params.pubkeyG1.X,
-                    Y: pub_key_reg_params.pubkeyG1.Y,
-                },
-                pubkeyG2: RegistryG2Point {
-                    X: pub_key_reg_params.pubkeyG2.X,
-                    Y: pub_key_reg_params.pubkeyG2.Y,
-                },
-            },
-            operator_signature_with_salt_and_expiry,
-        );
-
-        let tx_call = contract_call.gas(GAS_LIMIT_REGISTER_OPERATOR_REGISTRY_COORDINATOR);
-        let tx = tx_call
-            .send()
-            .await
-            .map_err(AvsRegistryError::AlloyContractError)?;
-
-        info!(tx_hash = ?tx,"Sent transaction to register operator in the AVS's registry coordinator" );
-        Ok(*tx.tx_hash())
+        Ok(operator_signature_with_salt_and_expiry)
     }
 
     /// Updates the stake of their entire operator set
     ///
     /// Is used by avs teams running https://github.com/Layr-Labs/avs-sync to updates
-    /// the stake of their entire operator set.
+    /// the stake of their entire operator set, or by the `eigen-services-avssync` service.
     /// Because of high gas costs of this operation, it typically needs to be called
     /// for every quorum, or perhaps for a small grouping of quorums
     /// (highly dependent on number of operators per quorum).
@@ -240,26 +881,29 @@ impl AvsRegistryChainWriter {
     ///
     /// # Returns
     ///
-    /// * `TxHash` - The transaction hash of the transaction that was sent to update the stakes
+    /// * `TxOutcome<StakeRegistryEvents>` - The receipt and stake update events of the mined
+    ///   transaction
     pub async fn update_stakes_of_entire_operator_set_for_quorums(
         &self,
         operators_per_quorum: Vec<Vec<Address>>,
         quorum_number: Bytes,
-    ) -> Result<TxHash, AvsRegistryError> {
+    ) -> Result<TxOutcome<StakeRegistryEvents>, AvsRegistryError> {
         info!(quorum_numbers = %quorum_number, "updating stakes for entire operator set");
-        let provider = get_signer(self.signer.clone(), &self.provider);
+        let provider = &self.provider;
         let contract_registry_coordinator =
             RegistryCoordinator::new(self.registry_coordinator_addr, provider);
         let contract_call = contract_registry_coordinator
             .updateOperatorsForQuorum(operators_per_quorum, quorum_number.clone());
 
-        let tx = contract_call
-            .send()
-            .await
-            .map_err(AvsRegistryError::AlloyContractError)?;
+        let outcome = self
+            .send_tx(
+                contract_call.into_transaction_request(),
+                self.stake_registry_addr,
+            )
+            .await?;
 
-        info!(tx_hash = ?tx, quorum_numbers = %quorum_number," update stakes for entire operator set tx" );
-        Ok(*tx.tx_hash())
+        info!(tx_hash = %outcome.tx_hash(), quorum_numbers = %quorum_number, "updated stakes for entire operator set");
+        Ok(outcome)
     }
 
     /// Update stakes of operator subset for all quorums
@@ -272,27 +916,30 @@ impl AvsRegistryChainWriter {
     ///
     /// # Returns
     ///
-    /// * `TxHash` - The transaction hash of the update stakes of operator subset for all quorums transaction.
+    /// * `TxOutcome<StakeRegistryEvents>` - The receipt and stake update events of the mined
+    ///   update stakes of operator subset for all quorums transaction.
     pub async fn update_stakes_of_operator_subset_for_all_quorums(
         &self,
         operators: Vec<Address>,
-    ) -> Result<TxHash, AvsRegistryError> {
+    ) -> Result<TxOutcome<StakeRegistryEvents>, AvsRegistryError> {
         info!(operators = ?operators, "updating stakes of operator subset for all quorums");
 
-        let provider = get_signer(self.signer.clone(), &self.provider);
+        let provider = &self.provider;
 
         let contract_registry_coordinator =
             RegistryCoordinator::new(self.registry_coordinator_addr, provider);
 
         let contract_call = contract_registry_coordinator.updateOperators(operators);
 
-        let tx = contract_call
-            .send()
-            .await
-            .map_err(AvsRegistryError::AlloyContractError)?;
+        let outcome = self
+            .send_tx(
+                contract_call.into_transaction_request(),
+                self.stake_registry_addr,
+            )
+            .await?;
 
-        info!(tx_hash = ?tx,"succesfully updated stakes of operator subset for all quorums" );
-        Ok(*tx.tx_hash())
+        info!(tx_hash = %outcome.tx_hash(), "succesfully updated stakes of operator subset for all quorums");
+        Ok(outcome)
     }
 
     /// Deregister operator
@@ -305,39 +952,104 @@ impl AvsRegistryChainWriter {
     ///
     /// # Returns
     ///
-    /// * `TxHash` - The transaction hash of the deregister operator transaction.
+    /// * `TxOutcome<RegistryCoordinatorEvents>` - The receipt and events of the mined deregister
+    ///   operator transaction.
     pub async fn deregister_operator(
         &self,
         quorum_numbers: Bytes,
-    ) -> Result<TxHash, AvsRegistryError> {
+    ) -> Result<TxOutcome<RegistryCoordinatorEvents>, AvsRegistryError> {
         info!("deregistering operator with the AVS's registry coordinator");
-        let provider = get_signer(self.signer.clone(), &self.provider);
+        let provider = &self.provider;
 
         let contract_registry_coordinator =
             RegistryCoordinator::new(self.registry_coordinator_addr, provider);
 
         let contract_call = contract_registry_coordinator.deregisterOperator(quorum_numbers);
 
-        let tx = contract_call
-            .send()
-            .await
-            .map_err(AvsRegistryError::AlloyContractError)?;
-        info!(tx_hash = ?tx,"succesfully deregistered operator with the AVS's registry coordinator" );
-        Ok(*tx.tx_hash())
+        let outcome = self
+            .send_tx(
+                contract_call.into_transaction_request(),
+                self.registry_coordinator_addr,
+            )
+            .await?;
+        info!(tx_hash = %outcome.tx_hash(), "succesfully deregistered operator with the AVS's registry coordinator");
+        Ok(outcome)
+    }
+
+    /// Update socket
+    ///
+    /// This function is used to update the socket an operator advertises on the AVS's registry
+    /// coordinator.
+    ///
+    /// # Arguments
+    ///
+    /// * `socket` - The new socket of the operator.
+    ///
+    /// # Returns
+    ///
+    /// * `SocketUpdate` - The update socket transaction.
+    ///
+    /// # Errors
+    ///
+    /// * `AvsRegistryError::OperatorNotRegistered` - if the operator is not registered, before any
+    ///   transaction is sent
+    pub async fn update_socket(&self, socket: String) -> Result<SocketUpdate, AvsRegistryError> {
+        let operator = self.signer_address();
+        info!(operator = %operator, socket, "updating socket with the AVS's registry coordinator");
+
+        let provider = &self.provider;
+        let contract_registry_coordinator =
+            RegistryCoordinator::new(self.registry_coordinator_addr, provider);
+        let RegistryCoordinator::getOperatorReturn { _0: operator_info } =
+            contract_registry_coordinator
+                .getOperator(operator)
+                .call()
+                .await
+                .map_err(AvsRegistryError::revert_or(
+                    "RegistryCoordinator",
+                    AvsRegistryError::GetOperatorStatus,
+                ))?;
+        // the registry coordinator only accepts socket updates from registered operators
+        if operator_info.status != OperatorStatus::Registered as u8 {
+            return Err(AvsRegistryError::OperatorNotRegistered(operator));
+        }
+
+        let contract_call = contract_registry_coordinator.updateSocket(socket.clone());
+        let outcome = self
+            .send_tx(
+                contract_call.into_transaction_request(),
+                self.registry_coordinator_addr,
+            )
+            .await?;
+
+        info!(tx_hash = %outcome.tx_hash(), "successfully updated socket with the AVS's registry coordinator");
+        Ok(SocketUpdate {
+            outcome,
+            operator_id: operator_info.operatorId,
+            socket,
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::AvsRegistryChainWriter;
+    use crate::{
+        admin::AvsRegistryAdminWriter, error::AvsRegistryError, reader::AvsRegistryChainReader,
+    };
     use alloy_node_bindings::Anvil;
     use alloy_primitives::{Address, Bytes, FixedBytes, U256};
+    use alloy_signer_local::PrivateKeySigner;
     use eigen_crypto_bls::BlsKeyPair;
     use eigen_logging::get_test_logger;
     use eigen_testing_utils::anvil_constants::{
-        get_operator_state_retriever_address, get_registry_coordinator_address, ANVIL_HTTP_URL,
+        get_erc20_mock_strategy, get_operator_state_retriever_address,
+        get_registry_coordinator_address, ANVIL_HTTP_URL,
     };
+    use eigen_testing_utils::multicall::deploy_multicall3;
     use eigen_testing_utils::transaction::get_transaction_status;
+    use eigen_types::operator::StrategyParams;
+    use eigen_utils::binding::RegistryCoordinator::{OperatorSetParam, RegistryCoordinatorEvents};
 
     async fn build_avs_registry_chain_writer(private_key: String) -> AvsRegistryChainWriter {
         let registry_coordinator_address = get_registry_coordinator_address().await;
@@ -356,6 +1068,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_avs_writer_methods() {
+        deploy_multicall3().await;
         let account_idx = 5;
         let anvil = Anvil::new().try_spawn().unwrap();
         let private_key = anvil.keys().get(account_idx).unwrap();
@@ -365,11 +1078,176 @@ mod tests {
         let operator_id = anvil.addresses().get(account_idx).unwrap();
 
         let quorum_nums = Bytes::from([0]);
-        test_register_operator(&avs_writer, private_key_decimal, quorum_nums.clone()).await;
+        test_register_operator(
+            &avs_writer,
+            private_key_decimal.clone(),
+            quorum_nums.clone(),
+        )
+        .await;
+        test_update_socket(&avs_writer).await;
         test_update_stake_of_operator_subset(&avs_writer, *operator_id).await;
         test_update_stake_of_entire_operator_set(&avs_writer, *operator_id, quorum_nums.clone())
             .await;
+        let additional_quorum_nums =
+            test_register_operator_in_additional_quorums(&avs_writer, private_key_decimal).await;
         test_deregister_operator(&avs_writer, quorum_nums).await;
+        test_deregister_operator(&avs_writer, additional_quorum_nums).await;
+    }
+
+    /// Create a new quorum weighting the erc20 mock strategy, as the registry coordinator owner
+    ///
+    /// # Returns
+    ///
+    /// The number of the new quorum
+    async fn create_quorum() -> u8 {
+        let avs_reader = AvsRegistryChainReader::new(
+            get_test_logger(),
+            get_registry_coordinator_address().await,
+            get_operator_state_retriever_address().await,
+            ANVIL_HTTP_URL.to_string(),
+        )
+        .await
+        .unwrap();
+        let quorum_number = avs_reader.get_quorum_count().await.unwrap();
+
+        let admin_writer = AvsRegistryAdminWriter::build_avs_registry_admin_writer(
+            get_test_logger(),
+            ANVIL_HTTP_URL.to_string(),
+            "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80".to_string(),
+            get_registry_coordinator_address().await,
+        )
+        .await
+        .unwrap();
+        admin_writer
+            .create_quorum(
+                OperatorSetParam {
+                    maxOperatorCount: 10,
+                    kickBIPsOfOperatorStake: 100,
+                    kickBIPsOfTotalStake: 1000,
+                },
+                U256::ZERO,
+                vec![StrategyParams {
+                    strategy: get_erc20_mock_strategy().await,
+                    multiplier: U256::from(1),
+                }],
+            )
+            .await
+            .unwrap();
+        quorum_number
+    }
+
+    #[tokio::test]
+    async fn test_build_avs_registry_chain_writer_invalid_private_key() {
+        let result = AvsRegistryChainWriter::build_avs_registry_chain_writer(
+            get_test_logger(),
+            ANVIL_HTTP_URL.to_string(),
+            "not a private key".to_string(),
+            Address::ZERO,
+            Address::ZERO,
+        )
+        .await;
+        assert!(matches!(result, Err(AvsRegistryError::InvalidPrivateKey)));
+    }
+
+    #[tokio::test]
+    async fn test_register_operator_with_tx_signer_only() {
+        let anvil = Anvil::new().try_spawn().unwrap();
+        let private_key = anvil.keys().get(7).unwrap();
+        let signer = PrivateKeySigner::from_slice(&private_key.to_bytes()).unwrap();
+        let avs_writer = AvsRegistryChainWriter::build_avs_registry_chain_writer_with_tx_signer(
+            get_test_logger(),
+            ANVIL_HTTP_URL.to_string(),
+            signer,
+            get_registry_coordinator_address().await,
+            get_operator_state_retriever_address().await,
+        )
+        .await
+        .unwrap();
+        assert_eq!(avs_writer.signer_address(), anvil.addresses()[7]);
+
+        let private_key_decimal = U256::from_be_bytes(private_key.to_bytes().into()).to_string();
+        let result = avs_writer
+            .register_operator_in_quorum_with_avs_registry_coordinator(
+                BlsKeyPair::new(private_key_decimal).unwrap(),
+                FixedBytes::from([0x02; 32]),
+                U256::MAX,
+                Bytes::from([0]),
+                "".into(),
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(AvsRegistryError::MessageSignerUnavailable)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_socket_operator_not_registered() {
+        let anvil = Anvil::new().try_spawn().unwrap();
+        let private_key = anvil.keys().get(6).unwrap();
+        let avs_writer = build_avs_registry_chain_writer(hex::encode(private_key.to_bytes())).await;
+
+        let result = avs_writer.update_socket("localhost:8080".to_string()).await;
+        assert!(matches!(
+            result,
+            Err(AvsRegistryError::OperatorNotRegistered(operator)) if operator == anvil.addresses()[6]
+        ));
+    }
+
+    // this function is caller from test_avs_writer_methods
+    async fn test_update_socket(avs_writer: &AvsRegistryChainWriter) {
+        let socket_update = avs_writer
+            .update_socket("localhost:8080".to_string())
+            .await
+            .unwrap();
+        assert_eq!(socket_update.socket, "localhost:8080");
+        assert_ne!(socket_update.operator_id, FixedBytes::ZERO);
+        assert!(socket_update.outcome.receipt.status());
+        assert!(matches!(
+            socket_update.outcome.events.as_slice(),
+            [RegistryCoordinatorEvents::OperatorSocketUpdate(_)]
+        ));
+    }
+
+    // this function is caller from test_avs_writer_methods, while the operator is registered in
+    // quorum 0. Returns the additional quorums the operator registered in.
+    async fn test_register_operator_in_additional_quorums(
+        avs_writer: &AvsRegistryChainWriter,
+        private_key_decimal: String,
+    ) -> Bytes {
+        let quorum_nums = Bytes::from([create_quorum().await]);
+        let bls_key_pair = BlsKeyPair::new(private_key_decimal).unwrap();
+        let registration = avs_writer
+            .register_operator_in_additional_quorums(
+                bls_key_pair.clone(),
+                FixedBytes::from([0x03; 32]),
+                U256::MAX,
+                quorum_nums.clone(),
+                "".into(),
+            )
+            .await
+            .unwrap();
+        // the bls apk registry kept the public key of the first registration
+        assert!(!registration.registers_pubkey);
+        assert_eq!(registration.quorum_numbers, quorum_nums);
+
+        let tx_status = get_transaction_status(registration.outcome.tx_hash()).await;
+        assert!(tx_status);
+
+        let result = avs_writer
+            .register_operator_in_additional_quorums(
+                bls_key_pair,
+                FixedBytes::from([0x04; 32]),
+                U256::MAX,
+                Bytes::from([0, quorum_nums[0]]),
+                "".into(),
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(AvsRegistryError::OperatorAlreadyInQuorums(quorums)) if quorums == vec![0, quorum_nums[0]]
+        ));
+        quorum_nums
     }
 
     // this function is caller from test_avs_writer_methods
@@ -377,12 +1255,12 @@ mod tests {
         avs_writer: &AvsRegistryChainWriter,
         operator_id: Address,
     ) {
-        let tx_hash = avs_writer
+        let outcome = avs_writer
             .update_stakes_of_operator_subset_for_all_quorums(vec![operator_id])
             .await
             .unwrap();
 
-        let tx_status = get_transaction_status(tx_hash).await;
+        let tx_status = get_transaction_status(outcome.tx_hash()).await;
         assert!(tx_status);
     }
 
@@ -392,12 +1270,12 @@ mod tests {
         operator_id: Address,
         quorum_nums: Bytes,
     ) {
-        let tx_hash = avs_writer
+        let outcome = avs_writer
             .update_stakes_of_entire_operator_set_for_quorums(vec![vec![operator_id]], quorum_nums)
             .await
             .unwrap();
 
-        let tx_status = get_transaction_status(tx_hash).await;
+        let tx_status = get_transaction_status(outcome.tx_hash()).await;
         assert!(tx_status);
     }
 
@@ -412,7 +1290,7 @@ mod tests {
 
         // this is set to U256::MAX so that the registry does not take the signature as expired.
         let signature_expiry = U256::MAX;
-        let tx_hash = avs_writer
+        let outcome = avs_writer
             .register_operator_in_quorum_with_avs_registry_coordinator(
                 bls_key_pair,
                 digest_hash,
@@ -423,15 +1301,24 @@ mod tests {
             .await
             .unwrap();
 
-        let tx_status = get_transaction_status(tx_hash).await;
+        let tx_status = get_transaction_status(outcome.tx_hash()).await;
         assert!(tx_status);
+        assert!(outcome.events.iter().any(|event| matches!(
+            event,
+            RegistryCoordinatorEvents::OperatorRegistered(registered)
+                if registered.operator == avs_writer.signer_address()
+        )));
     }
 
     // this function is caller from test_avs_writer_methods
     async fn test_deregister_operator(avs_writer: &AvsRegistryChainWriter, quorum_nums: Bytes) {
-        let tx_hash = avs_writer.deregister_operator(quorum_nums).await.unwrap();
+        let outcome = avs_writer.deregister_operator(quorum_nums).await.unwrap();
 
-        let tx_status = get_transaction_status(tx_hash).await;
+        let tx_status = get_transaction_status(outcome.tx_hash()).await;
         assert!(tx_status);
+        assert!(outcome
+            .events
+            .iter()
+            .any(|event| matches!(event, RegistryCoordinatorEvents::OperatorDeregistered(_))));
     }
 }
@@ -0,0 +1,69 @@
+use alloy_contract::Error as AlloyError;
+use alloy_primitives::TxHash;
+use eigen_chainio_txmanager::simple_tx_manager::TxManagerError;
+use eigen_client_elcontracts::error::ElContractsError;
+use eigen_utils::revert::ContractRevert;
+use thiserror::Error;
+
+/// Name of the contract the reverts are decoded with
+const ECDSA_STAKE_REGISTRY: &str = "ECDSAStakeRegistry";
+
+/// Error returned by the ECDSAStakeRegistry reader and writer
+#[derive(Debug, Error)]
+pub enum ECDSAStakeRegistryError {
+    /// Invalid private key
+    #[error("Invalid private key")]
+    InvalidPrivateKey,
+
+    /// The rpc url can't be parsed
+    #[error("invalid rpc url {0}")]
+    InvalidRpcUrl(String),
+
+    /// Failed to sign the operator registration digest
+    #[error("Failed to sign the operator registration digest")]
+    InvalidSignature,
+
+    /// Failed to read the EigenLayer core contracts
+    #[error("ElContracts error: {0}")]
+    ElContractsError(#[from] ElContractsError),
+
+    /// The tx manager failed to send a transaction or to get its receipt
+    #[error("tx manager error: {0}")]
+    TxManager(#[from] TxManagerError),
+
+    /// Transaction mined but reverted
+    #[error("transaction {0} reverted")]
+    TxReverted(TxHash),
+
+    /// A contract call or transaction reverted
+    #[error("{0}")]
+    ContractReverted(#[from] ContractRevert),
+
+    /// Alloy contract error
+    #[error("Alloy contract error: {0}")]
+    AlloyContractError(#[from] AlloyError),
+}
+
+impl ECDSAStakeRegistryError {
+    /// Map the error of a call to the stake registry to
+    /// [`ECDSAStakeRegistryError::ContractReverted`] if it reverted, or to
+    /// [`ECDSAStakeRegistryError::AlloyContractError`]
+    pub(crate) fn revert(err: AlloyError) -> Self {
+        match ContractRevert::from_call_error(ECDSA_STAKE_REGISTRY, &err) {
+            Some(revert) => Self::ContractReverted(revert),
+            None => Self::AlloyContractError(err),
+        }
+    }
+
+    /// Map the error of the tx manager sending a transaction to the stake registry to
+    /// [`ECDSAStakeRegistryError::ContractReverted`] if the transaction reverts, or to
+    /// [`ECDSAStakeRegistryError::TxManager`]
+    pub(crate) fn tx_revert(err: TxManagerError) -> Self {
+        match err {
+            TxManagerError::Reverted(data) => {
+                Self::ContractReverted(ContractRevert::decode(ECDSA_STAKE_REGISTRY, &data))
+            }
+            err => Self::TxManager(err),
+        }
+    }
+}
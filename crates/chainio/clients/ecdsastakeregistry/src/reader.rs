@@ -0,0 +1,298 @@
+use crate::error::ECDSAStakeRegistryError;
+use alloy_primitives::{Address, U256};
+use eigen_logging::logger::SharedLogger;
+use eigen_utils::{
+    binding::ECDSAStakeRegistry::{self, Quorum},
+    get_shared_provider, SharedProvider,
+};
+
+/// Reader of the ECDSAStakeRegistry of an AVS
+#[derive(Debug, Clone)]
+pub struct ECDSAStakeRegistryChainReader {
+    pub(crate) logger: SharedLogger,
+    stake_registry: Address,
+    provider: SharedProvider,
+}
+
+impl ECDSAStakeRegistryChainReader {
+    /// Create a new ECDSAStakeRegistryChainReader
+    ///
+    /// # Arguments
+    ///
+    /// * `logger` - SharedLogger used for logging
+    /// * `stake_registry` - address of the ECDSAStakeRegistry
+    /// * `provider` - provider string
+    ///
+    /// # Errors
+    ///
+    /// * `ECDSAStakeRegistryError::InvalidRpcUrl` - if `provider` is not a valid url
+    pub fn new(
+        logger: SharedLogger,
+        stake_registry: Address,
+        provider: String,
+    ) -> Result<Self, ECDSAStakeRegistryError> {
+        let shared_provider = get_shared_provider(&provider)
+            .map_err(|_| ECDSAStakeRegistryError::InvalidRpcUrl(provider.clone()))?;
+        Ok(Self::new_with_provider(
+            logger,
+            stake_registry,
+            shared_provider,
+        ))
+    }
+
+    /// Create a new ECDSAStakeRegistryChainReader reading through `provider`, shared with the
+    /// other clients built with it
+    ///
+    /// # Arguments
+    ///
+    /// * `logger` - SharedLogger used for logging
+    /// * `stake_registry` - address of the ECDSAStakeRegistry
+    /// * `provider` - provider, shared with the other clients built with it
+    pub fn new_with_provider(
+        logger: SharedLogger,
+        stake_registry: Address,
+        provider: SharedProvider,
+    ) -> Self {
+        Self {
+            logger,
+            stake_registry,
+            provider,
+        }
+    }
+
+    /// Get the provider the reader reads through, to build other clients sharing its
+    /// connection
+    pub fn provider(&self) -> &SharedProvider {
+        &self.provider
+    }
+
+    /// Get the address of the ECDSAStakeRegistry contract
+    pub fn stake_registry_address(&self) -> Address {
+        self.stake_registry
+    }
+
+    /// Get the weight of an operator at a block
+    ///
+    /// # Arguments
+    ///
+    /// * `operator` - The operator's address
+    /// * `block_number` - The block to read the weight at, lower than the current block
+    ///
+    /// # Returns
+    ///
+    /// * `U256` - The weight of the operator, zero if it wasn't registered at the block
+    pub async fn get_operator_weight_at_block(
+        &self,
+        operator: Address,
+        block_number: u32,
+    ) -> Result<U256, ECDSAStakeRegistryError> {
+        let contract_stake_registry = ECDSAStakeRegistry::new(self.stake_registry, &self.provider);
+
+        let ECDSAStakeRegistry::getOperatorWeightAtBlockReturn { _0: weight } =
+            contract_stake_registry
+                .getOperatorWeightAtBlock(operator, block_number)
+                .call()
+                .await
+                .map_err(ECDSAStakeRegistryError::revert)?;
+        Ok(weight)
+    }
+
+    /// Get the latest threshold weight the signed weight of a message must reach
+    ///
+    /// # Returns
+    ///
+    /// * `U256` - The threshold weight
+    pub async fn get_last_checkpoint_threshold_weight(
+        &self,
+    ) -> Result<U256, ECDSAStakeRegistryError> {
+        let contract_stake_registry = ECDSAStakeRegistry::new(self.stake_registry, &self.provider);
+
+        let ECDSAStakeRegistry::getLastCheckpointThresholdWeightReturn {
+            _0: threshold_weight,
+        } = contract_stake_registry
+            .getLastCheckpointThresholdWeight()
+            .call()
+            .await
+            .map_err(ECDSAStakeRegistryError::revert)?;
+        Ok(threshold_weight)
+    }
+
+    /// Get the threshold weight the signed weight of a message had to reach at a block
+    ///
+    /// # Arguments
+    ///
+    /// * `block_number` - The block to read the threshold weight at, lower than the current
+    ///   block
+    ///
+    /// # Returns
+    ///
+    /// * `U256` - The threshold weight
+    pub async fn get_last_checkpoint_threshold_weight_at_block(
+        &self,
+        block_number: u32,
+    ) -> Result<U256, ECDSAStakeRegistryError> {
+        let contract_stake_registry = ECDSAStakeRegistry::new(self.stake_registry, &self.provider);
+
+        let ECDSAStakeRegistry::getLastCheckpointThresholdWeightAtBlockReturn {
+            _0: threshold_weight,
+        } = contract_stake_registry
+            .getLastCheckpointThresholdWeightAtBlock(block_number)
+            .call()
+            .await
+            .map_err(ECDSAStakeRegistryError::revert)?;
+        Ok(threshold_weight)
+    }
+
+    /// Get the latest total weight of the registered operators
+    ///
+    /// # Returns
+    ///
+    /// * `U256` - The total weight
+    pub async fn get_last_checkpoint_total_weight(&self) -> Result<U256, ECDSAStakeRegistryError> {
+        let contract_stake_registry = ECDSAStakeRegistry::new(self.stake_registry, &self.provider);
+
+        let ECDSAStakeRegistry::getLastCheckpointTotalWeightReturn { _0: total_weight } =
+            contract_stake_registry
+                .getLastCheckpointTotalWeight()
+                .call()
+                .await
+                .map_err(ECDSAStakeRegistryError::revert)?;
+        Ok(total_weight)
+    }
+
+    /// Get the total weight of the registered operators at a block
+    ///
+    /// # Arguments
+    ///
+    /// * `block_number` - The block to read the total weight at, lower than the current block
+    ///
+    /// # Returns
+    ///
+    /// * `U256` - The total weight
+    pub async fn get_last_checkpoint_total_weight_at_block(
+        &self,
+        block_number: u32,
+    ) -> Result<U256, ECDSAStakeRegistryError> {
+        let contract_stake_registry = ECDSAStakeRegistry::new(self.stake_registry, &self.provider);
+
+        let ECDSAStakeRegistry::getLastCheckpointTotalWeightAtBlockReturn { _0: total_weight } =
+            contract_stake_registry
+                .getLastCheckpointTotalWeightAtBlock(block_number)
+                .call()
+                .await
+                .map_err(ECDSAStakeRegistryError::revert)?;
+        Ok(total_weight)
+    }
+
+    /// Get the quorum, the strategies the operators are weighted by
+    ///
+    /// # Returns
+    ///
+    /// * `Quorum` - The strategies and their multipliers, in basis points
+    pub async fn get_quorum(&self) -> Result<Quorum, ECDSAStakeRegistryError> {
+        let contract_stake_registry = ECDSAStakeRegistry::new(self.stake_registry, &self.provider);
+
+        let ECDSAStakeRegistry::quorumReturn { _0: quorum } = contract_stake_registry
+            .quorum()
+            .call()
+            .await
+            .map_err(ECDSAStakeRegistryError::revert)?;
+        Ok(quorum)
+    }
+
+    /// Get the minimum weight of an operator, operators below it are weighted zero
+    ///
+    /// # Returns
+    ///
+    /// * `U256` - The minimum weight
+    pub async fn get_minimum_weight(&self) -> Result<U256, ECDSAStakeRegistryError> {
+        let contract_stake_registry = ECDSAStakeRegistry::new(self.stake_registry, &self.provider);
+
+        let ECDSAStakeRegistry::minimumWeightReturn { _0: minimum_weight } =
+            contract_stake_registry
+                .minimumWeight()
+                .call()
+                .await
+                .map_err(ECDSAStakeRegistryError::revert)?;
+        Ok(minimum_weight)
+    }
+
+    /// Check if an operator is registered
+    ///
+    /// # Arguments
+    ///
+    /// * `operator` - The operator's address
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the operator is registered in the stake registry
+    pub async fn is_operator_registered(
+        &self,
+        operator: Address,
+    ) -> Result<bool, ECDSAStakeRegistryError> {
+        let contract_stake_registry = ECDSAStakeRegistry::new(self.stake_registry, &self.provider);
+
+        let ECDSAStakeRegistry::operatorRegisteredReturn { _0: registered } =
+            contract_stake_registry
+                .operatorRegistered(operator)
+                .call()
+                .await
+                .map_err(ECDSAStakeRegistryError::revert)?;
+        Ok(registered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{deploy_stake_registry, THRESHOLD_WEIGHT};
+    use eigen_logging::get_test_logger;
+    use eigen_testing_utils::anvil_constants::{get_erc20_mock_strategy, ANVIL_HTTP_URL};
+
+    #[test]
+    fn test_new_with_invalid_rpc_url() {
+        let result = ECDSAStakeRegistryChainReader::new(
+            get_test_logger(),
+            Address::ZERO,
+            "not a url".to_string(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(ECDSAStakeRegistryError::InvalidRpcUrl(url)) if url == "not a url"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_reader_methods() {
+        let stake_registry = deploy_stake_registry(
+            "5de4111afa1a4b94908f83103eb1f1706367c2e68ca870fc3fb9a804cdab365a",
+        )
+        .await;
+        let reader = ECDSAStakeRegistryChainReader::new(
+            get_test_logger(),
+            stake_registry,
+            ANVIL_HTTP_URL.to_string(),
+        )
+        .unwrap();
+
+        let quorum = reader.get_quorum().await.unwrap();
+        assert_eq!(quorum.strategies.len(), 1);
+        assert_eq!(
+            quorum.strategies[0].strategy,
+            get_erc20_mock_strategy().await
+        );
+        assert_eq!(
+            reader.get_last_checkpoint_threshold_weight().await.unwrap(),
+            U256::from(THRESHOLD_WEIGHT)
+        );
+        assert_eq!(
+            reader.get_last_checkpoint_total_weight().await.unwrap(),
+            U256::ZERO
+        );
+        assert_eq!(reader.get_minimum_weight().await.unwrap(), U256::ZERO);
+
+        let operator = Address::with_last_byte(1);
+        assert!(!reader.is_operator_registered(operator).await.unwrap());
+    }
+}
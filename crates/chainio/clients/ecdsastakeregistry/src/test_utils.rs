@@ -0,0 +1,53 @@
+use alloy_network::EthereumWallet;
+use alloy_primitives::{Address, U256};
+use alloy_signer_local::PrivateKeySigner;
+use eigen_testing_utils::anvil_constants::{
+    get_avs_directory_address, get_delegation_manager_address, get_erc20_mock_strategy,
+    ANVIL_HTTP_URL,
+};
+use eigen_utils::{
+    binding::ECDSAStakeRegistry::{self, Quorum, StrategyParams},
+    get_shared_provider, get_shared_wallet_provider,
+};
+
+/// Threshold weight of the stake registries deployed by [`deploy_stake_registry`]
+pub(crate) const THRESHOLD_WEIGHT: u64 = 1000;
+
+/// Deploy an ECDSAStakeRegistry weighting the operators by their shares in the erc20 mock
+/// strategy of the anvil deployment
+///
+/// The anvil deployment has no ECDSA service manager, so the stake registry is initialized with
+/// the AVS directory as its service manager. The stake registry is then the AVS the operators
+/// register to, and their registration digests must be signed for its address.
+pub(crate) async fn deploy_stake_registry(deployer_private_key: &str) -> Address {
+    let signer: PrivateKeySigner = deployer_private_key.parse().unwrap();
+    let provider = get_shared_wallet_provider(
+        EthereumWallet::from(signer),
+        get_shared_provider(ANVIL_HTTP_URL).unwrap(),
+    );
+    let contract_stake_registry =
+        ECDSAStakeRegistry::deploy(&provider, get_delegation_manager_address().await)
+            .await
+            .unwrap();
+
+    let quorum = Quorum {
+        strategies: vec![StrategyParams {
+            strategy: get_erc20_mock_strategy().await,
+            multiplier: 10_000,
+        }],
+    };
+    contract_stake_registry
+        .initialize(
+            get_avs_directory_address().await,
+            U256::from(THRESHOLD_WEIGHT),
+            quorum,
+        )
+        .send()
+        .await
+        .unwrap()
+        .get_receipt()
+        .await
+        .unwrap();
+
+    *contract_stake_registry.address()
+}
@@ -0,0 +1,425 @@
+use crate::error::ECDSAStakeRegistryError;
+use crate::reader::ECDSAStakeRegistryChainReader;
+use alloy_network::{EthereumWallet, TxSigner};
+use alloy_primitives::{Address, FixedBytes, U256};
+use alloy_rpc_types::TransactionRequest;
+use alloy_signer::{Signature, Signer};
+use alloy_signer_local::PrivateKeySigner;
+use eigen_chainio_txmanager::{
+    simple_tx_manager::SimpleTxManager,
+    tx_manager::{TxManager, TxOutcome},
+};
+use eigen_client_elcontracts::reader::ELChainReader;
+use eigen_utils::{
+    binding::ECDSAStakeRegistry::{self, ECDSAStakeRegistryEvents, SignatureWithSaltAndExpiry},
+    SharedProvider,
+};
+use std::fmt;
+use std::sync::Arc;
+use tracing::info;
+
+/// Writer of the ECDSAStakeRegistry of an AVS
+#[derive(Clone)]
+pub struct ECDSAStakeRegistryChainWriter {
+    stake_registry: Address,
+    service_manager: Address,
+    el_reader: ELChainReader,
+    provider: SharedProvider,
+    /// Signs and sends the transactions
+    tx_manager: Arc<dyn TxManager>,
+    /// Signs the operator registration digests
+    signer: Arc<dyn Signer + Send + Sync>,
+}
+
+impl fmt::Debug for ECDSAStakeRegistryChainWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ECDSAStakeRegistryChainWriter")
+            .field("stake_registry", &self.stake_registry)
+            .field("service_manager", &self.service_manager)
+            .field("el_reader", &self.el_reader)
+            .field("provider", &self.provider)
+            .field("tx_manager", &self.tx_manager)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ECDSAStakeRegistryChainWriter {
+    /// Create a new ECDSAStakeRegistryChainWriter signing with a private key
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - ECDSAStakeRegistryChainReader, whose stake registry and provider are shared
+    ///   with the writer
+    /// * `service_manager` - address of the service manager of the AVS, the operators register
+    ///   to it in the AVS directory
+    /// * `el_reader` - ELChainReader, computing the operator registration digests
+    /// * `signer` - private key of the operator
+    ///
+    /// # Errors
+    ///
+    /// * `ECDSAStakeRegistryError::InvalidPrivateKey` - if the private key can't be parsed
+    pub fn new(
+        reader: ECDSAStakeRegistryChainReader,
+        service_manager: Address,
+        el_reader: ELChainReader,
+        signer: String,
+    ) -> Result<Self, ECDSAStakeRegistryError> {
+        let signer = signer
+            .parse::<PrivateKeySigner>()
+            .map_err(|_| ECDSAStakeRegistryError::InvalidPrivateKey)?;
+        Ok(Self::new_with_signer(
+            reader,
+            service_manager,
+            el_reader,
+            signer,
+        ))
+    }
+
+    /// Create a new ECDSAStakeRegistryChainWriter with a signer of both transactions and
+    /// messages, e.g. a local or keystore key, or an AWS KMS key
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - ECDSAStakeRegistryChainReader, whose stake registry and provider are shared
+    ///   with the writer
+    /// * `service_manager` - address of the service manager of the AVS, the operators register
+    ///   to it in the AVS directory
+    /// * `el_reader` - ELChainReader, computing the operator registration digests
+    /// * `signer` - signer of the operator
+    pub fn new_with_signer<S>(
+        reader: ECDSAStakeRegistryChainReader,
+        service_manager: Address,
+        el_reader: ELChainReader,
+        signer: S,
+    ) -> Self
+    where
+        S: Signer + TxSigner<Signature> + Clone + Send + Sync + 'static,
+    {
+        let provider = reader.provider().clone();
+        let tx_manager = SimpleTxManager::new_with_provider(
+            reader.logger.clone(),
+            1.0,
+            EthereumWallet::from(signer.clone()),
+            provider.clone(),
+        );
+        Self {
+            stake_registry: reader.stake_registry_address(),
+            service_manager,
+            el_reader,
+            provider,
+            tx_manager: Arc::new(tx_manager),
+            signer: Arc::new(signer),
+        }
+    }
+
+    /// Send the transactions through `tx_manager` instead of the default [`SimpleTxManager`]
+    ///
+    /// The registration digests are still signed by the signer of the writer, the operator
+    /// registering doesn't have to send the transaction.
+    pub fn with_tx_manager(mut self, tx_manager: Arc<dyn TxManager>) -> Self {
+        self.tx_manager = tx_manager;
+        self
+    }
+
+    /// Get the address of the account sending the transactions
+    pub fn signer_address(&self) -> Address {
+        self.tx_manager.sender()
+    }
+
+    /// Send a transaction to the stake registry through the tx manager and wait for it to be
+    /// mined
+    ///
+    /// # Arguments
+    ///
+    /// * `tx` - the transaction
+    ///
+    /// # Errors
+    ///
+    /// * `ECDSAStakeRegistryError::ContractReverted` - if the transaction reverts when estimating
+    ///   its gas
+    /// * `ECDSAStakeRegistryError::TxReverted` - if the transaction reverted
+    async fn send_tx(
+        &self,
+        mut tx: TransactionRequest,
+    ) -> Result<TxOutcome<ECDSAStakeRegistryEvents>, ECDSAStakeRegistryError> {
+        let receipt = self
+            .tx_manager
+            .send_tx(&mut tx)
+            .await
+            .map_err(ECDSAStakeRegistryError::tx_revert)?;
+        if !receipt.status() {
+            return Err(ECDSAStakeRegistryError::TxReverted(
+                receipt.transaction_hash,
+            ));
+        }
+        Ok(TxOutcome::new(receipt, self.stake_registry))
+    }
+
+    /// Register the operator of the signer in the stake registry, and to the AVS in the AVS
+    /// directory
+    ///
+    /// # Arguments
+    ///
+    /// * `operator_to_avs_registration_sig_salt` - salt for the signature
+    /// * `operator_to_avs_registration_sig_expiry` - expiry for the signature
+    ///
+    /// # Returns
+    ///
+    /// * `TxOutcome<ECDSAStakeRegistryEvents>` - receipt and events of the mined
+    ///   `registerOperatorWithSignature` transaction
+    pub async fn register_operator_with_signature(
+        &self,
+        operator_to_avs_registration_sig_salt: FixedBytes<32>,
+        operator_to_avs_registration_sig_expiry: U256,
+    ) -> Result<TxOutcome<ECDSAStakeRegistryEvents>, ECDSAStakeRegistryError> {
+        let operator = self.signer.address();
+        info!(avs_service_manager = %self.service_manager, operator = %operator, "registering operator with the AVS's ECDSA stake registry");
+
+        let operator_signature = self
+            .operator_signature(
+                operator_to_avs_registration_sig_salt,
+                operator_to_avs_registration_sig_expiry,
+            )
+            .await?;
+
+        let contract_stake_registry = ECDSAStakeRegistry::new(self.stake_registry, &self.provider);
+        let contract_call =
+            contract_stake_registry.registerOperatorWithSignature(operator, operator_signature);
+        let outcome = self
+            .send_tx(contract_call.into_transaction_request())
+            .await?;
+
+        info!(tx_hash = %outcome.tx_hash(), "registered operator with the AVS's ECDSA stake registry");
+        Ok(outcome)
+    }
+
+    /// Sign the registration of the operator to the AVS, checked by the AVS directory
+    async fn operator_signature(
+        &self,
+        operator_to_avs_registration_sig_salt: FixedBytes<32>,
+        operator_to_avs_registration_sig_expiry: U256,
+    ) -> Result<SignatureWithSaltAndExpiry, ECDSAStakeRegistryError> {
+        let msg_to_sign = self
+            .el_reader
+            .calculate_operator_avs_registration_digest_hash(
+                self.signer.address(),
+                self.service_manager,
+                operator_to_avs_registration_sig_salt,
+                operator_to_avs_registration_sig_expiry,
+            )
+            .await?;
+
+        let operator_signature = self
+            .signer
+            .sign_hash(&msg_to_sign)
+            .await
+            .map_err(|_| ECDSAStakeRegistryError::InvalidSignature)?;
+
+        Ok(SignatureWithSaltAndExpiry {
+            signature: operator_signature.as_bytes().into(),
+            salt: operator_to_avs_registration_sig_salt,
+            expiry: operator_to_avs_registration_sig_expiry,
+        })
+    }
+
+    /// Deregister the operator sending the transaction from the stake registry, and from the
+    /// AVS in the AVS directory
+    ///
+    /// # Returns
+    ///
+    /// * `TxOutcome<ECDSAStakeRegistryEvents>` - receipt and events of the mined
+    ///   `deregisterOperator` transaction
+    pub async fn deregister_operator(
+        &self,
+    ) -> Result<TxOutcome<ECDSAStakeRegistryEvents>, ECDSAStakeRegistryError> {
+        info!(operator = %self.signer_address(), "deregistering operator with the AVS's ECDSA stake registry");
+        let contract_stake_registry = ECDSAStakeRegistry::new(self.stake_registry, &self.provider);
+        let contract_call = contract_stake_registry.deregisterOperator();
+
+        let outcome = self
+            .send_tx(contract_call.into_transaction_request())
+            .await?;
+
+        info!(tx_hash = %outcome.tx_hash(), "deregistered operator with the AVS's ECDSA stake registry");
+        Ok(outcome)
+    }
+
+    /// Update the weights of operators, and the total weight
+    ///
+    /// # Arguments
+    ///
+    /// * `operators` - The operators to update the weights of
+    ///
+    /// # Returns
+    ///
+    /// * `TxOutcome<ECDSAStakeRegistryEvents>` - receipt and weight update events of the mined
+    ///   `updateOperators` transaction
+    pub async fn update_operators(
+        &self,
+        operators: Vec<Address>,
+    ) -> Result<TxOutcome<ECDSAStakeRegistryEvents>, ECDSAStakeRegistryError> {
+        info!(operators = ?operators, "updating operator weights in the AVS's ECDSA stake registry");
+        let contract_stake_registry = ECDSAStakeRegistry::new(self.stake_registry, &self.provider);
+        let contract_call = contract_stake_registry.updateOperators(operators);
+
+        let outcome = self
+            .send_tx(contract_call.into_transaction_request())
+            .await?;
+
+        info!(tx_hash = %outcome.tx_hash(), "updated operator weights in the AVS's ECDSA stake registry");
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{deploy_stake_registry, THRESHOLD_WEIGHT};
+    use alloy_primitives::keccak256;
+    use eigen_logging::get_test_logger;
+    use eigen_testing_utils::anvil_constants::{
+        get_avs_directory_address, get_delegation_manager_address, ANVIL_HTTP_URL,
+    };
+    use eigen_utils::revert::RevertReason;
+
+    /// Build a writer of the stake registry for an operator of the anvil deployment
+    async fn build_writer(
+        stake_registry: Address,
+        private_key: &str,
+    ) -> (ECDSAStakeRegistryChainReader, ECDSAStakeRegistryChainWriter) {
+        let reader = ECDSAStakeRegistryChainReader::new(
+            get_test_logger(),
+            stake_registry,
+            ANVIL_HTTP_URL.to_string(),
+        )
+        .unwrap();
+        let el_reader = ELChainReader::build_with_provider(
+            get_test_logger(),
+            get_delegation_manager_address().await,
+            get_avs_directory_address().await,
+            reader.provider().clone(),
+        )
+        .await
+        .unwrap();
+        // the test stake registry is its own service manager, see `deploy_stake_registry`
+        let writer = ECDSAStakeRegistryChainWriter::new(
+            reader.clone(),
+            stake_registry,
+            el_reader,
+            private_key.to_string(),
+        )
+        .unwrap();
+        (reader, writer)
+    }
+
+    #[tokio::test]
+    async fn test_writer_methods() {
+        let stake_registry = deploy_stake_registry(
+            "7c852118294e51e653712a81e05800f419141751be58f605c371e15141b007a6",
+        )
+        .await;
+        // operator registered in EigenLayer at index 1
+        let (reader, writer) = build_writer(
+            stake_registry,
+            "59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d",
+        )
+        .await;
+        let operator = writer.signer_address();
+
+        // the salts are spent per operator, across AVSs
+        let salt = keccak256(stake_registry);
+        let registration = writer
+            .register_operator_with_signature(salt, U256::MAX)
+            .await
+            .unwrap();
+        assert!(registration.events.iter().any(|event| matches!(
+            event,
+            ECDSAStakeRegistryEvents::OperatorRegistered(registered) if registered._operator == operator
+        )));
+        assert!(reader.is_operator_registered(operator).await.unwrap());
+
+        let weight = reader.get_last_checkpoint_total_weight().await.unwrap();
+        assert!(weight > U256::ZERO);
+
+        writer.update_operators(vec![operator]).await.unwrap();
+
+        // the registration block is past once the update is mined
+        let registration_block = registration.receipt.block_number.unwrap() as u32;
+        assert_eq!(
+            reader
+                .get_operator_weight_at_block(operator, registration_block)
+                .await
+                .unwrap(),
+            weight
+        );
+        assert_eq!(
+            reader
+                .get_operator_weight_at_block(operator, registration_block - 1)
+                .await
+                .unwrap(),
+            U256::ZERO
+        );
+        assert_eq!(
+            reader
+                .get_last_checkpoint_total_weight_at_block(registration_block)
+                .await
+                .unwrap(),
+            weight
+        );
+        assert_eq!(
+            reader
+                .get_last_checkpoint_threshold_weight_at_block(registration_block)
+                .await
+                .unwrap(),
+            U256::from(THRESHOLD_WEIGHT)
+        );
+
+        let deregistration = writer.deregister_operator().await.unwrap();
+        assert!(deregistration.events.iter().any(|event| matches!(
+            event,
+            ECDSAStakeRegistryEvents::OperatorDeregistered(deregistered) if deregistered._operator == operator
+        )));
+        assert!(!reader.is_operator_registered(operator).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_deregister_operator_not_registered() {
+        let private_key = "47e179ec197488593b187f80a00eb0da91f1b9d0b13f8733639f19c30a34926a";
+        let stake_registry = deploy_stake_registry(private_key).await;
+        let (_, writer) = build_writer(stake_registry, private_key).await;
+
+        let result = writer.deregister_operator().await;
+        assert!(matches!(
+            result,
+            Err(ECDSAStakeRegistryError::ContractReverted(revert))
+                if revert.reason == RevertReason::CustomError("OperatorNotRegistered")
+        ));
+    }
+
+    #[test]
+    fn test_new_invalid_private_key() {
+        let reader = ECDSAStakeRegistryChainReader::new(
+            get_test_logger(),
+            Address::ZERO,
+            ANVIL_HTTP_URL.to_string(),
+        )
+        .unwrap();
+        let el_reader = ELChainReader::new(
+            get_test_logger(),
+            Address::ZERO,
+            Address::ZERO,
+            Address::ZERO,
+            ANVIL_HTTP_URL.to_string(),
+        );
+        let result = ECDSAStakeRegistryChainWriter::new(
+            reader,
+            Address::ZERO,
+            el_reader,
+            "not a private key".to_string(),
+        );
+        assert!(matches!(
+            result,
+            Err(ECDSAStakeRegistryError::InvalidPrivateKey)
+        ));
+    }
+}
@@ -1,4 +1,8 @@
 use alloy_contract::Error as AlloyError;
+use alloy_primitives::TxHash;
+use eigen_chainio_txmanager::simple_tx_manager::TxManagerError;
+use eigen_chainio_utils::{log_scanner::LogScannerError, multicall::MulticallError};
+use eigen_utils::revert::ContractRevert;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -31,6 +35,14 @@ pub enum ElContractsError {
     #[error("Failed to get service manager slashing expiry")]
     ServiceManagerCanSlashOperatorExpiry,
 
+    /// Get withdrawal delays
+    #[error("Failed to get withdrawal delays")]
+    GetWithdrawalDelays,
+
+    /// Get queued withdrawals status
+    #[error("Failed to get queued withdrawals status")]
+    GetQueuedWithdrawals,
+
     /// Get underlying token
     #[error("Failed to get underlying token")]
     GetUnderlyingToken,
@@ -67,6 +79,83 @@ pub enum ElContractsError {
     #[error("Failed to update metadata uri")]
     UpdateMetadataUri,
 
+    /// Invalid private key
+    #[error("Invalid private key")]
+    InvalidPrivateKey,
+
+    /// The tx manager failed to send a transaction or to get its receipt
+    #[error("tx manager error: {0}")]
+    TxManager(#[from] TxManagerError),
+
+    /// Transaction mined but reverted
+    #[error("transaction {0} reverted")]
+    TxReverted(TxHash),
+
+    /// A contract call or transaction reverted
+    #[error("{0}")]
+    ContractReverted(#[from] ContractRevert),
+
+    /// Failed to execute a batch of calls
+    #[error("Multicall error: {0}")]
+    MulticallError(#[from] MulticallError),
+
+    /// Failed to scan logs
+    #[error("Log scanner error: {0}")]
+    LogScannerError(#[from] LogScannerError),
+
+    /// The rpc url can't be parsed
+    #[error("invalid rpc url {0}")]
+    InvalidRpcUrl(String),
+
     #[error("Alloy contract error: {0}")]
     AlloyContractError(#[from] AlloyError),
 }
+
+impl ElContractsError {
+    /// Map the error of a call to `contract` to [`ElContractsError::ContractReverted`] if it
+    /// reverted, or to `otherwise`
+    pub(crate) fn revert_or(
+        contract: &'static str,
+        otherwise: Self,
+    ) -> impl FnOnce(AlloyError) -> Self {
+        move |err| {
+            ContractRevert::from_call_error(contract, &err)
+                .map_or(otherwise, Self::ContractReverted)
+        }
+    }
+
+    /// Map the error of a call to `contract` to [`ElContractsError::ContractReverted`] if it
+    /// reverted, or to [`ElContractsError::AlloyContractError`]
+    pub(crate) fn revert(contract: &'static str) -> impl FnOnce(AlloyError) -> Self {
+        move |err| match ContractRevert::from_call_error(contract, &err) {
+            Some(revert) => Self::ContractReverted(revert),
+            None => Self::AlloyContractError(err),
+        }
+    }
+
+    /// Map the error of a call to `contract` batched in a multicall to
+    /// [`ElContractsError::ContractReverted`] if it reverted, or to `otherwise`
+    pub(crate) fn multicall_revert_or(
+        contract: &'static str,
+        otherwise: Self,
+    ) -> impl FnOnce(MulticallError) -> Self {
+        move |err| match err {
+            MulticallError::CallReverted { data, .. } => {
+                Self::ContractReverted(ContractRevert::decode(contract, &data))
+            }
+            _ => otherwise,
+        }
+    }
+
+    /// Map the error of the tx manager sending a transaction to `contract` to
+    /// [`ElContractsError::ContractReverted`] if the transaction reverts, or to
+    /// [`ElContractsError::TxManager`]
+    pub(crate) fn tx_revert(contract: &'static str) -> impl FnOnce(TxManagerError) -> Self {
+        move |err| match err {
+            TxManagerError::Reverted(data) => {
+                Self::ContractReverted(ContractRevert::decode(contract, &data))
+            }
+            err => Self::TxManager(err),
+        }
+    }
+}
@@ -1,48 +1,185 @@
 use crate::error::ElContractsError;
 use alloy_primitives::{Address, FixedBytes, U256};
+use alloy_rpc_types::Filter;
+use eigen_chainio_utils::{
+    log_scanner::LogScanner,
+    multicall::{Multicall, MulticallResults, MULTICALL3_ADDRESS},
+};
 use eigen_logging::logger::SharedLogger;
 use eigen_types::operator::Operator;
 use eigen_utils::{
-    binding::{AVSDirectory, DelegationManager, ISlasher, IStrategy, IERC20},
-    get_provider,
+    binding::{
+        AVSDirectory,
+        DelegationManager::{self, Withdrawal},
+        ISlasher, IStrategy, StrategyManager, IERC20,
+    },
+    get_shared_provider, SharedProvider,
 };
 
+/// Shares held in a strategy, by a staker or delegated to an operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StrategyShares {
+    /// The strategy
+    pub strategy: Address,
+    /// The shares in the strategy
+    pub shares: U256,
+}
+
+/// Withdrawal delay of a strategy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StrategyWithdrawalDelay {
+    /// The strategy
+    pub strategy: Address,
+    /// Number of blocks a withdrawal from the strategy waits before it can be completed
+    pub delay_blocks: U256,
+}
+
+/// Withdrawal delay settings of the DelegationManager, read at the same block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithdrawalDelays {
+    /// Number of blocks every withdrawal waits before it can be completed
+    pub min_withdrawal_delay_blocks: U256,
+    /// Withdrawal delay of each strategy, in the order they were requested
+    pub strategy_withdrawal_delays: Vec<StrategyWithdrawalDelay>,
+    /// Number of blocks a withdrawal from all the requested strategies waits, the highest of
+    /// the delays above
+    pub withdrawal_delay_blocks: U256,
+}
+
+/// Withdrawal queued in the DelegationManager
+#[derive(Debug, Clone)]
+pub struct QueuedWithdrawal {
+    /// Root of the withdrawal, its key in the DelegationManager
+    pub root: FixedBytes<32>,
+    /// The withdrawal
+    pub withdrawal: Withdrawal,
+    /// Whether the withdrawal is still pending, `false` once completed
+    pub pending: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct ELChainReader {
-    _logger: SharedLogger,
+    pub(crate) logger: SharedLogger,
     slasher: Address,
     delegation_manager: Address,
     avs_directory: Address,
-    pub provider: String,
+    multicall: Address,
+    provider: SharedProvider,
 }
 
 impl ELChainReader {
     pub fn new(
-        _logger: SharedLogger,
+        logger: SharedLogger,
         slasher: Address,
         delegation_manager: Address,
         avs_directory: Address,
         provider: String,
+    ) -> Self {
+        Self::new_with_provider(
+            logger,
+            slasher,
+            delegation_manager,
+            avs_directory,
+            get_shared_provider(&provider).expect("Wrong rpc url"),
+        )
+    }
+
+    /// Create a new [`ELChainReader`] reading through `provider`, shared with the other
+    /// clients built with it.
+    pub fn new_with_provider(
+        logger: SharedLogger,
+        slasher: Address,
+        delegation_manager: Address,
+        avs_directory: Address,
+        provider: SharedProvider,
     ) -> Self {
         ELChainReader {
-            _logger,
+            logger,
             slasher,
             delegation_manager,
             avs_directory,
+            multicall: MULTICALL3_ADDRESS,
             provider,
         }
     }
 
+    /// Batch the calls through the Multicall3 deployed at `multicall` instead of
+    /// [`MULTICALL3_ADDRESS`]
+    pub fn with_multicall_address(mut self, multicall: Address) -> Self {
+        self.multicall = multicall;
+        self
+    }
+
+    /// Get the provider the reader reads through, to build other clients sharing its
+    /// connection
+    pub fn provider(&self) -> &SharedProvider {
+        &self.provider
+    }
+
+    /// Get the address of the DelegationManager contract
+    pub fn delegation_manager_address(&self) -> Address {
+        self.delegation_manager
+    }
+
+    /// Get the address of the AVSDirectory contract
+    pub fn avs_directory_address(&self) -> Address {
+        self.avs_directory
+    }
+
+    /// Create an empty batch of calls, executed with [`Self::call_multicall`]
+    pub fn multicall(&self) -> Multicall {
+        Multicall::new(self.multicall)
+    }
+
+    /// Execute a batch of calls at a single block
+    ///
+    /// # Arguments
+    ///
+    /// * `multicall` - The batch of calls
+    /// * `block_number` - The block to read the state at, the latest block if `None`
+    ///
+    /// # Returns
+    ///
+    /// * `MulticallResults` - The results of the calls, all read at the same block
+    pub async fn call_multicall(
+        &self,
+        multicall: &Multicall,
+        block_number: Option<u64>,
+    ) -> Result<MulticallResults, ElContractsError> {
+        let provider = &self.provider;
+        let results = match block_number {
+            Some(block_number) => multicall.call_at_block(provider, block_number).await?,
+            None => multicall.call(provider).await?,
+        };
+        Ok(results)
+    }
+
     /// Builds a new [`ELChainReader`] instance .
     pub async fn build(
-        _logger: SharedLogger,
+        logger: SharedLogger,
         delegation_manager: Address,
         avs_directory: Address,
-        client: &String,
+        client: &str,
     ) -> Result<Self, ElContractsError> {
-        let provider = get_provider(client);
+        Self::build_with_provider(
+            logger,
+            delegation_manager,
+            avs_directory,
+            get_shared_provider(client)
+                .map_err(|_| ElContractsError::InvalidRpcUrl(client.to_string()))?,
+        )
+        .await
+    }
 
-        let contract_delegation_manager = DelegationManager::new(delegation_manager, provider);
+    /// Builds a new [`ELChainReader`] instance reading through `provider`, shared with the
+    /// other clients built with it.
+    pub async fn build_with_provider(
+        logger: SharedLogger,
+        delegation_manager: Address,
+        avs_directory: Address,
+        provider: SharedProvider,
+    ) -> Result<Self, ElContractsError> {
+        let contract_delegation_manager = DelegationManager::new(delegation_manager, &provider);
 
         let slasher_result = contract_delegation_manager.slasher().call().await;
 
@@ -51,15 +188,16 @@ impl ELChainReader {
                 let DelegationManager::slasherReturn { _0: slasher_addr } = slasher;
 
                 Ok(Self {
-                    _logger,
+                    logger,
                     avs_directory,
                     slasher: slasher_addr,
                     delegation_manager,
-                    provider: client.to_string(),
+                    multicall: MULTICALL3_ADDRESS,
+                    provider,
                 })
             }
 
-            Err(e) => Err(ElContractsError::AlloyContractError(e)),
+            Err(e) => Err(ElContractsError::revert("DelegationManager")(e)),
         }
     }
 
@@ -71,7 +209,7 @@ impl ELChainReader {
         approve_salt: FixedBytes<32>,
         expiry: U256,
     ) -> Result<FixedBytes<32>, ElContractsError> {
-        let provider = get_provider(&self.provider);
+        let provider = &self.provider;
         let contract_delegation_manager = DelegationManager::new(self.delegation_manager, provider);
         let delegation_approval_digest_hash_result = contract_delegation_manager
             .calculateDelegationApprovalDigestHash(
@@ -91,7 +229,7 @@ impl ELChainReader {
 
                 Ok(digest_hash)
             }
-            Err(e) => Err(ElContractsError::AlloyContractError(e)),
+            Err(e) => Err(ElContractsError::revert("DelegationManager")(e)),
         }
     }
 
@@ -102,7 +240,7 @@ impl ELChainReader {
         salt: FixedBytes<32>,
         expiry: U256,
     ) -> Result<FixedBytes<32>, ElContractsError> {
-        let provider = get_provider(&self.provider);
+        let provider = &self.provider;
 
         let contract_avs_directory = AVSDirectory::new(self.avs_directory, provider);
 
@@ -118,7 +256,7 @@ impl ELChainReader {
 
                 Ok(avs_hash)
             }
-            Err(e) => Err(ElContractsError::AlloyContractError(e)),
+            Err(e) => Err(ElContractsError::revert("AVSDirectory")(e)),
         }
     }
 
@@ -137,7 +275,7 @@ impl ELChainReader {
         operator_addr: Address,
         strategy_addr: Address,
     ) -> Result<U256, ElContractsError> {
-        let provider = get_provider(&self.provider);
+        let provider = &self.provider;
 
         let contract_delegation_manager = DelegationManager::new(self.delegation_manager, provider);
 
@@ -145,17 +283,61 @@ impl ELChainReader {
             .operatorShares(operator_addr, strategy_addr)
             .call()
             .await
-            .map_err(ElContractsError::AlloyContractError)?;
+            .map_err(ElContractsError::revert("DelegationManager"))?;
 
         let DelegationManager::operatorSharesReturn { _0: shares } = operator_shares_in_strategy;
         Ok(shares)
     }
 
+    /// Get the operator's shares in several strategies, read at the same block
+    ///
+    /// # Arguments
+    ///
+    /// * `operator_addr` - The operator's address
+    /// * `strategy_addrs` - The strategies' addresses
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<U256>` - The operator's shares in each strategy, in the order of `strategy_addrs`
+    pub async fn get_operator_shares_in_strategies(
+        &self,
+        operator_addr: Address,
+        strategy_addrs: &[Address],
+    ) -> Result<Vec<U256>, ElContractsError> {
+        let mut multicall = self.multicall();
+        let handles: Vec<_> = strategy_addrs
+            .iter()
+            .map(|&strategy_addr| {
+                multicall.add_call(
+                    self.delegation_manager,
+                    &DelegationManager::operatorSharesCall {
+                        _0: operator_addr,
+                        _1: strategy_addr,
+                    },
+                )
+            })
+            .collect();
+        let results = self.call_multicall(&multicall, None).await?;
+
+        handles
+            .into_iter()
+            .map(|handle| {
+                results
+                    .get(handle)
+                    .map(|DelegationManager::operatorSharesReturn { _0: shares }| shares)
+                    .map_err(ElContractsError::multicall_revert_or(
+                        "DelegationManager",
+                        ElContractsError::GetOperatorShares,
+                    ))
+            })
+            .collect()
+    }
+
     pub async fn operator_is_frozen(
         &self,
         operator_addr: Address,
     ) -> Result<bool, ElContractsError> {
-        let provider = get_provider(&self.provider);
+        let provider = &self.provider;
 
         let contract_slasher = ISlasher::new(self.slasher, provider);
 
@@ -166,7 +348,7 @@ impl ELChainReader {
                 let ISlasher::isFrozenReturn { _0: is_froze } = operator_is_frozen;
                 Ok(is_froze)
             }
-            Err(e) => Err(ElContractsError::AlloyContractError(e)),
+            Err(e) => Err(ElContractsError::revert("ISlasher")(e)),
         }
     }
 
@@ -175,7 +357,7 @@ impl ELChainReader {
         operator_addr: Address,
         service_manager_addr: Address,
     ) -> Result<u32, ElContractsError> {
-        let provider = get_provider(&self.provider);
+        let provider = &self.provider;
 
         let contract_slasher = ISlasher::new(self.slasher, provider);
 
@@ -191,7 +373,7 @@ impl ELChainReader {
 
                 Ok(can_slash)
             }
-            Err(e) => Err(ElContractsError::AlloyContractError(e)),
+            Err(e) => Err(ElContractsError::revert("ISlasher")(e)),
         }
     }
 
@@ -214,9 +396,9 @@ impl ELChainReader {
         &self,
         strategy_addr: Address,
     ) -> Result<(Address, Address, Address), ElContractsError> {
-        let provider = get_provider(&self.provider);
+        let provider = &self.provider;
 
-        let contract_strategy = IStrategy::new(strategy_addr, &provider);
+        let contract_strategy = IStrategy::new(strategy_addr, provider);
 
         let underlying_token_result = contract_strategy.underlyingToken().call().await;
         match underlying_token_result {
@@ -225,7 +407,7 @@ impl ELChainReader {
                     _0: underlying_token_addr,
                 } = underlying_token;
 
-                let contract_ierc20 = IERC20::new(underlying_token_addr, &provider);
+                let contract_ierc20 = IERC20::new(underlying_token_addr, provider);
 
                 return Ok((
                     strategy_addr,
@@ -234,7 +416,7 @@ impl ELChainReader {
                 ));
             }
 
-            Err(e) => Err(ElContractsError::AlloyContractError(e)),
+            Err(e) => Err(ElContractsError::revert("IStrategy")(e)),
         }
     }
 
@@ -242,10 +424,9 @@ impl ELChainReader {
         &self,
         operator: Address,
     ) -> Result<Operator, ElContractsError> {
-        let provider = get_provider(&self.provider);
+        let provider = &self.provider;
 
-        let contract_delegation_manager =
-            DelegationManager::new(self.delegation_manager, &provider);
+        let contract_delegation_manager = DelegationManager::new(self.delegation_manager, provider);
 
         let operator_det_result = contract_delegation_manager
             .operatorDetails(operator)
@@ -266,7 +447,7 @@ impl ELChainReader {
                     None,
                 ))
             }
-            Err(e) => Err(ElContractsError::AlloyContractError(e)),
+            Err(e) => Err(ElContractsError::revert("DelegationManager")(e)),
         }
     }
 
@@ -274,7 +455,7 @@ impl ELChainReader {
         &self,
         operator: Address,
     ) -> Result<bool, ElContractsError> {
-        let provider = get_provider(&self.provider);
+        let provider = &self.provider;
 
         let contract_delegation_manager = DelegationManager::new(self.delegation_manager, provider);
 
@@ -288,28 +469,372 @@ impl ELChainReader {
                 let DelegationManager::isOperatorReturn { _0: is_operator_is } = is_operator;
                 Ok(is_operator_is)
             }
-            Err(e) => Err(ElContractsError::AlloyContractError(e)),
+            Err(e) => Err(ElContractsError::revert("DelegationManager")(e)),
         }
     }
+
+    /// Check if an operator already used a salt to sign an avs registration
+    ///
+    /// # Arguments
+    ///
+    /// * `operator` - The operator's address
+    /// * `salt` - The salt of the operator signature
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the salt is spent in the AVSDirectory
+    pub async fn is_operator_salt_spent(
+        &self,
+        operator: Address,
+        salt: FixedBytes<32>,
+    ) -> Result<bool, ElContractsError> {
+        let provider = &self.provider;
+
+        let contract_avs_directory = AVSDirectory::new(self.avs_directory, provider);
+
+        let AVSDirectory::operatorSaltIsSpentReturn { _0: is_spent } = contract_avs_directory
+            .operatorSaltIsSpent(operator, salt)
+            .call()
+            .await
+            .map_err(ElContractsError::revert("AVSDirectory"))?;
+        Ok(is_spent)
+    }
+
+    /// Get the operator a staker is delegated to
+    ///
+    /// # Arguments
+    ///
+    /// * `staker` - The staker's address
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Address>` - The operator, `None` if the staker isn't delegated
+    pub async fn get_delegated_operator(
+        &self,
+        staker: Address,
+    ) -> Result<Option<Address>, ElContractsError> {
+        let provider = &self.provider;
+
+        let contract_delegation_manager = DelegationManager::new(self.delegation_manager, provider);
+
+        let DelegationManager::delegatedToReturn { _0: operator } = contract_delegation_manager
+            .delegatedTo(staker)
+            .call()
+            .await
+            .map_err(ElContractsError::revert("DelegationManager"))?;
+        Ok((!operator.is_zero()).then_some(operator))
+    }
+
+    /// Check if a staker is delegated to an operator
+    ///
+    /// # Arguments
+    ///
+    /// * `staker` - The staker's address
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the staker is delegated, operators are delegated to themselves
+    pub async fn is_staker_delegated(&self, staker: Address) -> Result<bool, ElContractsError> {
+        let provider = &self.provider;
+
+        let contract_delegation_manager = DelegationManager::new(self.delegation_manager, provider);
+
+        let DelegationManager::isDelegatedReturn { _0: is_delegated } = contract_delegation_manager
+            .isDelegated(staker)
+            .call()
+            .await
+            .map_err(ElContractsError::revert("DelegationManager"))?;
+        Ok(is_delegated)
+    }
+
+    /// Get the address of the StrategyManager, read from the DelegationManager
+    pub async fn get_strategy_manager_address(&self) -> Result<Address, ElContractsError> {
+        let provider = &self.provider;
+
+        let contract_delegation_manager = DelegationManager::new(self.delegation_manager, provider);
+
+        let DelegationManager::strategyManagerReturn {
+            _0: strategy_manager,
+        } = contract_delegation_manager
+            .strategyManager()
+            .call()
+            .await
+            .map_err(ElContractsError::revert_or(
+                "DelegationManager",
+                ElContractsError::GetStrategyManager,
+            ))?;
+        Ok(strategy_manager)
+    }
+
+    /// Get the staker's shares in a strategy
+    ///
+    /// # Arguments
+    ///
+    /// * `staker` - The staker's address
+    /// * `strategy_addr` - The strategy's address
+    ///
+    /// # Returns
+    ///
+    /// * `U256` - The staker's shares deposited in the strategy through the StrategyManager
+    pub async fn get_staker_shares_in_strategy(
+        &self,
+        staker: Address,
+        strategy_addr: Address,
+    ) -> Result<U256, ElContractsError> {
+        let provider = &self.provider;
+
+        let contract_strategy_manager =
+            StrategyManager::new(self.get_strategy_manager_address().await?, provider);
+
+        let StrategyManager::stakerStrategySharesReturn { _0: shares } = contract_strategy_manager
+            .stakerStrategyShares(staker, strategy_addr)
+            .call()
+            .await
+            .map_err(ElContractsError::revert("StrategyManager"))?;
+        Ok(shares)
+    }
+
+    /// Get the staker's shares in every strategy it deposited into
+    ///
+    /// # Arguments
+    ///
+    /// * `staker` - The staker's address
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<StrategyShares>` - The staker's shares in each strategy of the StrategyManager it
+    ///   has shares in
+    pub async fn get_staker_deposits(
+        &self,
+        staker: Address,
+    ) -> Result<Vec<StrategyShares>, ElContractsError> {
+        let provider = &self.provider;
+
+        let contract_strategy_manager =
+            StrategyManager::new(self.get_strategy_manager_address().await?, provider);
+
+        let StrategyManager::getDepositsReturn {
+            _0: strategies,
+            _1: shares,
+        } = contract_strategy_manager
+            .getDeposits(staker)
+            .call()
+            .await
+            .map_err(ElContractsError::revert("StrategyManager"))?;
+        Ok(strategies
+            .into_iter()
+            .zip(shares)
+            .map(|(strategy, shares)| StrategyShares { strategy, shares })
+            .collect())
+    }
+
+    /// Get the shares delegated to an operator in several strategies, read at the same block
+    ///
+    /// # Arguments
+    ///
+    /// * `operator_addr` - The operator's address
+    /// * `strategy_addrs` - The strategies' addresses
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<StrategyShares>` - The shares delegated to the operator in each strategy, in the
+    ///   order of `strategy_addrs`
+    pub async fn get_operator_delegated_shares(
+        &self,
+        operator_addr: Address,
+        strategy_addrs: &[Address],
+    ) -> Result<Vec<StrategyShares>, ElContractsError> {
+        let shares = self
+            .get_operator_shares_in_strategies(operator_addr, strategy_addrs)
+            .await?;
+        Ok(strategy_addrs
+            .iter()
+            .zip(shares)
+            .map(|(&strategy, shares)| StrategyShares { strategy, shares })
+            .collect())
+    }
+
+    /// Get the withdrawal delay settings, read at the same block
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy_addrs` - The strategies to get the withdrawal delays of
+    ///
+    /// # Returns
+    ///
+    /// * `WithdrawalDelays` - The minimum withdrawal delay, the delay of each strategy and the
+    ///   delay of a withdrawal from all of them
+    pub async fn get_withdrawal_delays(
+        &self,
+        strategy_addrs: &[Address],
+    ) -> Result<WithdrawalDelays, ElContractsError> {
+        let mut multicall = self.multicall();
+        let min_withdrawal_delay_blocks = multicall.add_call(
+            self.delegation_manager,
+            &DelegationManager::minWithdrawalDelayBlocksCall {},
+        );
+        let withdrawal_delay_blocks = multicall.add_call(
+            self.delegation_manager,
+            &DelegationManager::getWithdrawalDelayCall {
+                strategies: strategy_addrs.to_vec(),
+            },
+        );
+        let strategy_handles: Vec<_> = strategy_addrs
+            .iter()
+            .map(|&strategy_addr| {
+                multicall.add_call(
+                    self.delegation_manager,
+                    &DelegationManager::strategyWithdrawalDelayBlocksCall { _0: strategy_addr },
+                )
+            })
+            .collect();
+        let results = self.call_multicall(&multicall, None).await?;
+
+        let DelegationManager::minWithdrawalDelayBlocksReturn {
+            _0: min_withdrawal_delay_blocks,
+        } = results.get(min_withdrawal_delay_blocks).map_err(
+            ElContractsError::multicall_revert_or(
+                "DelegationManager",
+                ElContractsError::GetWithdrawalDelays,
+            ),
+        )?;
+        let DelegationManager::getWithdrawalDelayReturn {
+            _0: withdrawal_delay_blocks,
+        } = results
+            .get(withdrawal_delay_blocks)
+            .map_err(ElContractsError::multicall_revert_or(
+                "DelegationManager",
+                ElContractsError::GetWithdrawalDelays,
+            ))?;
+        let strategy_withdrawal_delays = strategy_addrs
+            .iter()
+            .zip(strategy_handles)
+            .map(|(&strategy, handle)| {
+                results
+                    .get(handle)
+                    .map(
+                        |DelegationManager::strategyWithdrawalDelayBlocksReturn {
+                             _0: delay_blocks,
+                         }| StrategyWithdrawalDelay {
+                            strategy,
+                            delay_blocks,
+                        },
+                    )
+                    .map_err(ElContractsError::multicall_revert_or(
+                        "DelegationManager",
+                        ElContractsError::GetWithdrawalDelays,
+                    ))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(WithdrawalDelays {
+            min_withdrawal_delay_blocks,
+            strategy_withdrawal_delays,
+            withdrawal_delay_blocks,
+        })
+    }
+
+    /// Get the withdrawals queued by a staker in a block range
+    ///
+    /// # Arguments
+    ///
+    /// * `staker` - The staker's address
+    /// * `start_block` - The first block of the range
+    /// * `stop_block` - The last block of the range
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<QueuedWithdrawal>` - The withdrawals of the staker, in the order they were queued,
+    ///   along with whether they are still pending
+    pub async fn get_queued_withdrawals(
+        &self,
+        staker: Address,
+        start_block: u64,
+        stop_block: u64,
+    ) -> Result<Vec<QueuedWithdrawal>, ElContractsError> {
+        let provider = &self.provider;
+
+        let withdrawals: Vec<_> = LogScanner::new()
+            .scan_events::<DelegationManager::WithdrawalQueued, _, _>(
+                provider,
+                &Filter::new().address(self.delegation_manager),
+                start_block,
+                stop_block,
+            )
+            .await?
+            .into_iter()
+            .map(|log| log.inner.data)
+            .filter(|queued| queued.withdrawal.staker == staker)
+            .collect();
+
+        // the withdrawals are only pending until completed, check them at the same block
+        let mut multicall = self.multicall();
+        let handles: Vec<_> = withdrawals
+            .iter()
+            .map(|queued| {
+                multicall.add_call(
+                    self.delegation_manager,
+                    &DelegationManager::pendingWithdrawalsCall {
+                        _0: queued.withdrawalRoot,
+                    },
+                )
+            })
+            .collect();
+        let results = self.call_multicall(&multicall, None).await?;
+
+        withdrawals
+            .into_iter()
+            .zip(handles)
+            .map(|(queued, handle)| {
+                let DelegationManager::pendingWithdrawalsReturn { _0: pending } = results
+                    .get(handle)
+                    .map_err(ElContractsError::multicall_revert_or(
+                        "DelegationManager",
+                        ElContractsError::GetQueuedWithdrawals,
+                    ))?;
+                Ok(QueuedWithdrawal {
+                    root: queued.withdrawalRoot,
+                    withdrawal: queued.withdrawal,
+                    pending,
+                })
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::writer::ELChainWriter;
     use alloy_eips::eip1898::BlockNumberOrTag::Number;
+    use alloy_network::{EthereumWallet, TransactionBuilder};
     use alloy_primitives::{address, keccak256, Address, FixedBytes, U256};
     use alloy_provider::Provider;
+    use alloy_rpc_types::TransactionRequest;
+    use alloy_signer_local::PrivateKeySigner;
     use eigen_logging::get_test_logger;
     use eigen_testing_utils::anvil_constants::{self, ANVIL_HTTP_URL, ANVIL_RPC_URL};
+    use eigen_testing_utils::multicall::deploy_multicall3;
     use eigen_utils::binding::{
         mockAvsServiceManager, AVSDirectory,
         AVSDirectory::calculateOperatorAVSRegistrationDigestHashReturn, DelegationManager,
         DelegationManager::calculateDelegationApprovalDigestHashReturn,
+        DelegationManager::QueuedWithdrawalParams,
     };
+    use eigen_utils::{get_provider, get_wallet_provider};
     use serial_test::serial;
+    use std::str::FromStr;
     use tokio::time::{sleep, Duration};
 
+    /// Deployer of the anvil contracts, holding the mock erc20 tokens
+    const DEPLOYER_PRIVATE_KEY: &str =
+        "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+    /// Staker funded by the deployer, not registered as an operator nor delegated
+    const STAKER_PRIVATE_KEY: &str =
+        "3f1a4f5c6b7d8e9f0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f6071";
+
     async fn build_el_chain_reader() -> ELChainReader {
+        deploy_multicall3().await;
         let delegation_manager_address = anvil_constants::get_delegation_manager_address().await;
         let delegation_manager_contract = DelegationManager::new(
             delegation_manager_address,
@@ -435,4 +960,218 @@ mod tests {
 
         assert_eq!(hash, operator_hash);
     }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_build_with_provider() {
+        let el_chain_reader = build_el_chain_reader().await;
+        let provider = get_shared_provider(ANVIL_HTTP_URL).unwrap();
+
+        let shared_el_chain_reader = ELChainReader::build_with_provider(
+            get_test_logger(),
+            el_chain_reader.delegation_manager,
+            el_chain_reader.avs_directory,
+            provider.clone(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(shared_el_chain_reader.slasher, el_chain_reader.slasher);
+
+        let operator: Address = address!("5eb15C0992734B5e77c888D713b4FC67b3D679A2");
+        assert_eq!(
+            shared_el_chain_reader
+                .is_operator_registered(operator)
+                .await
+                .unwrap(),
+            el_chain_reader
+                .is_operator_registered(operator)
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            shared_el_chain_reader
+                .provider()
+                .get_block_number()
+                .await
+                .unwrap(),
+            provider.get_block_number().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_staker_and_delegation_reads() {
+        let el_chain_reader = build_el_chain_reader().await;
+        let operator: Address = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let strategy = anvil_constants::get_erc20_mock_strategy().await;
+
+        // operators are delegated to themselves
+        assert!(el_chain_reader.is_staker_delegated(operator).await.unwrap());
+        assert_eq!(
+            el_chain_reader
+                .get_delegated_operator(operator)
+                .await
+                .unwrap(),
+            Some(operator)
+        );
+        let staker = Address::with_last_byte(1);
+        assert!(!el_chain_reader.is_staker_delegated(staker).await.unwrap());
+        assert_eq!(
+            el_chain_reader
+                .get_delegated_operator(staker)
+                .await
+                .unwrap(),
+            None
+        );
+
+        let shares = el_chain_reader
+            .get_staker_shares_in_strategy(operator, strategy)
+            .await
+            .unwrap();
+        assert!(shares > U256::ZERO);
+        let deposits = el_chain_reader.get_staker_deposits(operator).await.unwrap();
+        assert!(deposits.contains(&StrategyShares { strategy, shares }));
+        assert!(el_chain_reader
+            .get_staker_deposits(staker)
+            .await
+            .unwrap()
+            .is_empty());
+
+        let delegated_shares = el_chain_reader
+            .get_operator_delegated_shares(operator, &[strategy])
+            .await
+            .unwrap();
+        assert_eq!(delegated_shares.len(), 1);
+        assert_eq!(delegated_shares[0].strategy, strategy);
+        assert!(delegated_shares[0].shares >= shares);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_get_withdrawal_delays() {
+        let el_chain_reader = build_el_chain_reader().await;
+        let strategy = anvil_constants::get_erc20_mock_strategy().await;
+
+        let delays = el_chain_reader
+            .get_withdrawal_delays(&[strategy])
+            .await
+            .unwrap();
+        assert_eq!(delays.strategy_withdrawal_delays.len(), 1);
+        assert_eq!(delays.strategy_withdrawal_delays[0].strategy, strategy);
+        assert!(delays.withdrawal_delay_blocks >= delays.min_withdrawal_delay_blocks);
+        assert!(
+            delays.withdrawal_delay_blocks >= delays.strategy_withdrawal_delays[0].delay_blocks
+        );
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_get_queued_withdrawals() {
+        let el_chain_reader = build_el_chain_reader().await;
+        let delegation_manager = anvil_constants::get_delegation_manager_address().await;
+        let strategy = anvil_constants::get_erc20_mock_strategy().await;
+        let deployer = PrivateKeySigner::from_str(DEPLOYER_PRIVATE_KEY).unwrap();
+        let deployer_provider = get_wallet_provider(EthereumWallet::from(deployer), ANVIL_HTTP_URL);
+        let staker_signer = PrivateKeySigner::from_str(STAKER_PRIVATE_KEY).unwrap();
+        let staker = staker_signer.address();
+        let staker_provider =
+            get_wallet_provider(EthereumWallet::from(staker_signer), ANVIL_HTTP_URL);
+        let amount = U256::from(100);
+
+        // fund a staker that is not delegated, so that no operator shares change
+        let receipt = deployer_provider
+            .send_transaction(
+                TransactionRequest::default()
+                    .with_to(staker)
+                    .with_value(U256::from(10).pow(U256::from(18))),
+            )
+            .await
+            .unwrap()
+            .get_receipt()
+            .await
+            .unwrap();
+        assert!(receipt.status());
+        let (_, token, _) = el_chain_reader
+            .get_strategy_and_underlying_erc20_token(strategy)
+            .await
+            .unwrap();
+        let receipt = IERC20::new(token, &deployer_provider)
+            .transfer(staker, amount)
+            .send()
+            .await
+            .unwrap()
+            .get_receipt()
+            .await
+            .unwrap();
+        assert!(receipt.status());
+        ELChainWriter::new(
+            delegation_manager,
+            anvil_constants::get_strategy_manager_address().await,
+            el_chain_reader.clone(),
+            ANVIL_HTTP_URL.to_string(),
+            STAKER_PRIVATE_KEY.to_string(),
+        )
+        .unwrap()
+        .deposit_erc20_into_strategy(strategy, amount)
+        .await
+        .unwrap();
+
+        // queue all the shares of the staker
+        let contract_delegation_manager =
+            DelegationManager::new(delegation_manager, &staker_provider);
+        let DelegationManager::getDelegatableSharesReturn {
+            _0: strategies,
+            _1: shares,
+        } = contract_delegation_manager
+            .getDelegatableShares(staker)
+            .call()
+            .await
+            .unwrap();
+        let DelegationManager::cumulativeWithdrawalsQueuedReturn { _0: nonce } =
+            contract_delegation_manager
+                .cumulativeWithdrawalsQueued(staker)
+                .call()
+                .await
+                .unwrap();
+        let receipt = contract_delegation_manager
+            .queueWithdrawals(vec![QueuedWithdrawalParams {
+                strategies: strategies.clone(),
+                shares: shares.clone(),
+                withdrawer: staker,
+            }])
+            .send()
+            .await
+            .unwrap()
+            .get_receipt()
+            .await
+            .unwrap();
+        assert!(receipt.status());
+        let queued_block = receipt.block_number.unwrap();
+
+        let withdrawal = Withdrawal {
+            staker,
+            delegatedTo: Address::ZERO,
+            withdrawer: staker,
+            nonce,
+            startBlock: queued_block as u32,
+            strategies,
+            shares,
+        };
+        let DelegationManager::calculateWithdrawalRootReturn { _0: expected_root } =
+            contract_delegation_manager
+                .calculateWithdrawalRoot(withdrawal.clone())
+                .call()
+                .await
+                .unwrap();
+
+        let withdrawals = el_chain_reader
+            .get_queued_withdrawals(staker, queued_block, queued_block)
+            .await
+            .unwrap();
+        assert_eq!(withdrawals.len(), 1);
+        assert_eq!(withdrawals[0].root, expected_root);
+        assert_eq!(withdrawals[0].withdrawal.nonce, withdrawal.nonce);
+        assert_eq!(withdrawals[0].withdrawal.shares, withdrawal.shares);
+        assert!(withdrawals[0].pending);
+    }
 }
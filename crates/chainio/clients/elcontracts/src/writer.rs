@@ -1,48 +1,209 @@
 use crate::error::ElContractsError;
 use crate::reader::ELChainReader;
-use alloy_primitives::{Address, FixedBytes, TxHash, U256};
+use alloy_network::{EthereumWallet, TxSigner};
+use alloy_primitives::{Address, U256};
+use alloy_rpc_types::TransactionRequest;
+use alloy_signer::Signature;
+use alloy_signer_local::PrivateKeySigner;
+use alloy_sol_types::SolEventInterface;
+use eigen_chainio_txmanager::{
+    simple_tx_manager::SimpleTxManager,
+    tx_manager::{TxManager, TxOutcome},
+};
 pub use eigen_types::operator::Operator;
 use eigen_utils::{
-    binding::{DelegationManager, StrategyManager, IERC20},
-    get_signer,
+    binding::{
+        DelegationManager::{self, DelegationManagerEvents},
+        StrategyManager::{self, StrategyManagerEvents},
+        IERC20::{self, IERC20Events},
+    },
+    SharedProvider,
 };
-
+use std::sync::Arc;
 use tracing::info;
 use DelegationManager::OperatorDetails;
 
 /// Gas limit for registerAsOperator in [`DelegationManager`]
 pub const GAS_LIMIT_REGISTER_AS_OPERATOR_DELEGATION_MANAGER: u128 = 300000;
 
+/// Result of [`ELChainWriter::update_operator_details`]
+#[derive(Debug)]
+pub struct OperatorDetailsUpdate {
+    /// The `modifyOperatorDetails` transaction
+    pub details: TxOutcome<DelegationManagerEvents>,
+    /// The `updateOperatorMetadataURI` transaction, sent once `details` is mined
+    pub metadata_uri: TxOutcome<DelegationManagerEvents>,
+}
+
+/// Result of [`ELChainWriter::deposit_erc20_into_strategy`]
+#[derive(Debug)]
+pub struct StrategyDeposit {
+    /// The `approve` transaction of the underlying token
+    pub approve: TxOutcome<IERC20Events>,
+    /// The `depositIntoStrategy` transaction, sent once `approve` is mined
+    pub deposit: TxOutcome<StrategyManagerEvents>,
+}
+
 #[derive(Debug, Clone)]
 pub struct ELChainWriter {
     delegation_manager: Address,
     strategy_manager: Address,
     el_chain_reader: ELChainReader,
-    provider: String,
-    signer: String,
+    provider: SharedProvider,
+    tx_manager: Arc<dyn TxManager>,
 }
 
 impl ELChainWriter {
+    /// Create a new ELChainWriter signing its transactions with a private key
+    ///
+    /// # Arguments
+    ///
+    /// * `delegation_manager` - address of the delegation manager
+    /// * `strategy_manager` - address of the strategy manager
+    /// * `el_chain_reader` - ELChainReader
+    /// * `provider` - provider string
+    /// * `signer` - private key of the operator
+    ///
+    /// # Errors
+    ///
+    /// * `ElContractsError::InvalidPrivateKey` - if the private key can't be parsed
     pub fn new(
         delegation_manager: Address,
         strategy_manager: Address,
         el_chain_reader: ELChainReader,
         provider: String,
         signer: String,
+    ) -> Result<Self, ElContractsError> {
+        let signer = signer
+            .parse::<PrivateKeySigner>()
+            .map_err(|_| ElContractsError::InvalidPrivateKey)?;
+        Self::new_with_signer(
+            delegation_manager,
+            strategy_manager,
+            el_chain_reader,
+            provider,
+            signer,
+        )
+    }
+
+    /// Create a new ELChainWriter signing its transactions with any [`TxSigner`], e.g. an AWS KMS
+    /// key or a Web3Signer
+    ///
+    /// # Arguments
+    ///
+    /// * `delegation_manager` - address of the delegation manager
+    /// * `strategy_manager` - address of the strategy manager
+    /// * `el_chain_reader` - ELChainReader
+    /// * `provider` - provider string
+    /// * `signer` - transaction signer of the operator
+    ///
+    /// # Errors
+    ///
+    /// * `ElContractsError::TxManager` - if the provider url is invalid
+    pub fn new_with_signer<S>(
+        delegation_manager: Address,
+        strategy_manager: Address,
+        el_chain_reader: ELChainReader,
+        provider: String,
+        signer: S,
+    ) -> Result<Self, ElContractsError>
+    where
+        S: TxSigner<Signature> + Send + Sync + 'static,
+    {
+        let tx_manager = SimpleTxManager::new_with_wallet(
+            el_chain_reader.logger.clone(),
+            1.0,
+            EthereumWallet::from(signer),
+            &provider,
+        )?;
+        Ok(Self::new_with_tx_manager(
+            delegation_manager,
+            strategy_manager,
+            el_chain_reader,
+            Arc::new(tx_manager),
+        ))
+    }
+
+    /// Create a new ELChainWriter reading through the provider of `el_chain_reader` and sending
+    /// its transactions through `tx_manager`
+    ///
+    /// # Arguments
+    ///
+    /// * `delegation_manager` - address of the delegation manager
+    /// * `strategy_manager` - address of the strategy manager
+    /// * `el_chain_reader` - ELChainReader, whose provider is shared with the writer
+    /// * `tx_manager` - tx manager signing and sending the transactions, e.g. a
+    ///   [`SimpleTxManager`] built with the same provider
+    pub fn new_with_tx_manager(
+        delegation_manager: Address,
+        strategy_manager: Address,
+        el_chain_reader: ELChainReader,
+        tx_manager: Arc<dyn TxManager>,
     ) -> Self {
+        let provider = el_chain_reader.provider().clone();
         Self {
             delegation_manager,
             strategy_manager,
             el_chain_reader,
             provider,
-            signer,
+            tx_manager,
         }
     }
 
+    /// Send the transactions through `tx_manager` instead of the default [`SimpleTxManager`]
+    pub fn with_tx_manager(mut self, tx_manager: Arc<dyn TxManager>) -> Self {
+        self.tx_manager = tx_manager;
+        self
+    }
+
+    /// Get the address of the operator sending the transactions
+    pub fn signer_address(&self) -> Address {
+        self.tx_manager.sender()
+    }
+
+    /// Send a transaction through the tx manager and wait for it to be mined
+    ///
+    /// # Arguments
+    ///
+    /// * `tx` - the transaction
+    /// * `contract` - the contract whose events are decoded from the receipt
+    /// * `contract_name` - the name of the contract, to decode the revert reason
+    ///
+    /// # Errors
+    ///
+    /// * `ElContractsError::ContractReverted` - if the transaction reverts before being sent
+    /// * `ElContractsError::TxReverted` - if the transaction reverted
+    async fn send_tx<E: SolEventInterface>(
+        &self,
+        mut tx: TransactionRequest,
+        contract: Address,
+        contract_name: &'static str,
+    ) -> Result<TxOutcome<E>, ElContractsError> {
+        let receipt = self
+            .tx_manager
+            .send_tx(&mut tx)
+            .await
+            .map_err(ElContractsError::tx_revert(contract_name))?;
+        if !receipt.status() {
+            return Err(ElContractsError::TxReverted(receipt.transaction_hash));
+        }
+        Ok(TxOutcome::new(receipt, contract))
+    }
+
+    /// Register the signer as an operator in EigenLayer
+    ///
+    /// # Arguments
+    ///
+    /// * `operator` - the operator details
+    ///
+    /// # Returns
+    ///
+    /// * `TxOutcome<DelegationManagerEvents>` - the receipt and events of the mined
+    ///   `registerAsOperator` transaction
     pub async fn register_as_operator(
         &self,
         operator: Operator,
-    ) -> Result<FixedBytes<32>, ElContractsError> {
+    ) -> Result<TxOutcome<DelegationManagerEvents>, ElContractsError> {
         info!(
             "registering operator {:?} to EigenLayer",
             operator.has_address()
@@ -52,39 +213,42 @@ impl ELChainWriter {
             delegationApprover: operator.has_delegation_approver_address(),
             stakerOptOutWindowBlocks: operator.has_staker_opt_out_window_blocks(),
         };
-        let provider = get_signer(self.signer.clone(), &self.provider);
+        let provider = &self.provider;
 
         let contract_delegation_manager = DelegationManager::new(self.delegation_manager, provider);
 
         let binding = {
             let contract_call = contract_delegation_manager
                 .registerAsOperator(op_details, operator.has_metadata_url().unwrap_or_default());
-            contract_call.gas(300000)
+            contract_call.gas(GAS_LIMIT_REGISTER_AS_OPERATOR_DELEGATION_MANAGER)
         };
 
-        let binding_tx = binding
-            .send()
-            .await
-            .map_err(ElContractsError::AlloyContractError)?;
-
-        let receipt = binding_tx.get_receipt().await.map_err(|e| {
-            ElContractsError::AlloyContractError(alloy_contract::Error::TransportError(e))
-        })?;
-
-        let tx_status = receipt.status();
-        let hash = receipt.transaction_hash;
-        if tx_status {
-            info!(tx_hash = %receipt.transaction_hash, "tx successfully included");
-        } else {
-            info!(tx_hash = %receipt.transaction_hash, "failed to register operator");
-        };
-        Ok(hash)
+        let outcome = self
+            .send_tx(
+                binding.into_transaction_request(),
+                self.delegation_manager,
+                "DelegationManager",
+            )
+            .await?;
+        info!(tx_hash = %outcome.tx_hash(), "tx successfully included");
+        Ok(outcome)
     }
 
+    /// Update the details and the metadata uri of the operator
+    ///
+    /// The metadata uri is only updated once the details update is mined.
+    ///
+    /// # Arguments
+    ///
+    /// * `operator` - the new operator details
+    ///
+    /// # Returns
+    ///
+    /// * `OperatorDetailsUpdate` - the receipts and events of both mined transactions
     pub async fn update_operator_details(
         &self,
         operator: Operator,
-    ) -> Result<TxHash, ElContractsError> {
+    ) -> Result<OperatorDetailsUpdate, ElContractsError> {
         info!(
             "updating operator detils of operator {:?} to EigenLayer",
             operator.has_address()
@@ -94,81 +258,107 @@ impl ELChainWriter {
             delegationApprover: operator.has_delegation_approver_address(),
             stakerOptOutWindowBlocks: operator.has_staker_opt_out_window_blocks(),
         };
-        let provider = get_signer(self.signer.clone(), &self.provider);
+        let provider = &self.provider;
 
         let contract_delegation_manager = DelegationManager::new(self.delegation_manager, provider);
 
         let contract_call_modify_operator_details =
             contract_delegation_manager.modifyOperatorDetails(operator_details);
 
-        let modify_operator_tx = contract_call_modify_operator_details
-            .send()
-            .await
-            .map_err(ElContractsError::AlloyContractError)?;
+        let details = self
+            .send_tx(
+                contract_call_modify_operator_details.into_transaction_request(),
+                self.delegation_manager,
+                "DelegationManager",
+            )
+            .await?;
 
-        info!(tx_hash = %modify_operator_tx.tx_hash(), operator = %operator.has_address(), "updated operator details tx");
+        info!(tx_hash = %details.tx_hash(), operator = %operator.has_address(), "updated operator details tx");
 
         let contract_call_update_metadata_uri = contract_delegation_manager
             .updateOperatorMetadataURI(operator.has_metadata_url().unwrap_or_default());
 
-        let metadata_tx = contract_call_update_metadata_uri.send().await?;
-
-        Ok(*metadata_tx.tx_hash())
+        let metadata_uri = self
+            .send_tx(
+                contract_call_update_metadata_uri.into_transaction_request(),
+                self.delegation_manager,
+                "DelegationManager",
+            )
+            .await?;
+
+        Ok(OperatorDetailsUpdate {
+            details,
+            metadata_uri,
+        })
     }
 
+    /// Deposit tokens into a strategy, approving the strategy manager to spend them first
+    ///
+    /// The deposit is only sent once the approval is mined.
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy_addr` - the strategy to deposit into
+    /// * `amount` - the amount of underlying tokens to deposit
+    ///
+    /// # Returns
+    ///
+    /// * `StrategyDeposit` - the receipts and events of the mined approve and deposit
+    ///   transactions
     pub async fn deposit_erc20_into_strategy(
         &self,
         strategy_addr: Address,
         amount: U256,
-    ) -> Result<TxHash, ElContractsError> {
+    ) -> Result<StrategyDeposit, ElContractsError> {
         info!(
             "depositing {:?} tokens into strategy {:?}",
             amount, strategy_addr
         );
-        let tokens_result = self
+        let (_, underlying_token_contract, underlying_token) = self
             .el_chain_reader
             .get_strategy_and_underlying_erc20_token(strategy_addr)
-            .await;
-        match tokens_result {
-            Ok(tokens) => {
-                let (_, underlying_token_contract, underlying_token) = tokens;
-                let provider = get_signer(self.signer.clone(), &self.provider);
+            .await?;
+        let provider = &self.provider;
 
-                let contract_underlying_token = IERC20::new(underlying_token_contract, &provider);
+        let contract_underlying_token = IERC20::new(underlying_token_contract, provider);
 
-                let contract_call =
-                    contract_underlying_token.approve(self.strategy_manager, amount);
+        let contract_call = contract_underlying_token.approve(self.strategy_manager, amount);
 
-                let _approve = contract_call.send().await?;
+        let approve = self
+            .send_tx(
+                contract_call.into_transaction_request(),
+                underlying_token_contract,
+                "IERC20",
+            )
+            .await?;
 
-                let contract_strategy_manager =
-                    StrategyManager::new(self.strategy_manager, &provider);
+        let contract_strategy_manager = StrategyManager::new(self.strategy_manager, provider);
 
-                let deposit_contract_call = contract_strategy_manager.depositIntoStrategy(
-                    strategy_addr,
-                    underlying_token,
-                    amount,
-                );
+        let deposit_contract_call =
+            contract_strategy_manager.depositIntoStrategy(strategy_addr, underlying_token, amount);
 
-                let tx = deposit_contract_call.send().await?;
+        let deposit = self
+            .send_tx(
+                deposit_contract_call.into_transaction_request(),
+                self.strategy_manager,
+                "StrategyManager",
+            )
+            .await?;
 
-                info!(
-                    "deposited {:?} tokens into strategy {:?}",
-                    amount, strategy_addr
-                );
-                Ok(*tx.tx_hash())
-            }
-            Err(e) => Err(e),
-        }
+        info!(
+            "deposited {:?} tokens into strategy {:?}",
+            amount, strategy_addr
+        );
+        Ok(StrategyDeposit { approve, deposit })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::ELChainWriter;
+    use crate::error::ElContractsError;
     use crate::reader::ELChainReader;
     use alloy_primitives::{Address, U256};
-    use alloy_provider::Provider;
     use alloy_signer_local::PrivateKeySigner;
     use anvil_constants::{ANVIL_RPC_URL, CONTRACTS_REGISTRY};
     use eigen_logging::get_test_logger;
@@ -180,7 +370,8 @@ mod tests {
     use eigen_utils::binding::{
         mockAvsServiceManager,
         ContractsRegistry::{self, get_test_valuesReturn},
-        DelegationManager,
+        DelegationManager::{self, DelegationManagerEvents},
+        StrategyManager::StrategyManagerEvents,
     };
     use std::str::FromStr;
 
@@ -258,6 +449,25 @@ mod tests {
             .unwrap());
     }
 
+    #[test]
+    fn test_new_invalid_private_key() {
+        let el_chain_reader = ELChainReader::new(
+            get_test_logger(),
+            Address::ZERO,
+            Address::ZERO,
+            Address::ZERO,
+            ANVIL_HTTP_URL.to_string(),
+        );
+        let result = ELChainWriter::new(
+            Address::ZERO,
+            Address::ZERO,
+            el_chain_reader,
+            ANVIL_HTTP_URL.to_string(),
+            "not a private key".to_string(),
+        );
+        assert!(matches!(result, Err(ElContractsError::InvalidPrivateKey)));
+    }
+
     #[tokio::test]
     async fn test_chain_writer() {
         let (el_chain_reader, _) = setup_el_chain_reader().await;
@@ -273,7 +483,8 @@ mod tests {
             el_chain_reader,
             http_endpoint,
             operator_private_key,
-        );
+        )
+        .unwrap();
 
         // define an operator
         let wallet = PrivateKeySigner::from_str(
@@ -290,18 +501,15 @@ mod tests {
         );
 
         // First test: register as an operator
-        let tx_hash = el_chain_writer
+        let outcome = el_chain_writer
             .register_as_operator(operator)
             .await
             .unwrap();
-
-        // this sleep is needed so that we wait for the tx to be processed
-        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
-        let receipt = ANVIL_RPC_URL
-            .get_transaction_receipt(tx_hash)
-            .await
-            .unwrap();
-        assert!(receipt.unwrap().status());
+        assert!(outcome.receipt.status());
+        assert!(outcome
+            .events
+            .iter()
+            .any(|event| matches!(event, DelegationManagerEvents::OperatorRegistered(_))));
 
         let wallet_modified = PrivateKeySigner::from_str(
             "2a871d0798f97d79848a013d4936a73bf4cc922c825d33c1cf7073dff6d409c6",
@@ -317,33 +525,26 @@ mod tests {
         );
 
         // Second test: update operator details
-        let tx_hash = el_chain_writer
+        let update = el_chain_writer
             .update_operator_details(operator_modified)
             .await
             .unwrap();
-
-        // this sleep is needed so that we wait for the tx to be processed
-        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
-        let receipt = ANVIL_RPC_URL
-            .get_transaction_receipt(tx_hash)
-            .await
-            .unwrap();
-        assert!(receipt.unwrap().status());
+        assert!(update.details.receipt.status());
+        assert!(update.metadata_uri.receipt.status());
 
         // Third test: deposit_erc20_into_strategy
         let amount = U256::from_str("100").unwrap();
         let strategy_addr = get_erc20_mock_strategy().await;
-        let tx_hash = el_chain_writer
+        let deposit = el_chain_writer
             .deposit_erc20_into_strategy(strategy_addr, amount)
             .await
             .unwrap();
-
-        // this sleep is needed so that we wait for the tx to be processed
-        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
-        let receipt = ANVIL_RPC_URL
-            .get_transaction_receipt(tx_hash)
-            .await
-            .unwrap();
-        assert!(receipt.unwrap().status());
+        assert!(deposit.approve.receipt.status());
+        assert!(deposit.deposit.receipt.status());
+        assert!(deposit
+            .deposit
+            .events
+            .iter()
+            .any(|event| matches!(event, StrategyManagerEvents::Deposit(_))));
     }
 }
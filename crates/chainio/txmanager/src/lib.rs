@@ -5,3 +5,4 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 
 pub mod simple_tx_manager;
+pub mod tx_manager;
@@ -1,18 +1,21 @@
+use crate::tx_manager::TxManager;
 use alloy_eips::BlockNumberOrTag;
-use alloy_network::{Ethereum, EthereumWallet, TransactionBuilder};
-use alloy_primitives::Address;
+use alloy_network::{Ethereum, EthereumWallet, TransactionBuilder, TxSigner};
+use alloy_primitives::{Address, Bytes};
 use alloy_provider::{PendingTransactionBuilder, Provider, ProviderBuilder, RootProvider};
 use alloy_rpc_types_eth::{TransactionInput, TransactionReceipt, TransactionRequest};
 use alloy_signer_local::PrivateKeySigner;
+use async_trait::async_trait;
 use eigen_logging::logger::SharedLogger;
 use eigen_signer::signer::Config;
-use k256::ecdsa::SigningKey;
+use eigen_utils::revert::revert_data;
 use reqwest::Url;
 use thiserror::Error;
 
 static FALLBACK_GAS_TIP_CAP: u128 = 5_000_000_000;
 
-pub type Transport = alloy_transport_http::Http<reqwest::Client>;
+/// Transport of the provider, boxed so the provider can be shared with the chain clients
+pub type Transport = alloy_transport::BoxTransport;
 
 /// Possible errors raised in Tx Manager
 #[derive(Error, Debug)]
@@ -27,12 +30,16 @@ pub enum TxManagerError {
     AddressError,
     #[error("invalid url error")]
     InvalidUrlError,
+    /// The transaction reverts, its gas can't be estimated
+    #[error("transaction reverts with data {0}")]
+    Reverted(Bytes),
 }
 
+#[derive(Debug)]
 pub struct SimpleTxManager {
     logger: SharedLogger,
     gas_limit_multiplier: f64,
-    private_key: String,
+    wallet: EthereumWallet,
     provider: RootProvider<Transport>,
 }
 
@@ -52,58 +59,101 @@ impl SimpleTxManager {
     ///
     /// # Errors
     ///
+    /// - If the private key is invalid.
     /// - If the URL is invalid.
     pub fn new(
         logger: SharedLogger,
         gas_limit_multiplier: f64,
         private_key: &str,
         rpc_url: &str,
+    ) -> Result<SimpleTxManager, TxManagerError> {
+        let config = Config::PrivateKey(private_key.to_string());
+        let signer: PrivateKeySigner = Config::signer_from_config(config)
+            .inspect_err(|err| logger.error("Failed to create signer", &err.to_string()))
+            .map_err(|_| TxManagerError::SignerError)?;
+        SimpleTxManager::new_with_wallet(
+            logger,
+            gas_limit_multiplier,
+            EthereumWallet::from(signer),
+            rpc_url,
+        )
+    }
+
+    /// Creates a new SimpleTxManager signing the transactions with a wallet, which can hold
+    /// any [`TxSigner`]: a local key, an AWS KMS key, a Web3Signer, etc.
+    ///
+    /// # Arguments
+    ///
+    /// - `logger`: The logger to be used.
+    /// - `gas_limit_multiplier`: The gas limit multiplier.
+    /// - `wallet`: The wallet signing the transactions.
+    /// - `rpc_url`: The RPC URL. It could be an anvil node or any other node.
+    ///
+    /// # Returns
+    ///
+    /// - The SimpleTxManager created.
+    ///
+    /// # Errors
+    ///
+    /// - If the URL is invalid.
+    pub fn new_with_wallet(
+        logger: SharedLogger,
+        gas_limit_multiplier: f64,
+        wallet: EthereumWallet,
+        rpc_url: &str,
     ) -> Result<SimpleTxManager, TxManagerError> {
         let url = Url::parse(rpc_url)
             .inspect_err(|err| logger.error("Failed to parse url", &err.to_string()))
             .map_err(|_| TxManagerError::InvalidUrlError)?;
-        let provider = ProviderBuilder::new().on_http(url);
-        Ok(SimpleTxManager {
+        let provider = ProviderBuilder::new().on_http(url).boxed();
+        Ok(SimpleTxManager::new_with_provider(
             logger,
             gas_limit_multiplier,
-            private_key: private_key.to_string(),
+            wallet,
             provider,
-        })
+        ))
     }
 
-    /// Returns the address of the wallet, beloing to the given private key.
+    /// Creates a new SimpleTxManager signing the transactions with a wallet and sending them
+    /// through an existing provider, sharing its connection.
+    ///
+    /// # Arguments
+    ///
+    /// - `logger`: The logger to be used.
+    /// - `gas_limit_multiplier`: The gas limit multiplier.
+    /// - `wallet`: The wallet signing the transactions.
+    /// - `provider`: The provider, over any transport boxed with `RootProvider::boxed`.
     ///
     /// # Returns
     ///
-    /// - The address of the wallet.
+    /// - The SimpleTxManager created.
+    pub fn new_with_provider(
+        logger: SharedLogger,
+        gas_limit_multiplier: f64,
+        wallet: EthereumWallet,
+        provider: RootProvider<Transport>,
+    ) -> SimpleTxManager {
+        SimpleTxManager {
+            logger,
+            gas_limit_multiplier,
+            wallet,
+            provider,
+        }
+    }
+
+    /// Returns the address of the wallet signing the transactions.
     ///
-    /// # Errors
+    /// # Returns
     ///
-    /// - If the private key is invalid.
+    /// - The address of the wallet.
     pub fn get_address(&self) -> Result<Address, TxManagerError> {
-        let private_key_signing_key = SigningKey::from_slice(self.private_key.as_bytes())
-            .inspect_err(|err| {
-                self.logger
-                    .error("Failed to parse private key", &err.to_string())
-            })
-            .map_err(|_| TxManagerError::AddressError)?;
-        Ok(Address::from_private_key(&private_key_signing_key))
+        Ok(self.wallet.default_signer().address())
     }
 
     pub fn with_gas_limit_multiplier(&mut self, multiplier: f64) {
         self.gas_limit_multiplier = multiplier;
     }
 
-    fn create_local_signer(&self) -> Result<PrivateKeySigner, TxManagerError> {
-        let config = Config::PrivateKey(self.private_key.clone());
-        Config::signer_from_config(config)
-            .inspect_err(|err| {
-                self.logger
-                    .error("Failed to create signer", &err.to_string())
-            })
-            .map_err(|_| TxManagerError::SignerError)
-    }
-
     /// Send is used to send a transaction to the Ethereum node. It takes an unsigned/signed transaction,
     /// sends it to the Ethereum node and waits for the receipt.
     /// If you pass in a signed transaction it will ignore the signature
@@ -128,11 +178,8 @@ impl SimpleTxManager {
                 .error("Failed to estimate gas", &err.to_string())
         })?;
 
-        let signer = self.create_local_signer()?;
-        let wallet = EthereumWallet::from(signer);
-
         let signed_tx = tx
-            .build(&wallet)
+            .build(&self.wallet)
             .await
             .inspect_err(|err| {
                 self.logger
@@ -143,9 +190,12 @@ impl SimpleTxManager {
         // send transaction and get receipt
         let pending_tx = self
             .provider
-            .send_transaction(signed_tx.into())
+            .send_tx_envelope(signed_tx)
             .await
-            .inspect_err(|err| self.logger.error("Failed to get receipt", &err.to_string()))
+            .inspect_err(|err| {
+                self.logger
+                    .error("Failed to send transaction", &err.to_string())
+            })
             .map_err(|_| TxManagerError::SendTxError)?;
 
         self.logger.debug(
@@ -198,11 +248,11 @@ impl SimpleTxManager {
         let base_fee = header.base_fee_per_gas.ok_or(TxManagerError::SendTxError)?;
         let gas_fee_cap = 2 * base_fee + gas_tip_cap;
 
+        let from = self.get_address()?;
         let mut gas_limit = tx.gas_limit();
         let tx_input = tx.input().unwrap_or_default().to_vec();
         // we only estimate if gas_limit is not already set
-        if let Some(0) = gas_limit {
-            let from = self.get_address()?;
+        if gas_limit.unwrap_or_default() == 0 {
             let to = match tx.to() {
                 Some(c) => c,
                 None => return Err(TxManagerError::SendTxError),
@@ -215,32 +265,58 @@ impl SimpleTxManager {
             tx_request.set_max_priority_fee_per_gas(gas_tip_cap);
             tx_request.set_max_fee_per_gas(gas_fee_cap);
 
-            gas_limit = Some(
-                self.provider
-                    .estimate_gas(&tx_request)
-                    .await
-                    .map_err(|_| TxManagerError::SendTxError)?,
-            );
+            let estimated_gas = self
+                .provider
+                .estimate_gas(&tx_request)
+                .await
+                .map_err(|err| match revert_data(&err) {
+                    Some(data) => TxManagerError::Reverted(data),
+                    None => TxManagerError::SendTxError,
+                })?;
+            // the estimate is scaled to leave room for state changes until the tx is mined
+            gas_limit = Some((estimated_gas as f64 * self.gas_limit_multiplier) as u128);
         }
-        let gas_price_multiplied =
-            tx.gas_price().unwrap_or_default() as f64 * self.gas_limit_multiplier;
-        let gas_price = gas_price_multiplied as u128;
-
         let to = match tx.to() {
             None => return Err(TxManagerError::SendTxError),
             Some(adress) => adress,
         };
+        let nonce = match tx.nonce() {
+            Some(nonce) => nonce,
+            None => self
+                .provider
+                .get_transaction_count(from)
+                // counts the txs still in the mempool, so back to back sends don't reuse a nonce
+                .pending()
+                .await
+                .inspect_err(|err| self.logger.error("Failed to get nonce", &err.to_string()))
+                .map_err(|_| TxManagerError::SendTxError)?,
+        };
+        let chain_id = match tx.chain_id() {
+            Some(chain_id) => chain_id,
+            None => self
+                .provider
+                .get_chain_id()
+                .await
+                .inspect_err(|err| {
+                    self.logger
+                        .error("Failed to get chain id", &err.to_string())
+                })
+                .map_err(|_| TxManagerError::SendTxError)?,
+        };
 
-        let new_tx = TransactionRequest::default()
+        let mut new_tx = TransactionRequest::default()
             .with_to(to)
             .with_value(tx.value().unwrap_or_default())
             .with_gas_limit(gas_limit.unwrap_or_default())
-            .with_nonce(tx.nonce().unwrap_or_default())
+            .with_nonce(nonce)
             .with_input(tx_input)
-            .with_chain_id(tx.chain_id().unwrap_or(1))
+            .with_chain_id(chain_id)
             .with_max_priority_fee_per_gas(gas_tip_cap)
-            .with_max_fee_per_gas(gas_fee_cap)
-            .with_gas_price(gas_price);
+            .with_max_fee_per_gas(gas_fee_cap);
+        // a gas price makes it a legacy transaction, so it is only set when the caller set one
+        if let Some(gas_price) = tx.gas_price() {
+            new_tx.set_gas_price((gas_price as f64 * self.gas_limit_multiplier) as u128);
+        }
 
         Ok(new_tx)
     }
@@ -269,6 +345,20 @@ impl SimpleTxManager {
     }
 }
 
+#[async_trait]
+impl TxManager for SimpleTxManager {
+    async fn send_tx(
+        &self,
+        tx: &mut TransactionRequest,
+    ) -> Result<TransactionReceipt, TxManagerError> {
+        SimpleTxManager::send_tx(self, tx).await
+    }
+
+    fn sender(&self) -> Address {
+        self.wallet.default_signer().address()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::SimpleTxManager;
@@ -276,6 +366,7 @@ mod tests {
     use alloy_network::TransactionBuilder;
     use alloy_node_bindings::Anvil;
     use alloy_primitives::{bytes, TxKind::Call, U256};
+    use alloy_provider::Provider;
     use alloy_rpc_types_eth::TransactionRequest;
     use eigen_logging::get_test_logger;
     use tokio;
@@ -352,4 +443,44 @@ mod tests {
         assert!(block_number > 0);
         assert_eq!(receipt.to, Some(bob));
     }
+
+    #[tokio::test]
+    async fn test_estimate_gas_and_nonce_of_pending_transaction() {
+        // Spin up a local Anvil node that keeps transactions in its mempool.
+        // Ensure `anvil` is available in $PATH.
+        let anvil = Anvil::new().arg("--no-mining").try_spawn().unwrap();
+        let rpc_url: String = anvil.endpoint().parse().unwrap();
+
+        let logger = get_test_logger();
+        let simple_tx_manager =
+            SimpleTxManager::new(logger, 1.5, PRIVATE_KEY, rpc_url.as_str()).unwrap();
+        let bob = anvil.addresses()[1];
+
+        // a first transaction is left unmined in the mempool
+        let first_tx = TransactionRequest::default()
+            .with_to(bob)
+            .with_value(U256::from(100));
+        let first_tx = simple_tx_manager
+            .estimate_gas_and_nonce(&first_tx)
+            .await
+            .unwrap();
+        assert_eq!(TransactionBuilder::nonce(&first_tx), Some(0));
+        let signed_tx = first_tx.build(&simple_tx_manager.wallet).await.unwrap();
+        let _pending_tx = simple_tx_manager
+            .provider
+            .send_tx_envelope(signed_tx)
+            .await
+            .unwrap();
+
+        let second_tx = TransactionRequest::default()
+            .with_to(bob)
+            .with_value(U256::from(100));
+        let second_tx = simple_tx_manager
+            .estimate_gas_and_nonce(&second_tx)
+            .await
+            .unwrap();
+        // a plain transfer is estimated at 21_000 gas
+        assert_eq!(TransactionBuilder::gas_limit(&second_tx), Some(31_500));
+        assert_eq!(TransactionBuilder::nonce(&second_tx), Some(1));
+    }
 }
@@ -0,0 +1,69 @@
+use crate::simple_tx_manager::TxManagerError;
+use alloy_primitives::{Address, TxHash};
+use alloy_rpc_types_eth::{TransactionReceipt, TransactionRequest};
+use alloy_sol_types::SolEventInterface;
+use async_trait::async_trait;
+use std::fmt::Debug;
+
+/// Signs and sends transactions, waiting for them to be mined.
+///
+/// [`SimpleTxManager`](crate::simple_tx_manager::SimpleTxManager) is the default implementation,
+/// used by the chain writers unless they are given another one.
+#[async_trait]
+pub trait TxManager: Debug + Send + Sync {
+    /// Sign and send a transaction, filling its gas, fees and nonce when unset, then wait for
+    /// its receipt.
+    ///
+    /// # Arguments
+    ///
+    /// - `tx`: The transaction to be sent.
+    ///
+    /// # Returns
+    ///
+    /// - The receipt of the transaction, whether it succeeded or reverted.
+    async fn send_tx(
+        &self,
+        tx: &mut TransactionRequest,
+    ) -> Result<TransactionReceipt, TxManagerError>;
+
+    /// Get the address the transactions are sent from
+    fn sender(&self) -> Address;
+}
+
+/// Receipt of a mined transaction along with the events it emitted
+#[derive(Debug, Clone)]
+pub struct TxOutcome<E> {
+    /// The receipt of the transaction
+    pub receipt: TransactionReceipt,
+    /// The events emitted by the contract the transaction was sent to, in log order
+    pub events: Vec<E>,
+}
+
+impl<E: SolEventInterface> TxOutcome<E> {
+    /// Build the outcome of a transaction, decoding the events emitted by `contract`.
+    ///
+    /// Logs of other contracts, and logs that don't decode as an `E`, are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// - `receipt`: The receipt of the transaction.
+    /// - `contract`: The address of the contract whose events are decoded.
+    pub fn new(receipt: TransactionReceipt, contract: Address) -> Self {
+        let events = receipt
+            .inner
+            .logs()
+            .iter()
+            .filter(|log| log.address() == contract)
+            .filter_map(|log| E::decode_log(&log.inner, true).ok())
+            .map(|log| log.data)
+            .collect();
+        Self { receipt, events }
+    }
+}
+
+impl<E> TxOutcome<E> {
+    /// Get the hash of the transaction
+    pub fn tx_hash(&self) -> TxHash {
+        self.receipt.transaction_hash
+    }
+}
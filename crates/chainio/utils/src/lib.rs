@@ -1,4 +1,13 @@
+//! Utilities shared by the chainio clients.
+
 #![doc(
     html_logo_url = "https://github.com/Layr-Labs/eigensdk-rs/assets/91280922/bd13caec-3c00-4afc-839a-b83d2890beb5",
     issue_tracker_base_url = "https://github.com/Layr-Labs/eigensdk-rs/issues/"
 )]
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+
+/// Chunked `eth_getLogs` scanner
+pub mod log_scanner;
+
+/// Multicall3 batching of contract calls
+pub mod multicall;
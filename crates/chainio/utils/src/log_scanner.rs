@@ -0,0 +1,412 @@
+use alloy_provider::Provider;
+use alloy_rpc_types::{Filter, Log};
+use alloy_sol_types::SolEvent;
+use alloy_transport::{RpcError, Transport, TransportError};
+use futures_util::{stream, StreamExt, TryStreamExt};
+use thiserror::Error;
+
+/// Default number of blocks queried by a single `eth_getLogs` call
+pub const DEFAULT_CHUNK_SIZE: u64 = 1024;
+
+/// Default number of `eth_getLogs` calls in flight
+pub const DEFAULT_MAX_CONCURRENCY: usize = 4;
+
+/// Fragments of the error messages returned by rpc providers when a `eth_getLogs` query
+/// matches too many logs or spans too many blocks. Rate limit errors are not range errors:
+/// splitting the range would only send more queries.
+const RANGE_ERROR_MESSAGES: [&str; 10] = [
+    "query returned more than",
+    "too many logs",
+    "too many blocks",
+    "too many results",
+    "block range",
+    "range is too large",
+    "range too large",
+    "range is too wide",
+    "exceed maximum block range",
+    "response size",
+];
+
+/// Error returned by [`LogScanner`]
+#[derive(Debug, Error)]
+pub enum LogScannerError {
+    /// Failed to get the logs of a block range
+    #[error("failed to get logs from block {from_block} to block {to_block}: {source}")]
+    GetLogs {
+        /// First block of the range
+        from_block: u64,
+        /// Last block of the range
+        to_block: u64,
+        /// The provider error
+        source: TransportError,
+    },
+
+    /// Failed to decode a log into the requested event
+    #[error("failed to decode log: {0}")]
+    DecodeLog(#[from] alloy_sol_types::Error),
+}
+
+/// Fetches the logs of a block range in chunks.
+///
+/// The range is split in chunks of `chunk_size` blocks, fetched concurrently up to
+/// `max_concurrency` at a time. When the provider rejects a chunk because it matches too many
+/// logs or spans too many blocks, the chunk is split in halves until it is accepted. Any other
+/// error, rate limits included, is returned. Logs are always returned in the order they were
+/// emitted.
+#[derive(Debug, Clone)]
+pub struct LogScanner {
+    chunk_size: u64,
+    max_concurrency: usize,
+}
+
+impl Default for LogScanner {
+    fn default() -> Self {
+        Self {
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+        }
+    }
+}
+
+impl LogScanner {
+    /// Create a new scanner with the default chunk size and concurrency
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of blocks queried by a single `eth_getLogs` call
+    ///
+    /// # Arguments
+    ///
+    /// * `chunk_size` - The number of blocks, at least 1
+    pub fn with_chunk_size(mut self, chunk_size: u64) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Set the maximum number of `eth_getLogs` calls in flight
+    ///
+    /// # Arguments
+    ///
+    /// * `max_concurrency` - The number of calls, at least 1
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency.max(1);
+        self
+    }
+
+    /// Get the logs matching `filter` between `from_block` and `to_block`, both included
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The provider to query
+    /// * `filter` - The filter to match. Its block range is ignored.
+    /// * `from_block` - The first block of the range
+    /// * `to_block` - The last block of the range
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<Log>` - The matching logs, in the order they were emitted
+    pub async fn scan<T, P>(
+        &self,
+        provider: &P,
+        filter: &Filter,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<Log>, LogScannerError>
+    where
+        T: Transport + Clone,
+        P: Provider<T>,
+    {
+        let chunks: Vec<Vec<Log>> =
+            stream::iter(chunk_ranges(from_block, to_block, self.chunk_size))
+                .map(|(from_block, to_block)| {
+                    get_logs_splitting_range(provider, filter, from_block, to_block)
+                })
+                .buffered(self.max_concurrency)
+                .try_collect()
+                .await?;
+        Ok(chunks.into_iter().flatten().collect())
+    }
+
+    /// Get the `E` events emitted between `from_block` and `to_block`, both included
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The provider to query
+    /// * `filter` - The filter to match, usually restricted to the emitting contract. The event
+    ///   signature is set from `E`, and the block range is ignored.
+    /// * `from_block` - The first block of the range
+    /// * `to_block` - The last block of the range
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<Log<E>>` - The decoded events along with their log metadata, in the order they
+    ///   were emitted
+    pub async fn scan_events<E, T, P>(
+        &self,
+        provider: &P,
+        filter: &Filter,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<Log<E>>, LogScannerError>
+    where
+        E: SolEvent,
+        T: Transport + Clone,
+        P: Provider<T>,
+    {
+        let filter = filter.clone().event_signature(E::SIGNATURE_HASH);
+        self.scan(provider, &filter, from_block, to_block)
+            .await?
+            .iter()
+            .map(|log| log.log_decode::<E>().map_err(LogScannerError::from))
+            .collect()
+    }
+}
+
+/// Split `from_block..=to_block` in ranges of at most `chunk_size` blocks
+fn chunk_ranges(from_block: u64, to_block: u64, chunk_size: u64) -> Vec<(u64, u64)> {
+    let mut ranges = vec![];
+    let mut start = from_block;
+    while start <= to_block {
+        let end = start.saturating_add(chunk_size - 1).min(to_block);
+        ranges.push((start, end));
+        if end == u64::MAX {
+            break;
+        }
+        start = end + 1;
+    }
+    ranges
+}
+
+/// Get the logs of a range, splitting it in halves while the provider rejects it as too large
+async fn get_logs_splitting_range<T, P>(
+    provider: &P,
+    filter: &Filter,
+    from_block: u64,
+    to_block: u64,
+) -> Result<Vec<Log>, LogScannerError>
+where
+    T: Transport + Clone,
+    P: Provider<T>,
+{
+    let mut logs = vec![];
+    // ranges left to query, the next one on top
+    let mut ranges = vec![(from_block, to_block)];
+    while let Some((from_block, to_block)) = ranges.pop() {
+        // ranges are inclusive on both ends
+        match provider
+            .get_logs(&filter.clone().select(from_block..to_block))
+            .await
+        {
+            Ok(range_logs) => logs.extend(range_logs),
+            Err(err) if from_block < to_block && is_range_error(&err) => {
+                let middle = from_block + (to_block - from_block) / 2;
+                ranges.push((middle + 1, to_block));
+                ranges.push((from_block, middle));
+            }
+            Err(source) => {
+                return Err(LogScannerError::GetLogs {
+                    from_block,
+                    to_block,
+                    source,
+                })
+            }
+        }
+    }
+    Ok(logs)
+}
+
+/// Whether the provider rejected a `eth_getLogs` query because its range is too large
+fn is_range_error(err: &TransportError) -> bool {
+    let message = match err {
+        RpcError::ErrorResp(payload) => payload.message.to_lowercase(),
+        RpcError::Transport(kind) => kind.to_string().to_lowercase(),
+        _ => return false,
+    };
+    RANGE_ERROR_MESSAGES
+        .iter()
+        .any(|fragment| message.contains(fragment))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_rpc::{ErrorPayload, RequestPacket, Response, ResponsePacket, ResponsePayload};
+    use alloy_provider::RootProvider;
+    use alloy_rpc_client::RpcClient;
+    use alloy_transport::TransportFut;
+    use std::{
+        sync::{Arc, Mutex},
+        task::{Context, Poll},
+    };
+    use tower::Service;
+
+    fn error_response(code: i64, message: &str) -> TransportError {
+        RpcError::ErrorResp(ErrorPayload {
+            code,
+            message: message.to_string(),
+            data: None,
+        })
+    }
+
+    /// Transport answering `eth_getLogs` with one log per block, and rejecting the queries
+    /// spanning more than `max_range` blocks with `error`
+    #[derive(Clone)]
+    struct MockTransport {
+        max_range: u64,
+        error: (i64, &'static str),
+        queries: Arc<Mutex<Vec<(u64, u64)>>>,
+    }
+
+    impl MockTransport {
+        fn new(max_range: u64, error: (i64, &'static str)) -> Self {
+            Self {
+                max_range,
+                error,
+                queries: Arc::default(),
+            }
+        }
+
+        fn provider(&self) -> RootProvider<Self> {
+            RootProvider::new(RpcClient::new(self.clone(), true))
+        }
+
+        fn queries(&self) -> Vec<(u64, u64)> {
+            self.queries.lock().unwrap().clone()
+        }
+    }
+
+    impl Service<RequestPacket> for MockTransport {
+        type Response = ResponsePacket;
+        type Error = TransportError;
+        type Future = TransportFut<'static>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: RequestPacket) -> Self::Future {
+            let RequestPacket::Single(request) = request else {
+                panic!("unexpected batch request");
+            };
+            assert_eq!(request.method(), "eth_getLogs");
+            let (filter,): (Filter,) =
+                serde_json::from_str(request.params().unwrap().get()).unwrap();
+            let from_block = filter.get_from_block().unwrap();
+            let to_block = filter.get_to_block().unwrap();
+            self.queries.lock().unwrap().push((from_block, to_block));
+
+            let payload = if to_block - from_block + 1 > self.max_range {
+                ResponsePayload::Failure(ErrorPayload {
+                    code: self.error.0,
+                    message: self.error.1.to_string(),
+                    data: None,
+                })
+            } else {
+                let logs: Vec<Log> = (from_block..=to_block)
+                    .map(|block_number| Log {
+                        block_number: Some(block_number),
+                        ..Default::default()
+                    })
+                    .collect();
+                ResponsePayload::Success(serde_json::value::to_raw_value(&logs).unwrap())
+            };
+            let response = ResponsePacket::Single(Response {
+                id: request.id().clone(),
+                payload,
+            });
+            Box::pin(async move { Ok(response) })
+        }
+    }
+
+    fn block_numbers(logs: &[Log]) -> Vec<u64> {
+        logs.iter().filter_map(|log| log.block_number).collect()
+    }
+
+    #[test]
+    fn test_chunk_ranges() {
+        assert_eq!(chunk_ranges(10, 35, 10), vec![(10, 19), (20, 29), (30, 35)]);
+        assert_eq!(chunk_ranges(10, 10, 10), vec![(10, 10)]);
+        assert_eq!(chunk_ranges(11, 10, 10), vec![]);
+        assert_eq!(
+            chunk_ranges(u64::MAX - 1, u64::MAX, 10),
+            vec![(u64::MAX - 1, u64::MAX)]
+        );
+    }
+
+    #[test]
+    fn test_is_range_error() {
+        assert!(is_range_error(&error_response(
+            -32005,
+            "query returned more than 10000 results"
+        )));
+        assert!(is_range_error(&error_response(
+            -32600,
+            "eth_getLogs block range is too large"
+        )));
+        assert!(is_range_error(&error_response(
+            -32602,
+            "Log response size exceeded"
+        )));
+        assert!(!is_range_error(&error_response(
+            -32000,
+            "execution reverted"
+        )));
+        assert!(!is_range_error(&RpcError::NullResp));
+    }
+
+    #[test]
+    fn test_is_range_error_rate_limit() {
+        assert!(!is_range_error(&error_response(429, "Too Many Requests")));
+        assert!(!is_range_error(&error_response(
+            -32005,
+            "daily request count exceeded, request rate limited"
+        )));
+        assert!(!is_range_error(&error_response(
+            -32007,
+            "100/second request limit reached - reduce calls per second"
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_scan_splits_rejected_ranges() {
+        let transport = MockTransport::new(3, (-32005, "query returned more than 10000 results"));
+        let logs = LogScanner::new()
+            .with_chunk_size(10)
+            .with_max_concurrency(2)
+            .scan(&transport.provider(), &Filter::new(), 0, 24)
+            .await
+            .unwrap();
+
+        assert_eq!(block_numbers(&logs), (0..=24).collect::<Vec<_>>());
+        let queries = transport.queries();
+        // each chunk is queried whole first, and only accepted ranges are small enough
+        for chunk in [(0, 9), (10, 19), (20, 24)] {
+            assert!(queries.contains(&chunk));
+        }
+        assert!(queries.contains(&(0, 2)));
+        assert!(queries.contains(&(23, 24)));
+    }
+
+    #[tokio::test]
+    async fn test_scan_returns_rate_limit_errors() {
+        let transport = MockTransport::new(3, (429, "Too Many Requests"));
+        let err = LogScanner::new()
+            .with_chunk_size(10)
+            .with_max_concurrency(1)
+            .scan(&transport.provider(), &Filter::new(), 0, 9)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            LogScannerError::GetLogs {
+                from_block: 0,
+                to_block: 9,
+                ..
+            }
+        ));
+        // the rate limited range is not split
+        assert_eq!(transport.queries(), vec![(0, 9)]);
+    }
+}
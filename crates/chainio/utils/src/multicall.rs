@@ -0,0 +1,389 @@
+use alloy_primitives::{address, Address, Bytes};
+use alloy_provider::Provider;
+use alloy_rpc_types::{BlockId, TransactionInput, TransactionRequest};
+use alloy_sol_types::{sol, SolCall};
+use alloy_transport::{Transport, TransportError};
+use eigen_utils::revert::revert_data;
+use std::fmt;
+use std::marker::PhantomData;
+use thiserror::Error;
+
+/// Address of Multicall3, deployed with the same address on most chains
+pub const MULTICALL3_ADDRESS: Address = address!("cA11bde05977b3631167028862bE2a173976CA11");
+
+sol! {
+    /// Multicall3 <https://github.com/mds1/multicall>
+    #[allow(missing_docs)]
+    #[derive(Debug)]
+    interface IMulticall3 {
+        struct Call3 {
+            address target;
+            bool allowFailure;
+            bytes callData;
+        }
+
+        struct Result {
+            bool success;
+            bytes returnData;
+        }
+
+        function aggregate3(Call3[] calldata calls) external payable returns (Result[] memory returnData);
+    }
+}
+
+/// Error returned by [`Multicall`]
+#[derive(Debug, Error)]
+pub enum MulticallError {
+    /// Failed to get the latest block number to pin the calls to
+    #[error("failed to get block number: {0}")]
+    GetBlockNumber(TransportError),
+
+    /// Failed to check if Multicall3 is deployed
+    #[error("failed to get Multicall3 code at block {block_number}: {source}")]
+    GetCode {
+        /// The pinned block
+        block_number: u64,
+        /// The provider error
+        source: TransportError,
+    },
+
+    /// The `eth_call` failed for another reason than a revert
+    #[error("eth_call failed at block {block_number}: {source}")]
+    Call {
+        /// The pinned block
+        block_number: u64,
+        /// The provider error
+        source: TransportError,
+    },
+
+    /// A call of the batch reverted
+    #[error("call {index} reverted")]
+    CallReverted {
+        /// Index of the call in the batch
+        index: usize,
+        /// The revert data
+        data: Bytes,
+    },
+
+    /// Multicall3 returned a different number of results than calls
+    #[error("expected {expected} results, got {actual}")]
+    ResultCount {
+        /// Number of calls
+        expected: usize,
+        /// Number of results
+        actual: usize,
+    },
+
+    /// Failed to decode the results of a call
+    #[error("failed to decode call result: {0}")]
+    Decode(#[from] alloy_sol_types::Error),
+}
+
+/// Handle to the result of a call added to a [`Multicall`]
+pub struct CallHandle<C> {
+    index: usize,
+    call: PhantomData<fn() -> C>,
+}
+
+impl<C> CallHandle<C> {
+    /// Index of the call in the batch
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl<C> Clone for CallHandle<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for CallHandle<C> {}
+
+impl<C> fmt::Debug for CallHandle<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CallHandle")
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+/// Batch of contract calls executed in a single `eth_call` to Multicall3.
+///
+/// Calls are pinned to one block, so all the results come from the same state. A reverting
+/// call doesn't revert the batch, its error is returned when getting its result.
+///
+/// When Multicall3 isn't deployed, e.g. on a local anvil chain, the calls are sent one by one,
+/// still pinned to the same block.
+#[derive(Debug, Clone)]
+pub struct Multicall {
+    address: Address,
+    calls: Vec<IMulticall3::Call3>,
+}
+
+impl Default for Multicall {
+    fn default() -> Self {
+        Self::new(MULTICALL3_ADDRESS)
+    }
+}
+
+impl Multicall {
+    /// Create an empty batch
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Address of Multicall3, usually [`MULTICALL3_ADDRESS`]
+    pub fn new(address: Address) -> Self {
+        Self {
+            address,
+            calls: vec![],
+        }
+    }
+
+    /// Add a call to the batch
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The called contract
+    /// * `call` - The call
+    ///
+    /// # Returns
+    ///
+    /// * `CallHandle<C>` - The handle to get the result of the call from [`MulticallResults`]
+    pub fn add_call<C: SolCall>(&mut self, target: Address, call: &C) -> CallHandle<C> {
+        self.calls.push(IMulticall3::Call3 {
+            target,
+            allowFailure: true,
+            callData: call.abi_encode().into(),
+        });
+        CallHandle {
+            index: self.calls.len() - 1,
+            call: PhantomData,
+        }
+    }
+
+    /// Number of calls in the batch
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    /// Whether the batch has no calls
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Execute the calls at the latest block
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The provider to query
+    ///
+    /// # Returns
+    ///
+    /// * `MulticallResults` - The results, along with the block they were read at
+    pub async fn call<T, P>(&self, provider: &P) -> Result<MulticallResults, MulticallError>
+    where
+        T: Transport + Clone,
+        P: Provider<T>,
+    {
+        let block_number = provider
+            .get_block_number()
+            .await
+            .map_err(MulticallError::GetBlockNumber)?;
+        self.call_at_block(provider, block_number).await
+    }
+
+    /// Execute the calls at a block
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The provider to query
+    /// * `block_number` - The block to read the state at
+    ///
+    /// # Returns
+    ///
+    /// * `MulticallResults` - The results
+    pub async fn call_at_block<T, P>(
+        &self,
+        provider: &P,
+        block_number: u64,
+    ) -> Result<MulticallResults, MulticallError>
+    where
+        T: Transport + Clone,
+        P: Provider<T>,
+    {
+        let block_id = BlockId::number(block_number);
+        if self.calls.is_empty() {
+            return Ok(MulticallResults {
+                block_number,
+                results: vec![],
+            });
+        }
+
+        let code = provider
+            .get_code_at(self.address)
+            .block_id(block_id)
+            .await
+            .map_err(|source| MulticallError::GetCode {
+                block_number,
+                source,
+            })?;
+        let results = if code.is_empty() {
+            self.call_one_by_one(provider, block_id, block_number)
+                .await?
+        } else {
+            let aggregate = IMulticall3::aggregate3Call {
+                calls: self.calls.clone(),
+            };
+            let return_data = provider
+                .call(&call_request(self.address, aggregate.abi_encode().into()))
+                .block(block_id)
+                .await
+                .map_err(|source| MulticallError::Call {
+                    block_number,
+                    source,
+                })?;
+            IMulticall3::aggregate3Call::abi_decode_returns(&return_data, true)?.returnData
+        };
+
+        if results.len() != self.calls.len() {
+            return Err(MulticallError::ResultCount {
+                expected: self.calls.len(),
+                actual: results.len(),
+            });
+        }
+        Ok(MulticallResults {
+            block_number,
+            results,
+        })
+    }
+
+    /// Send the calls one by one, for chains without Multicall3
+    async fn call_one_by_one<T, P>(
+        &self,
+        provider: &P,
+        block_id: BlockId,
+        block_number: u64,
+    ) -> Result<Vec<IMulticall3::Result>, MulticallError>
+    where
+        T: Transport + Clone,
+        P: Provider<T>,
+    {
+        let mut results = Vec::with_capacity(self.calls.len());
+        for call in self.calls.iter() {
+            let result = match provider
+                .call(&call_request(call.target, call.callData.clone()))
+                .block(block_id)
+                .await
+            {
+                Ok(return_data) => IMulticall3::Result {
+                    success: true,
+                    returnData: return_data,
+                },
+                Err(err) => match revert_data(&err) {
+                    Some(return_data) => IMulticall3::Result {
+                        success: false,
+                        returnData: return_data,
+                    },
+                    None => {
+                        return Err(MulticallError::Call {
+                            block_number,
+                            source: err,
+                        })
+                    }
+                },
+            };
+            results.push(result);
+        }
+        Ok(results)
+    }
+}
+
+/// Results of a [`Multicall`]
+#[derive(Debug, Clone)]
+pub struct MulticallResults {
+    block_number: u64,
+    results: Vec<IMulticall3::Result>,
+}
+
+impl MulticallResults {
+    /// The block all the calls were executed at
+    pub fn block_number(&self) -> u64 {
+        self.block_number
+    }
+
+    /// Get the decoded result of a call
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The handle returned when adding the call
+    ///
+    /// # Errors
+    ///
+    /// * `MulticallError::CallReverted` - if the call reverted
+    /// * `MulticallError::Decode` - if the returned data can't be decoded
+    pub fn get<C: SolCall>(&self, handle: CallHandle<C>) -> Result<C::Return, MulticallError> {
+        let result = self
+            .results
+            .get(handle.index)
+            .ok_or(MulticallError::ResultCount {
+                expected: handle.index + 1,
+                actual: self.results.len(),
+            })?;
+        if !result.success {
+            return Err(MulticallError::CallReverted {
+                index: handle.index,
+                data: result.returnData.clone(),
+            });
+        }
+        Ok(C::abi_decode_returns(&result.returnData, true)?)
+    }
+}
+
+/// Build the request of an `eth_call`
+fn call_request(to: Address, input: Bytes) -> TransactionRequest {
+    TransactionRequest::default()
+        .to(to)
+        .input(TransactionInput::new(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::U256;
+
+    sol! {
+        function balanceOf(address owner) external view returns (uint256);
+    }
+
+    #[test]
+    fn test_results_get() {
+        let mut multicall = Multicall::default();
+        let owner = Address::with_last_byte(1);
+        let first = multicall.add_call(Address::with_last_byte(2), &balanceOfCall { owner });
+        let second = multicall.add_call(Address::with_last_byte(3), &balanceOfCall { owner });
+        assert_eq!(multicall.len(), 2);
+        assert_eq!(second.index(), 1);
+
+        let results = MulticallResults {
+            block_number: 7,
+            results: vec![
+                IMulticall3::Result {
+                    success: true,
+                    returnData: U256::from(42).to_be_bytes::<32>().into(),
+                },
+                IMulticall3::Result {
+                    success: false,
+                    returnData: Bytes::from_static(&[0xde, 0xad]),
+                },
+            ],
+        };
+
+        assert_eq!(results.block_number(), 7);
+        assert_eq!(results.get(first).unwrap()._0, U256::from(42));
+        assert!(matches!(
+            results.get(second),
+            Err(MulticallError::CallReverted { index: 1, data }) if data == Bytes::from_static(&[0xde, 0xad])
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-use alloy_primitives::Address;
+use alloy_primitives::{Address, FixedBytes, U256};
 use clap::{ArgGroup, Parser, Subcommand};
 use eigen_testing_utils::anvil_constants::ANVIL_HTTP_URL;
 use rust_bls_bn254::{
@@ -52,6 +52,59 @@ outputs addresses for all relevant Eigenlayer and AVS contracts within the netwo
         #[command(subcommand)]
         subcommand: EigenKeyCommand,
     },
+
+    #[command(
+        about = "Checks an operator registration with the registry coordinator before sending it.
+Reports every check that would make registerOperator revert: the operator is registered
+in the DelegationManager and not yet in the quorums, the quorums exist, the operator stake
+meets their minimum stake, the salt is unused, the signature expiry is in the future and
+the bls key is not registered to another operator",
+        name = "preflight"
+    )]
+    RegistrationPreflight {
+        #[arg(long, help = "RegistryCoordinator contract address")]
+        registry_coordinator: Address,
+
+        #[arg(long, help = "OperatorStateRetriever contract address")]
+        operator_state_retriever: Address,
+
+        #[arg(long, help = "operator address")]
+        operator: Address,
+
+        #[arg(
+            long,
+            help = "(bn254) G1 public key of the operator, as its comma separated X,Y coordinates",
+            value_parser = parse_g1_coordinates
+        )]
+        bls_public_key: [U256; 2],
+
+        #[arg(long, help = "salt of the operator signature (32 bytes in hex)")]
+        salt: FixedBytes<32>,
+
+        #[arg(long, help = "expiry of the operator signature (unix timestamp)")]
+        expiry: u64,
+
+        #[arg(
+            long,
+            help = "comma separated quorum numbers to register for",
+            value_delimiter = ',',
+            required = true
+        )]
+        quorum_numbers: Vec<u8>,
+
+        #[arg(long, help = "rpc url", default_value = ANVIL_HTTP_URL)]
+        rpc_url: String,
+    },
+}
+
+/// Parses the comma separated X,Y coordinates of a G1 point
+fn parse_g1_coordinates(value: &str) -> Result<[U256; 2], String> {
+    let (x, y) = value
+        .split_once(',')
+        .ok_or_else(|| "expected the X,Y coordinates".to_string())?;
+    let x = x.trim().parse::<U256>().map_err(|err| err.to_string())?;
+    let y = y.trim().parse::<U256>().map_err(|err| err.to_string())?;
+    Ok([x, y])
 }
 
 #[derive(Subcommand, Debug)]
@@ -8,6 +8,7 @@ mod convert;
 pub mod eigen_address;
 mod generate;
 mod operator_id;
+mod preflight;
 
 use eth_keystore::KeystoreError;
 use tokio::runtime::Runtime;
@@ -21,6 +22,7 @@ use ark_serialize::SerializationError;
 use bls::BlsKeystore;
 use colored::*;
 use convert::store;
+use eigen_client_avsregistry::error::AvsRegistryError;
 use eigen_crypto_bls::error::BlsError;
 pub use generate::KeyGenerator;
 use operator_id::derive_operator_id;
@@ -34,6 +36,8 @@ pub enum EigenCliError {
     EigenAddressCliError(EigenAddressCliError),
     #[error("key error")]
     EigenKeyCliError(EigenKeyCliError),
+    #[error("preflight error")]
+    EigenPreflightCliError(EigenPreflightCliError),
 }
 
 /// Possible errors raised while trying to get contract addresses
@@ -45,6 +49,17 @@ pub enum EigenAddressCliError {
     RpcError(RpcError<TransportErrorKind>),
 }
 
+/// Possible errors raised while checking an operator registration
+#[derive(Error, Debug)]
+pub enum EigenPreflightCliError {
+    #[error("avs registry error")]
+    AvsRegistryError(AvsRegistryError),
+    #[error("BLS error")]
+    BLSError(BlsError),
+    #[error("{0} registration checks failed")]
+    ChecksFailed(usize),
+}
+
 /// Possible errors raised while executing egnkey commands
 #[derive(Error, Debug)]
 pub enum EigenKeyCliError {
@@ -155,7 +170,8 @@ pub fn execute_egnkey_subcommand(subcommand: EigenKeyCommand) -> Result<(), Eige
 ///
 /// # Arguments
 ///
-/// * `command` - A CLI command which can be `Commands::EigenAddress` or `Commands::EigenKey`
+/// * `command` - A CLI command which can be `Commands::EigenAddress`, `Commands::EigenKey` or
+///   `Commands::RegistrationPreflight`
 ///
 /// # Errors
 ///
@@ -180,6 +196,37 @@ pub fn execute_command(command: Commands) -> Result<(), EigenCliError> {
             execute_egnkey_subcommand(subcommand).map_err(EigenCliError::EigenKeyCliError)?;
             Ok(())
         }
+        Commands::RegistrationPreflight {
+            registry_coordinator,
+            operator_state_retriever,
+            operator,
+            bls_public_key,
+            salt,
+            expiry,
+            quorum_numbers,
+            rpc_url,
+        } => {
+            let rt = Runtime::new().unwrap();
+            let report = rt
+                .block_on(preflight::check_registration(
+                    registry_coordinator,
+                    operator_state_retriever,
+                    operator,
+                    bls_public_key,
+                    salt,
+                    expiry,
+                    quorum_numbers,
+                    rpc_url,
+                ))
+                .map_err(EigenCliError::EigenPreflightCliError)?;
+            preflight::print_report(&report);
+            if !report.is_ok() {
+                return Err(EigenCliError::EigenPreflightCliError(
+                    EigenPreflightCliError::ChecksFailed(report.failures.len()),
+                ));
+            }
+            Ok(())
+        }
     }
 }
 
@@ -189,14 +236,18 @@ mod test {
     use crate::convert::store;
     use crate::eigen_address::ContractAddresses;
     use crate::{
-        args::{Commands, KeyType},
+        args::{Args, Commands, KeyType},
         execute_command,
         generate::{KeyGenerator, DEFAULT_KEY_FOLDER, PASSWORD_FILE, PRIVATE_KEY_HEX_FILE},
         operator_id::derive_operator_id,
+        preflight, EigenCliError, EigenPreflightCliError,
     };
-    use alloy_primitives::Address;
+    use alloy_primitives::{Address, FixedBytes, U256};
+    use clap::Parser;
+    use eigen_crypto_bls::error::BlsError;
     use eigen_testing_utils::anvil_constants::{
-        get_registry_coordinator_address, get_service_manager_address, ANVIL_HTTP_URL,
+        get_operator_state_retriever_address, get_registry_coordinator_address,
+        get_service_manager_address, ANVIL_HTTP_URL,
     };
     use eigen_testing_utils::test_data::TestData;
     use eth_keystore::decrypt_key;
@@ -205,6 +256,7 @@ mod test {
     use rust_bls_bn254::keystores::base_keystore::Keystore;
     use serde::Deserialize;
     use std::fs;
+    use std::str::FromStr;
     use tempfile::tempdir;
 
     #[rstest]
@@ -386,4 +438,133 @@ mod test {
 
         assert_eq!(private_key, decrypted_private_key.as_slice());
     }
+
+    /// G1 public key of the bls private key
+    /// 12248929636257230549931416853095037629726205319386239410403476017439825112537
+    const BLS_PUBLIC_KEY_X: &str =
+        "277950648056014144722774518899051149098728246263316284984520891067822832300";
+    const BLS_PUBLIC_KEY_Y: &str =
+        "16927236637669640540790285431111034664564710839671197540688155537113438534238";
+
+    #[test]
+    fn test_preflight_args() {
+        let args = Args::try_parse_from([
+            "eigen",
+            "preflight",
+            "--registry-coordinator",
+            "0x0000000000000000000000000000000000000001",
+            "--operator-state-retriever",
+            "0x0000000000000000000000000000000000000002",
+            "--operator",
+            "0x0000000000000000000000000000000000000003",
+            "--bls-public-key",
+            "277950648056014144722774518899051149098728246263316284984520891067822832300,16927236637669640540790285431111034664564710839671197540688155537113438534238",
+            "--salt",
+            "0x0202020202020202020202020202020202020202020202020202020202020202",
+            "--expiry",
+            "1000",
+            "--quorum-numbers",
+            "0,1",
+        ])
+        .unwrap();
+
+        let Commands::RegistrationPreflight {
+            operator,
+            bls_public_key,
+            salt,
+            expiry,
+            quorum_numbers,
+            rpc_url,
+            ..
+        } = args.command
+        else {
+            panic!("expected the preflight command");
+        };
+        assert_eq!(operator, Address::with_last_byte(3));
+        assert_eq!(
+            bls_public_key,
+            [
+                U256::from_str(BLS_PUBLIC_KEY_X).unwrap(),
+                U256::from_str(BLS_PUBLIC_KEY_Y).unwrap()
+            ]
+        );
+        assert_eq!(salt, FixedBytes::from([0x02; 32]));
+        assert_eq!(expiry, 1000);
+        assert_eq!(quorum_numbers, vec![0, 1]);
+        assert_eq!(rpc_url, ANVIL_HTTP_URL);
+    }
+
+    #[tokio::test]
+    async fn test_preflight_reports_failed_checks() {
+        let command = Commands::RegistrationPreflight {
+            registry_coordinator: get_registry_coordinator_address().await,
+            operator_state_retriever: get_operator_state_retriever_address().await,
+            operator: Address::from([0x42; 20]),
+            bls_public_key: [
+                U256::from_str(BLS_PUBLIC_KEY_X).unwrap(),
+                U256::from_str(BLS_PUBLIC_KEY_Y).unwrap(),
+            ],
+            salt: FixedBytes::from([0x02; 32]),
+            expiry: 0,
+            quorum_numbers: vec![0],
+            rpc_url: ANVIL_HTTP_URL.to_string(),
+        };
+
+        // execute_command blocks on its own runtime
+        let result = tokio::task::spawn_blocking(move || execute_command(command))
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            result,
+            Err(EigenCliError::EigenPreflightCliError(
+                EigenPreflightCliError::ChecksFailed(_)
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_preflight_args_require_both_coordinates() {
+        let result = Args::try_parse_from([
+            "eigen",
+            "preflight",
+            "--registry-coordinator",
+            "0x0000000000000000000000000000000000000001",
+            "--operator-state-retriever",
+            "0x0000000000000000000000000000000000000002",
+            "--operator",
+            "0x0000000000000000000000000000000000000003",
+            "--bls-public-key",
+            BLS_PUBLIC_KEY_X,
+            "--salt",
+            "0x0202020202020202020202020202020202020202020202020202020202020202",
+            "--expiry",
+            "1000",
+            "--quorum-numbers",
+            "0",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_preflight_rejects_point_off_the_curve() {
+        // (1, 1) is not on y^2 = x^3 + 3, so no contract is called
+        let result = preflight::check_registration(
+            Address::ZERO,
+            Address::ZERO,
+            Address::from([0x42; 20]),
+            [U256::from(1), U256::from(1)],
+            FixedBytes::from([0x02; 32]),
+            0,
+            vec![0],
+            ANVIL_HTTP_URL.to_string(),
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(EigenPreflightCliError::BLSError(BlsError::InvalidG1Affine))
+        ));
+    }
 }
@@ -0,0 +1,94 @@
+use crate::EigenPreflightCliError;
+use alloy_primitives::{Address, Bytes, FixedBytes, U256};
+use ark_bn254::{Fq, G1Affine};
+use ark_ff::{BigInt, PrimeField};
+use colored::*;
+use eigen_client_avsregistry::preflight::{RegistrationPreflight, RegistrationPreflightReport};
+use eigen_crypto_bls::{error::BlsError, BlsG1Point};
+use eigen_logging::{get_logger, init_logger, log_level::LogLevel};
+
+/// Checks an operator registration with the registry coordinator.
+///
+/// # Arguments
+///
+/// * `registry_coordinator` - The registry coordinator contract address.
+/// * `operator_state_retriever` - The operator state retriever contract address.
+/// * `operator` - The operator address.
+/// * `bls_public_key` - The X and Y coordinates of the (bn254) G1 public key of the operator.
+/// * `salt` - The salt of the operator signature.
+/// * `expiry` - The expiry of the operator signature.
+/// * `quorum_numbers` - The quorums to register for.
+/// * `rpc_url` - The rpc url.
+///
+/// # Returns
+///
+/// * `RegistrationPreflightReport` - The failed checks.
+///
+/// # Errors
+///
+/// * If the bls public key is not a G1 point or a contract call fails.
+#[allow(clippy::too_many_arguments)]
+pub async fn check_registration(
+    registry_coordinator: Address,
+    operator_state_retriever: Address,
+    operator: Address,
+    bls_public_key: [U256; 2],
+    salt: FixedBytes<32>,
+    expiry: u64,
+    quorum_numbers: Vec<u8>,
+    rpc_url: String,
+) -> Result<RegistrationPreflightReport, EigenPreflightCliError> {
+    let bls_public_key =
+        g1_point_from_coordinates(bls_public_key).map_err(EigenPreflightCliError::BLSError)?;
+
+    init_logger(LogLevel::Info);
+    let preflight = RegistrationPreflight::build(
+        get_logger(),
+        registry_coordinator,
+        operator_state_retriever,
+        rpc_url,
+    )
+    .await
+    .map_err(EigenPreflightCliError::AvsRegistryError)?;
+
+    preflight
+        .check(
+            operator,
+            bls_public_key,
+            salt,
+            U256::from(expiry),
+            Bytes::from(quorum_numbers),
+        )
+        .await
+        .map_err(EigenPreflightCliError::AvsRegistryError)
+}
+
+/// Builds a G1 point from its coordinates, rejecting points that are not on the curve.
+fn g1_point_from_coordinates([x, y]: [U256; 2]) -> Result<BlsG1Point, BlsError> {
+    let x = Fq::from_bigint(BigInt::new(x.into_limbs())).ok_or(BlsError::InvalidG1Affine)?;
+    let y = Fq::from_bigint(BigInt::new(y.into_limbs())).ok_or(BlsError::InvalidG1Affine)?;
+    let g1 = G1Affine::new_unchecked(x, y);
+    if !g1.is_on_curve() || !g1.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(BlsError::InvalidG1Affine);
+    }
+    Ok(BlsG1Point::new(g1))
+}
+
+/// Prints every check of a registration preflight report.
+///
+/// # Arguments
+///
+/// * `report` - The report to print.
+pub fn print_report(report: &RegistrationPreflightReport) {
+    println!(
+        "operator {} (id {}), quorums {}",
+        report.operator, report.operator_id, report.quorum_numbers
+    );
+    if report.is_ok() {
+        println!("{}", "All registration checks passed".green().bold());
+        return;
+    }
+    for failure in report.failures.iter() {
+        println!("{} {}", "FAILED".red().bold(), failure);
+    }
+}
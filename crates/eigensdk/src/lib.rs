@@ -42,6 +42,10 @@ pub use eigen_contract_bindings as contract_bindings;
 #[doc(inline)]
 pub use eigen_client_avsregistry as client_avsregistry;
 
+#[cfg(feature = "client-ecdsastakeregistry")]
+#[doc(inline)]
+pub use eigen_client_ecdsastakeregistry as client_ecdsastakeregistry;
+
 #[cfg(feature = "client-elcontracts")]
 #[doc(inline)]
 pub use eigen_client_elcontracts as client_elcontracts;
@@ -60,10 +64,22 @@ pub use eigen_client_fireblocks as client_fireblocks;
 #[doc(inline)]
 pub use eigen_services_avsregistry as services_avsregistry;
 
+#[cfg(feature = "services-avssync")]
+#[doc(inline)]
+pub use eigen_services_avssync as services_avssync;
+
 #[cfg(feature = "services-blsaggregation")]
 #[doc(inline)]
 pub use eigen_services_blsaggregation as services_blsaggregation;
 
+#[cfg(feature = "services-churner")]
+#[doc(inline)]
+pub use eigen_services_churner as services_churner;
+
+#[cfg(feature = "services-ejector")]
+#[doc(inline)]
+pub use eigen_services_ejector as services_ejector;
+
 #[cfg(feature = "services-operatorsinfo")]
 #[doc(inline)]
 pub use eigen_services_operatorsinfo as services_operatorsinfo;
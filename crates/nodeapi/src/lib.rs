@@ -19,7 +19,7 @@ pub enum NodeHealth {
     Unhealthy,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ServiceStatus {
     Up,
     Down,
@@ -55,6 +55,11 @@ impl<R: AvsRegistryReader + Sync, S: OperatorInfoService + Sync> AvsRegistryServ
         for (quorum_id, quorum_num) in quorum_nums.iter().enumerate() {
             for operator in &operators_stakes_in_quorums[quorum_id] {
                 let info = self.get_operator_info(*operator.operatorId).await?;
+                let socket = self
+                    .operators_info_service
+                    .get_operator_socket_by_id(operator.operatorId)
+                    .await
+                    .unwrap_or(None);
                 let stake_per_quorum = HashMap::new();
                 let avs_state = operators_avs_state
                     .entry(FixedBytes(*operator.operatorId))
@@ -62,6 +67,7 @@ impl<R: AvsRegistryReader + Sync, S: OperatorInfoService + Sync> AvsRegistryServ
                         operator_id: *operator.operatorId,
                         operator_info: OperatorInfo {
                             pub_keys: Some(info),
+                            socket,
                         },
                         stake_per_quorum,
                         block_num: block_num.into(),
@@ -137,14 +143,17 @@ impl<R: AvsRegistryReader + Sync, S: OperatorInfoService + Sync> AvsRegistryServ
 }
 
 impl<R: AvsRegistryReader, S: OperatorInfoService> AvsRegistryServiceChainCaller<R, S> {
+    /// Get the operator public keys from the operator info service, looked up by operator id.
+    ///
+    /// # Arguments
+    ///
+    /// * `operator_id` - The operator id
     async fn get_operator_info(
         &self,
         operator_id: [u8; 32],
     ) -> Result<OperatorPubKeys, AvsRegistryError> {
-        let operator_addr = self.avs_registry.get_operator_from_id(operator_id).await?;
-
         self.operators_info_service
-            .get_operator_info(operator_addr)
+            .get_operator_info_by_id(operator_id.into())
             .await
             .unwrap_or(None)
             .ok_or(AvsRegistryError::GetOperatorInfo)
@@ -207,7 +216,8 @@ mod tests {
     ) -> AvsRegistryServiceChainCaller<FakeAvsRegistryReader, FakeOperatorInfoService> {
         let operator_address = Address::from_str(operator_address).unwrap();
         let avs_registry = FakeAvsRegistryReader::new(test_operator.clone(), operator_address);
-        let operator_info_service = FakeOperatorInfoService::new(test_operator.bls_keypair.clone());
+        let operator_info_service =
+            FakeOperatorInfoService::new(test_operator.bls_keypair.clone(), operator_address);
         AvsRegistryServiceChainCaller::new(avs_registry, operator_info_service)
     }
 
@@ -270,6 +280,7 @@ mod tests {
             operator_id: test_operator.operator_id.into(),
             operator_info: OperatorInfo {
                 pub_keys: Some(OperatorPubKeys::from(test_operator.bls_keypair)),
+                socket: None,
             },
             stake_per_quorum: test_operator.stake_per_quorum,
             block_num: test_data.input.block_num.into(),
@@ -0,0 +1,530 @@
+use crate::batch::{
+    batch_operators, batch_quorums, stake_changed, QuorumOperators, StakeUpdateBatch,
+};
+use crate::error::AvsSyncError;
+use alloy_primitives::{Address, Bytes, U256};
+use alloy_provider::Provider;
+use alloy_rpc_types::TransactionReceipt;
+use eigen_client_avsregistry::{
+    error::AvsRegistryError,
+    reader::{AvsRegistryChainReader, AvsRegistryReader},
+    writer::AvsRegistryChainWriter,
+};
+use eigen_logging::logger::SharedLogger;
+use std::collections::BTreeSet;
+use tokio::time::{interval, Duration, MissedTickBehavior};
+use tokio_util::sync::CancellationToken;
+
+/// Default gas limit of a single stake update transaction
+pub const DEFAULT_GAS_LIMIT_PER_TX: u64 = 10_000_000;
+
+/// Default estimate of the gas used to update the stake of one operator in one quorum
+pub const DEFAULT_GAS_PER_OPERATOR: u64 = 50_000;
+
+/// Gas used by a stake update transaction regardless of its operators
+const BASE_GAS_PER_TX: u64 = 100_000;
+
+/// Which stakes the [`AvsSyncService`] updates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncMode {
+    /// Update the whole operator set of each quorum with `updateOperatorsForQuorum`
+    FullOperatorSet,
+    /// Update with `updateOperators` only the operators whose stake changed by more than
+    /// `threshold_bips` basis points of their recorded stake
+    StakeChanges { threshold_bips: u16 },
+}
+
+/// Configuration of the [`AvsSyncService`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvsSyncConfig {
+    /// Interval between two syncs
+    pub sync_interval: Duration,
+    /// Quorums to sync, all of them if empty
+    pub quorum_numbers: Vec<u8>,
+    pub mode: SyncMode,
+    /// Gas limit a single stake update transaction must fit in
+    pub gas_limit_per_tx: u64,
+    /// Estimate of the gas used to update the stake of one operator in one quorum
+    pub gas_per_operator: u64,
+}
+
+impl Default for AvsSyncConfig {
+    fn default() -> Self {
+        Self {
+            sync_interval: Duration::from_secs(24 * 60 * 60),
+            quorum_numbers: vec![],
+            mode: SyncMode::FullOperatorSet,
+            gas_limit_per_tx: DEFAULT_GAS_LIMIT_PER_TX,
+            gas_per_operator: DEFAULT_GAS_PER_OPERATOR,
+        }
+    }
+}
+
+impl AvsSyncConfig {
+    /// Get the maximum number of operator stakes updated by a single transaction
+    pub fn max_operators_per_tx(&self) -> usize {
+        let operators =
+            self.gas_limit_per_tx.saturating_sub(BASE_GAS_PER_TX) / self.gas_per_operator.max(1);
+        usize::try_from(operators).unwrap_or(usize::MAX).max(1)
+    }
+}
+
+/// Stake sync service, updating on a schedule the stakes of the operators of an AVS in its stake
+/// registry
+#[derive(Debug)]
+pub struct AvsSyncService {
+    logger: SharedLogger,
+    avs_registry_reader: AvsRegistryChainReader,
+    avs_registry_writer: AvsRegistryChainWriter,
+    config: AvsSyncConfig,
+}
+
+impl AvsSyncService {
+    /// Create a new stake sync service
+    ///
+    /// # Arguments
+    ///
+    /// * `logger` - The logger
+    /// * `avs_registry_reader` - The AVS registry reader, used to read the operators of the
+    ///   quorums
+    /// * `avs_registry_writer` - The AVS registry writer, used to send the stake updates and wait
+    ///   for their receipts
+    /// * `config` - The sync configuration
+    pub fn new(
+        logger: SharedLogger,
+        avs_registry_reader: AvsRegistryChainReader,
+        avs_registry_writer: AvsRegistryChainWriter,
+        config: AvsSyncConfig,
+    ) -> Self {
+        Self {
+            logger,
+            avs_registry_reader,
+            avs_registry_writer,
+            config,
+        }
+    }
+
+    /// Sync the stakes every `sync_interval`, starting right away, until `cancellation_token` is
+    /// cancelled. A failed sync is logged and retried on the next interval.
+    pub async fn start(&self, cancellation_token: &CancellationToken) {
+        let mut sync_interval = interval(self.config.sync_interval);
+        sync_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => {
+                    self.logger.info("Stake sync cancelled", "eigen-services-avssync.start");
+                    return;
+                }
+                _ = sync_interval.tick() => {
+                    if let Err(err) = self.sync_once().await {
+                        self.logger.error(
+                            &format!("Failed to sync stakes: {}", err),
+                            "eigen-services-avssync.start",
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Update the stakes of the operators once
+    ///
+    /// # Returns
+    ///
+    /// The receipts of the stake update transactions, in the order they were sent. A reverted
+    /// update fails the sync with `AvsRegistryError::TxReverted`.
+    pub async fn sync_once(&self) -> Result<Vec<TransactionReceipt>, AvsSyncError> {
+        let quorum_numbers = self.quorum_numbers().await?;
+        if quorum_numbers.is_empty() {
+            return Ok(vec![]);
+        }
+        let block_number = self.current_block_number().await?;
+
+        let mut receipts = vec![];
+        match self.config.mode {
+            SyncMode::FullOperatorSet => {
+                let operators_per_quorum = self
+                    .avs_registry_reader
+                    .get_operators_stake_in_quorums_at_block(
+                        block_number,
+                        Bytes::from(quorum_numbers.clone()),
+                    )
+                    .await?;
+                let quorums = quorum_numbers
+                    .into_iter()
+                    .zip(operators_per_quorum)
+                    .map(|(quorum_number, operators)| {
+                        QuorumOperators::new(
+                            quorum_number,
+                            operators
+                                .into_iter()
+                                .map(|operator| operator.operator)
+                                .collect(),
+                        )
+                    })
+                    .collect();
+                for batch in batch_quorums(quorums, self.config.max_operators_per_tx()) {
+                    let outcome = match batch {
+                        StakeUpdateBatch::Quorums(quorums) => {
+                            let quorum_numbers: Vec<u8> =
+                                quorums.iter().map(|quorum| quorum.quorum_number).collect();
+                            self.logger.info(
+                                &format!(
+                                    "Updating stakes of entire operator set of quorums {:?}",
+                                    quorum_numbers
+                                ),
+                                "eigen-services-avssync.sync_once",
+                            );
+                            self.avs_registry_writer
+                                .update_stakes_of_entire_operator_set_for_quorums(
+                                    quorums.into_iter().map(|quorum| quorum.operators).collect(),
+                                    quorum_numbers.into(),
+                                )
+                                .await?
+                        }
+                        StakeUpdateBatch::Operators {
+                            quorum_number,
+                            operators,
+                        } => {
+                            self.logger.warn(
+                                &format!(
+                                    "Quorum {} has more operators than fit in a transaction, updating {} of them with updateOperators",
+                                    quorum_number,
+                                    operators.len()
+                                ),
+                                "eigen-services-avssync.sync_once",
+                            );
+                            self.avs_registry_writer
+                                .update_stakes_of_operator_subset_for_all_quorums(operators)
+                                .await?
+                        }
+                    };
+                    receipts.push(outcome.receipt);
+                }
+            }
+            SyncMode::StakeChanges { threshold_bips } => {
+                let changed_operators = self
+                    .operators_with_stake_changes(&quorum_numbers, block_number, threshold_bips)
+                    .await?;
+                self.logger.info(
+                    &format!(
+                        "Updating stakes of {} operators whose stake changed",
+                        changed_operators.len()
+                    ),
+                    "eigen-services-avssync.sync_once",
+                );
+                for batch in batch_operators(changed_operators, self.config.max_operators_per_tx())
+                {
+                    let outcome = self
+                        .avs_registry_writer
+                        .update_stakes_of_operator_subset_for_all_quorums(batch)
+                        .await?;
+                    receipts.push(outcome.receipt);
+                }
+            }
+        }
+        Ok(receipts)
+    }
+
+    /// Get the operators of quorums whose stake changed by more than `threshold_bips` basis
+    /// points of their recorded stake
+    ///
+    /// The recorded stakes and the current weights are both read at `block_number`, the weights
+    /// of each quorum in a single batch of calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `quorum_numbers` - The quorums whose operators are checked
+    /// * `block_number` - The block to read the stakes at
+    /// * `threshold_bips` - The threshold, in basis points of the recorded stake
+    ///
+    /// # Returns
+    ///
+    /// The operators whose stake changed, sorted by ascending address
+    pub async fn operators_with_stake_changes(
+        &self,
+        quorum_numbers: &[u8],
+        block_number: u32,
+        threshold_bips: u16,
+    ) -> Result<Vec<Address>, AvsSyncError> {
+        let operators_per_quorum = self
+            .avs_registry_reader
+            .get_operators_stake_in_quorums_at_block(
+                block_number,
+                Bytes::from(quorum_numbers.to_vec()),
+            )
+            .await?;
+
+        let mut changed_operators = BTreeSet::new();
+        for (quorum_number, operators) in quorum_numbers.iter().zip(operators_per_quorum) {
+            let addresses: Vec<Address> =
+                operators.iter().map(|operator| operator.operator).collect();
+            let weights = self
+                .avs_registry_reader
+                .weights_of_operators_for_quorum_at_block(*quorum_number, &addresses, block_number)
+                .await?;
+            for (operator, current_stake) in operators.into_iter().zip(weights) {
+                if stake_changed(U256::from(operator.stake), current_stake, threshold_bips) {
+                    changed_operators.insert(operator.operator);
+                }
+            }
+        }
+        Ok(changed_operators.into_iter().collect())
+    }
+
+    /// Get the number of the current block, which the stakes of a sync are read at
+    async fn current_block_number(&self) -> Result<u32, AvsSyncError> {
+        let block_number = self
+            .avs_registry_reader
+            .provider()
+            .get_block_number()
+            .await
+            .map_err(|_| AvsRegistryError::GetBlockNumber)?;
+        u32::try_from(block_number).map_err(|_| AvsRegistryError::BlockNumberOverflow.into())
+    }
+
+    /// Get the quorums to sync
+    async fn quorum_numbers(&self) -> Result<Vec<u8>, AvsSyncError> {
+        if !self.config.quorum_numbers.is_empty() {
+            return Ok(self.config.quorum_numbers.clone());
+        }
+        let quorum_count = self.avs_registry_reader.get_quorum_count().await?;
+        Ok((0..quorum_count).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_network::EthereumWallet;
+    use alloy_primitives::keccak256;
+    use alloy_signer_local::PrivateKeySigner;
+    use eigen_client_avsregistry::admin::AvsRegistryAdminWriter;
+    use eigen_client_elcontracts::{reader::ELChainReader, writer::ELChainWriter};
+    use eigen_crypto_bls::BlsKeyPair;
+    use eigen_logging::get_test_logger;
+    use eigen_testing_utils::anvil_constants::{
+        get_avs_directory_address, get_delegation_manager_address, get_erc20_mock_strategy,
+        get_operator_state_retriever_address, get_registry_coordinator_address,
+        get_strategy_manager_address, ANVIL_HTTP_URL, ANVIL_RPC_URL,
+    };
+    use eigen_types::operator::StrategyParams;
+    use eigen_utils::binding::{
+        RegistryCoordinator::{OperatorSetParam, RegistryCoordinatorEvents},
+        IERC20,
+    };
+    use eigen_utils::get_wallet_provider;
+    use std::str::FromStr;
+
+    const PRIVATE_KEY: &str = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+    /// Anvil accounts 6 and 7, registered as operators with EigenLayer in the anvil state
+    const OPERATOR_PRIVATE_KEYS: [&str; 2] = [
+        "92db14e403b83dfe3df233f83dfa3a0d7096f21ca9b0d6d6b8d88b2b4ec1564e",
+        "4bbbf85ce3377467afe5d46f804f221813b2bb87f24d81f60f1fcdbf7cbf4356",
+    ];
+    const OPERATOR_BLS_PRIVATE_KEYS: [&str; 2] = [
+        "17610126902690889134622698668747132666439281256983827313388062967626731803503",
+        "18610126902690889134622698668747132666439281256983827313388062967626731803504",
+    ];
+
+    /// Create a quorum weighting the erc20 mock strategy one to one, as the registry coordinator
+    /// owner, and register the test operators in it
+    ///
+    /// # Returns
+    ///
+    /// The number of the new quorum
+    async fn create_quorum_with_operators() -> u8 {
+        let registry_coordinator_address = get_registry_coordinator_address().await;
+        let admin_writer = AvsRegistryAdminWriter::build_avs_registry_admin_writer(
+            get_test_logger(),
+            ANVIL_HTTP_URL.to_string(),
+            PRIVATE_KEY.to_string(),
+            registry_coordinator_address,
+        )
+        .await
+        .unwrap();
+        let outcome = admin_writer
+            .create_quorum(
+                OperatorSetParam {
+                    maxOperatorCount: 10,
+                    kickBIPsOfOperatorStake: 100,
+                    kickBIPsOfTotalStake: 1000,
+                },
+                U256::ZERO,
+                vec![StrategyParams {
+                    strategy: get_erc20_mock_strategy().await,
+                    multiplier: U256::from(10).pow(U256::from(18)),
+                }],
+            )
+            .await
+            .unwrap();
+        let quorum_number = outcome
+            .events
+            .iter()
+            .find_map(|event| match event {
+                RegistryCoordinatorEvents::OperatorSetParamsUpdated(updated) => {
+                    Some(updated.quorumNumber)
+                }
+                _ => None,
+            })
+            .unwrap();
+
+        for (private_key, bls_private_key) in
+            OPERATOR_PRIVATE_KEYS.iter().zip(OPERATOR_BLS_PRIVATE_KEYS)
+        {
+            AvsRegistryChainWriter::build_avs_registry_chain_writer(
+                get_test_logger(),
+                ANVIL_HTTP_URL.to_string(),
+                private_key.to_string(),
+                registry_coordinator_address,
+                get_operator_state_retriever_address().await,
+            )
+            .await
+            .unwrap()
+            .register_operator_in_additional_quorums(
+                BlsKeyPair::new(bls_private_key.to_string()).unwrap(),
+                keccak256([quorum_number]),
+                U256::MAX,
+                Bytes::from([quorum_number]),
+                "".into(),
+            )
+            .await
+            .unwrap();
+        }
+        quorum_number
+    }
+
+    /// Increase the stake of an operator by depositing tokens sent by the deployer into the
+    /// erc20 mock strategy
+    async fn increase_stake(private_key: &str, amount: U256) {
+        let strategy = get_erc20_mock_strategy().await;
+        let el_chain_reader = ELChainReader::build(
+            get_test_logger(),
+            get_delegation_manager_address().await,
+            get_avs_directory_address().await,
+            ANVIL_HTTP_URL,
+        )
+        .await
+        .unwrap();
+        let (_, _, token) = el_chain_reader
+            .get_strategy_and_underlying_erc20_token(strategy)
+            .await
+            .unwrap();
+        let operator = PrivateKeySigner::from_str(private_key).unwrap().address();
+
+        let deployer = EthereumWallet::from(PrivateKeySigner::from_str(PRIVATE_KEY).unwrap());
+        let receipt = IERC20::new(token, get_wallet_provider(deployer, ANVIL_HTTP_URL))
+            .transfer(operator, amount)
+            .send()
+            .await
+            .unwrap()
+            .get_receipt()
+            .await
+            .unwrap();
+        assert!(receipt.status());
+
+        ELChainWriter::new(
+            get_delegation_manager_address().await,
+            get_strategy_manager_address().await,
+            el_chain_reader,
+            ANVIL_HTTP_URL.to_string(),
+            private_key.to_string(),
+        )
+        .unwrap()
+        .deposit_erc20_into_strategy(strategy, amount)
+        .await
+        .unwrap();
+    }
+
+    async fn build_avs_sync_service(config: AvsSyncConfig) -> AvsSyncService {
+        let registry_coordinator_address = get_registry_coordinator_address().await;
+        let operator_state_retriever_address = get_operator_state_retriever_address().await;
+        let avs_registry_reader = AvsRegistryChainReader::new(
+            get_test_logger(),
+            registry_coordinator_address,
+            operator_state_retriever_address,
+            ANVIL_HTTP_URL.to_string(),
+        )
+        .await
+        .unwrap();
+        let avs_registry_writer = AvsRegistryChainWriter::build_avs_registry_chain_writer(
+            get_test_logger(),
+            ANVIL_HTTP_URL.to_string(),
+            PRIVATE_KEY.to_string(),
+            registry_coordinator_address,
+            operator_state_retriever_address,
+        )
+        .await
+        .unwrap();
+        AvsSyncService::new(
+            get_test_logger(),
+            avs_registry_reader,
+            avs_registry_writer,
+            config,
+        )
+    }
+
+    #[test]
+    fn test_max_operators_per_tx() {
+        let config = AvsSyncConfig {
+            gas_limit_per_tx: 1_100_000,
+            gas_per_operator: 100_000,
+            ..Default::default()
+        };
+        assert_eq!(config.max_operators_per_tx(), 10);
+
+        let config = AvsSyncConfig {
+            gas_limit_per_tx: 0,
+            gas_per_operator: 0,
+            ..Default::default()
+        };
+        assert_eq!(config.max_operators_per_tx(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_sync_once_full_operator_set() {
+        let avs_sync = build_avs_sync_service(AvsSyncConfig {
+            quorum_numbers: vec![0],
+            ..Default::default()
+        })
+        .await;
+
+        let receipts = avs_sync.sync_once().await.unwrap();
+        assert_eq!(receipts.len(), 1);
+        assert!(receipts[0].status());
+    }
+
+    #[tokio::test]
+    async fn test_sync_once_stake_changes() {
+        let quorum_number = create_quorum_with_operators().await;
+        let avs_sync = build_avs_sync_service(AvsSyncConfig {
+            quorum_numbers: vec![quorum_number],
+            mode: SyncMode::StakeChanges { threshold_bips: 0 },
+            ..Default::default()
+        })
+        .await;
+        let changed_operator = PrivateKeySigner::from_str(OPERATOR_PRIVATE_KEYS[1])
+            .unwrap()
+            .address();
+
+        increase_stake(OPERATOR_PRIVATE_KEYS[1], U256::from(1000)).await;
+        let block_number = ANVIL_RPC_URL.get_block_number().await.unwrap() as u32;
+        let changed_operators = avs_sync
+            .operators_with_stake_changes(&[quorum_number], block_number, 0)
+            .await
+            .unwrap();
+        assert_eq!(changed_operators, vec![changed_operator]);
+
+        let receipts = avs_sync.sync_once().await.unwrap();
+        assert_eq!(receipts.len(), 1);
+        assert!(receipts[0].status());
+
+        // the updated stake is now the recorded one
+        let block_number = ANVIL_RPC_URL.get_block_number().await.unwrap() as u32;
+        assert!(avs_sync
+            .operators_with_stake_changes(&[quorum_number], block_number, 0)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,198 @@
+use alloy_primitives::{Address, U256};
+
+/// Denominator of the stake change thresholds, in basis points
+pub const BIPS_DENOMINATOR: u64 = 10_000;
+
+/// Operators of a quorum, sorted by ascending address as `updateOperatorsForQuorum` expects them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuorumOperators {
+    pub quorum_number: u8,
+    pub operators: Vec<Address>,
+}
+
+impl QuorumOperators {
+    /// Create the operator set of a quorum, sorting its operators by ascending address
+    pub fn new(quorum_number: u8, mut operators: Vec<Address>) -> Self {
+        operators.sort();
+        operators.dedup();
+        Self {
+            quorum_number,
+            operators,
+        }
+    }
+}
+
+/// Stake update transaction of a sync
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StakeUpdateBatch {
+    /// Whole operator sets of quorums, updated with `updateOperatorsForQuorum`
+    Quorums(Vec<QuorumOperators>),
+    /// Part of the operator set of a quorum too large for a single transaction, updated with
+    /// `updateOperators`
+    Operators {
+        quorum_number: u8,
+        operators: Vec<Address>,
+    },
+}
+
+/// Group whole quorums into batches of at most `max_operators` operators.
+///
+/// `updateOperatorsForQuorum` requires the full operator set of each quorum, so a quorum is never
+/// split across two such batches. The operators of a quorum with more than `max_operators`
+/// operators are instead split into [`StakeUpdateBatch::Operators`] batches, so that no
+/// transaction goes over the gas limit.
+///
+/// # Arguments
+///
+/// * `quorums` - The operator sets of the quorums
+/// * `max_operators` - The maximum number of operators updated by a single transaction
+pub fn batch_quorums(quorums: Vec<QuorumOperators>, max_operators: usize) -> Vec<StakeUpdateBatch> {
+    let mut batches = vec![];
+    let mut batch: Vec<QuorumOperators> = vec![];
+    let mut batch_size = 0;
+    for quorum in quorums {
+        let quorum_size = quorum.operators.len();
+        if quorum_size > max_operators {
+            let quorum_number = quorum.quorum_number;
+            batches.extend(
+                batch_operators(quorum.operators, max_operators)
+                    .into_iter()
+                    .map(|operators| StakeUpdateBatch::Operators {
+                        quorum_number,
+                        operators,
+                    }),
+            );
+            continue;
+        }
+        if !batch.is_empty() && batch_size + quorum_size > max_operators {
+            batches.push(StakeUpdateBatch::Quorums(std::mem::take(&mut batch)));
+            batch_size = 0;
+        }
+        batch_size += quorum_size;
+        batch.push(quorum);
+    }
+    if !batch.is_empty() {
+        batches.push(StakeUpdateBatch::Quorums(batch));
+    }
+    batches
+}
+
+/// Split operators into batches of at most `max_operators` operators, for `updateOperators`
+pub fn batch_operators(operators: Vec<Address>, max_operators: usize) -> Vec<Vec<Address>> {
+    operators
+        .chunks(max_operators.max(1))
+        .map(<[Address]>::to_vec)
+        .collect()
+}
+
+/// Whether the stake of an operator changed by more than `threshold_bips` of its recorded stake.
+///
+/// Any change from or to a zero stake is significant, as it registers or removes the operator
+/// from the quorum.
+///
+/// # Arguments
+///
+/// * `recorded_stake` - The stake recorded in the stake registry at the last update
+/// * `current_stake` - The current stake of the operator
+/// * `threshold_bips` - The threshold, in basis points of the recorded stake
+pub fn stake_changed(recorded_stake: U256, current_stake: U256, threshold_bips: u16) -> bool {
+    if recorded_stake.is_zero() || current_stake.is_zero() {
+        return recorded_stake != current_stake;
+    }
+    let change = recorded_stake.abs_diff(current_stake);
+    change.saturating_mul(U256::from(BIPS_DENOMINATOR))
+        > recorded_stake.saturating_mul(U256::from(threshold_bips))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quorum(quorum_number: u8, operators: usize) -> QuorumOperators {
+        QuorumOperators::new(
+            quorum_number,
+            (0..operators)
+                .map(|i| Address::with_last_byte(i as u8))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_quorum_operators_sorted() {
+        let quorum = QuorumOperators::new(
+            0,
+            vec![
+                Address::with_last_byte(3),
+                Address::with_last_byte(1),
+                Address::with_last_byte(3),
+            ],
+        );
+        assert_eq!(
+            quorum.operators,
+            vec![Address::with_last_byte(1), Address::with_last_byte(3)]
+        );
+    }
+
+    #[test]
+    fn test_batch_quorums() {
+        let batches = batch_quorums(vec![quorum(0, 3), quorum(1, 2), quorum(2, 4)], 5);
+        assert_eq!(
+            batches,
+            vec![
+                StakeUpdateBatch::Quorums(vec![quorum(0, 3), quorum(1, 2)]),
+                StakeUpdateBatch::Quorums(vec![quorum(2, 4)]),
+            ]
+        );
+
+        assert!(batch_quorums(vec![], 5).is_empty());
+    }
+
+    #[test]
+    fn test_batch_quorums_larger_than_limit() {
+        let batches = batch_quorums(vec![quorum(0, 1), quorum(1, 12), quorum(2, 1)], 5);
+
+        // the operators of the quorum over the limit are updated in batches of their own
+        let operators = quorum(1, 12).operators;
+        assert_eq!(
+            batches,
+            vec![
+                StakeUpdateBatch::Operators {
+                    quorum_number: 1,
+                    operators: operators[..5].to_vec(),
+                },
+                StakeUpdateBatch::Operators {
+                    quorum_number: 1,
+                    operators: operators[5..10].to_vec(),
+                },
+                StakeUpdateBatch::Operators {
+                    quorum_number: 1,
+                    operators: operators[10..].to_vec(),
+                },
+                StakeUpdateBatch::Quorums(vec![quorum(0, 1), quorum(2, 1)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_batch_operators() {
+        let operators: Vec<Address> = (0..5).map(Address::with_last_byte).collect();
+        let batches = batch_operators(operators.clone(), 2);
+        assert_eq!(batches.len(), 3);
+        assert_eq!(batches.concat(), operators);
+        assert_eq!(batch_operators(operators, 0).len(), 5);
+    }
+
+    #[test]
+    fn test_stake_changed() {
+        let stake = |stake: u64| U256::from(stake);
+        // 1% threshold
+        assert!(!stake_changed(stake(1000), stake(1010), 100));
+        assert!(stake_changed(stake(1000), stake(1011), 100));
+        assert!(stake_changed(stake(1000), stake(989), 100));
+        assert!(!stake_changed(stake(1000), stake(1000), 0));
+        assert!(stake_changed(stake(1000), stake(1001), 0));
+        assert!(stake_changed(stake(0), stake(1), 100));
+        assert!(stake_changed(stake(1), stake(0), 10_000));
+        assert!(!stake_changed(stake(0), stake(0), 0));
+    }
+}
@@ -0,0 +1,10 @@
+use eigen_client_avsregistry::error::AvsRegistryError;
+use thiserror::Error;
+
+/// Error returned by the stake sync service
+#[derive(Debug, Error)]
+pub enum AvsSyncError {
+    /// AvsRegistry errors
+    #[error("AvsRegistryError: {0}")]
+    AvsRegistry(#[from] AvsRegistryError),
+}
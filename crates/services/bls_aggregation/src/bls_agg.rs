@@ -8,7 +8,7 @@ use eigen_services_avsregistry::AvsRegistryService;
 use eigen_types::avs::SignatureVerificationError;
 use eigen_types::{
     avs::{SignedTaskResponseDigest, TaskIndex, TaskResponseDigest},
-    operator::{OperatorAvsState, QuorumThresholdPercentage, QuorumThresholdPercentages},
+    operator::{OperatorAvsState, QuorumThreshold, QuorumThresholds},
 };
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
@@ -104,18 +104,19 @@ impl<A: AvsRegistryService + Send + Sync + Clone + 'static> BlsAggregatorService
     /// * `task_index` - The index of the task
     /// * `task_created_block` - The block number at which the task was created
     /// * `quorum_nums` - The quorum numbers for the task
-    /// * `quorum_threshold_percentages` - The quorum threshold percentages for the task
+    /// * `quorum_thresholds` - The quorum thresholds for the task. Either [`QuorumThreshold`]s
+    ///   or `u8` percentages.
     /// * `time_to_expiry` - The timemetout for the task reader to expire
     ///
     /// # Error
     ///
     /// Returns error if the task index already exists
-    pub async fn initialize_new_task(
+    pub async fn initialize_new_task<T: Into<QuorumThreshold>>(
         &self,
         task_index: TaskIndex,
         task_created_block: u32,
         quorum_nums: Vec<u8>,
-        quorum_threshold_percentages: QuorumThresholdPercentages,
+        quorum_thresholds: Vec<T>,
         time_to_expiry: Duration,
     ) -> Result<(), BlsAggregationServiceError> {
         let mut task_channel = self.signed_task_response.write();
@@ -127,6 +128,9 @@ impl<A: AvsRegistryService + Send + Sync + Clone + 'static> BlsAggregatorService
         let (tx, rx) = mpsc::unbounded_channel();
         task_channel.insert(task_index, tx);
 
+        let quorum_thresholds: QuorumThresholds =
+            quorum_thresholds.into_iter().map(Into::into).collect();
+
         let avs_registry_service = self.avs_registry_service.clone();
         let aggregated_response_sender = self.aggregated_response_sender.clone();
         tokio::spawn(async move {
@@ -136,7 +140,7 @@ impl<A: AvsRegistryService + Send + Sync + Clone + 'static> BlsAggregatorService
                 task_index,
                 task_created_block,
                 quorum_nums.clone(),
-                quorum_threshold_percentages.clone(),
+                quorum_thresholds.clone(),
                 time_to_expiry,
                 aggregated_response_sender,
                 rx,
@@ -257,7 +261,7 @@ impl<A: AvsRegistryService + Send + Sync + Clone + 'static> BlsAggregatorService
     /// * `task_index` - The index of the task
     /// * `task_created_block` - The block number at which the task was created
     /// * `quorum_nums` - The quorum numbers for the task
-    /// * `quorum_threshold_percentages` - The quorum threshold percentages for the task
+    /// * `quorum_thresholds` - The quorum thresholds for the task
     /// * `time_to_expiry` - The timeout for the task reader to expire
     /// * `aggregated_response_sender` - The sender channel for the aggregated responses
     /// * `rx` - The receiver channel for the signed task responses
@@ -267,17 +271,17 @@ impl<A: AvsRegistryService + Send + Sync + Clone + 'static> BlsAggregatorService
         task_index: TaskIndex,
         task_created_block: u32,
         quorum_nums: Vec<u8>,
-        quorum_threshold_percentages: QuorumThresholdPercentages,
+        quorum_thresholds: QuorumThresholds,
         time_to_expiry: Duration,
         aggregated_response_sender: UnboundedSender<
             Result<BlsAggregationServiceResponse, BlsAggregationServiceError>,
         >,
         mut rx: UnboundedReceiver<SignedTaskResponseDigest>,
     ) -> Result<(), BlsAggregationServiceError> {
-        let quorum_threshold_percentage_map: HashMap<u8, u8> = quorum_nums
+        let quorum_threshold_map: HashMap<u8, QuorumThreshold> = quorum_nums
             .iter()
             .enumerate()
-            .map(|(i, quorum_number)| (*quorum_number, quorum_threshold_percentages[i]))
+            .map(|(i, quorum_number)| (*quorum_number, quorum_thresholds[i]))
             .collect();
 
         let operator_state_avs = avs_registry_service
@@ -366,7 +370,7 @@ impl<A: AvsRegistryService + Send + Sync + Clone + 'static> BlsAggregatorService
             if !BlsAggregatorService::<A>::check_if_stake_thresholds_met(
                 &digest_aggregated_operators.signers_total_stake_per_quorum,
                 &total_stake_per_quorum,
-                &quorum_threshold_percentage_map,
+                &quorum_threshold_map,
             ) {
                 continue;
             }
@@ -504,8 +508,8 @@ impl<A: AvsRegistryService + Send + Sync + Clone + 'static> BlsAggregatorService
     ///
     /// * `signed_stake_per_quorum` - The signed stake per quorum.
     /// * `total_stake_per_quorum` - The total stake per quorum.
-    /// * `quorum_threshold_percentages_map` - The quorum threshold percentages map,
-    ///   containing the quorum id as a key and its corresponding quorum threshold percentage.
+    /// * `quorum_thresholds_map` - The quorum thresholds map,
+    ///   containing the quorum id as a key and its corresponding quorum threshold.
     ///
     /// # Returns
     ///
@@ -513,9 +517,9 @@ impl<A: AvsRegistryService + Send + Sync + Clone + 'static> BlsAggregatorService
     pub fn check_if_stake_thresholds_met(
         signed_stake_per_quorum: &HashMap<u8, U256>,
         total_stake_per_quorum: &HashMap<u8, U256>,
-        quorum_threshold_percentages_map: &HashMap<u8, QuorumThresholdPercentage>,
+        quorum_thresholds_map: &HashMap<u8, QuorumThreshold>,
     ) -> bool {
-        for (quorum_num, quorum_threshold_percentage) in quorum_threshold_percentages_map {
+        for (quorum_num, quorum_threshold) in quorum_thresholds_map {
             let (Some(signed_stake_by_quorum), Some(total_stake_by_quorum)) = (
                 signed_stake_per_quorum.get(quorum_num),
                 total_stake_per_quorum.get(quorum_num),
@@ -523,10 +527,7 @@ impl<A: AvsRegistryService + Send + Sync + Clone + 'static> BlsAggregatorService
                 return false;
             };
 
-            let signed_stake = signed_stake_by_quorum * U256::from(100);
-            let threshold_stake = *total_stake_by_quorum * U256::from(*quorum_threshold_percentage);
-
-            if signed_stake < threshold_stake {
+            if !quorum_threshold.is_met(*signed_stake_by_quorum, *total_stake_by_quorum) {
                 return false;
             }
         }
@@ -540,7 +541,7 @@ mod tests {
     use eigen_crypto_bls::{BlsG1Point, BlsG2Point, BlsKeyPair, Signature};
     use eigen_services_avsregistry::fake_avs_registry_service::FakeAvsRegistryService;
     use eigen_types::avs::SignatureVerificationError::IncorrectSignature;
-    use eigen_types::operator::{QuorumNum, QuorumThresholdPercentages};
+    use eigen_types::operator::{QuorumNum, QuorumThreshold, QuorumThresholdPercentages};
     use eigen_types::{avs::TaskIndex, test::TestOperator};
     use sha2::{Digest, Sha256};
     use std::collections::HashMap;
@@ -1786,4 +1787,24 @@ mod tests {
             result
         );
     }
+
+    #[test]
+    fn test_check_if_stake_thresholds_met_with_basis_points() {
+        // 2 out of 3 signed, which is 66.66..%
+        let signed_stake_per_quorum = HashMap::from([(0u8, U256::from(2))]);
+        let total_stake_per_quorum = HashMap::from([(0u8, U256::from(3))]);
+        let check = |threshold: QuorumThreshold| {
+            BlsAggregatorService::<FakeAvsRegistryService>::check_if_stake_thresholds_met(
+                &signed_stake_per_quorum,
+                &total_stake_per_quorum,
+                &HashMap::from([(0u8, threshold)]),
+            )
+        };
+
+        assert!(check(QuorumThreshold::from_basis_points(6666)));
+        assert!(!check(QuorumThreshold::from_basis_points(6667)));
+        assert!(check(QuorumThreshold::new(2, 3).unwrap()));
+        assert!(check(66u8.into()));
+        assert!(!check(67u8.into()));
+    }
 }
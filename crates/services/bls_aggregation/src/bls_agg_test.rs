@@ -1,4 +1,5 @@
 #[cfg(test)]
+#[allow(deprecated)]
 pub mod integration_test {
     use crate::bls_agg::{BlsAggregationServiceResponse, BlsAggregatorService};
     use alloy_primitives::{hex, Bytes, FixedBytes, B256, U256};
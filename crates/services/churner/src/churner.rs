@@ -0,0 +1,366 @@
+use crate::error::ChurnerError;
+use alloy_primitives::{Address, Bytes, FixedBytes, U256};
+use alloy_signer::Signer;
+use alloy_signer_local::PrivateKeySigner;
+use eigen_client_avsregistry::{
+    churn::{validate_operator_to_kick, QuorumChurnState},
+    reader::AvsRegistryChainReader,
+};
+use eigen_logging::logger::SharedLogger;
+use eigen_utils::binding::RegistryCoordinator::OperatorKickParam;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Operator a registration kicks from a quorum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OperatorToKick {
+    pub quorum_number: u8,
+    /// The operator to kick, the zero address if the quorum is not full
+    pub operator: Address,
+}
+
+impl From<OperatorToKick> for OperatorKickParam {
+    fn from(operator_to_kick: OperatorToKick) -> Self {
+        OperatorKickParam {
+            quorumNumber: operator_to_kick.quorum_number,
+            operator: operator_to_kick.operator,
+        }
+    }
+}
+
+impl From<&OperatorKickParam> for OperatorToKick {
+    fn from(kick_param: &OperatorKickParam) -> Self {
+        OperatorToKick {
+            quorum_number: kick_param.quorumNumber,
+            operator: kick_param.operator,
+        }
+    }
+}
+
+/// Request of an operator to register with churn
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChurnRequest {
+    /// Address of the registering operator
+    pub operator_address: Address,
+    /// Id of the registering operator
+    pub operator_id: FixedBytes<32>,
+    /// Quorums the operator registers for
+    pub quorum_numbers: Vec<u8>,
+    /// Operators to kick, one per quorum number
+    pub operators_to_kick: Vec<OperatorToKick>,
+    /// Salt of the approval, it can only be used once
+    pub salt: FixedBytes<32>,
+}
+
+/// Churn approver signature of a [`ChurnRequest`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChurnResponse {
+    /// Signature of the churn approval digest
+    pub signature: Bytes,
+    pub salt: FixedBytes<32>,
+    /// Timestamp after which the signature is no longer valid
+    pub expiry: U256,
+}
+
+/// Error body returned by the churn approver server when it doesn't approve a churn request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChurnErrorResponse {
+    /// Why the request was not approved
+    pub error: String,
+}
+
+/// Churn approver, signing the registrations with churn whose operators to kick meet the churn
+/// ratios of their quorums
+///
+/// Clones share the salts issued, so that a salt is only ever signed once.
+#[derive(Debug, Clone)]
+pub struct ChurnApprover {
+    logger: SharedLogger,
+    avs_registry_reader: AvsRegistryChainReader,
+    signer: PrivateKeySigner,
+    signature_validity_secs: u64,
+    /// Salts of the approvals issued or in flight, along with the expiry of their signature
+    issued_salts: Arc<Mutex<HashMap<FixedBytes<32>, u64>>>,
+}
+
+impl ChurnApprover {
+    /// Create a new churn approver
+    ///
+    /// # Arguments
+    ///
+    /// * `logger` - The logger
+    /// * `avs_registry_reader` - The AVS registry reader
+    /// * `private_key` - The private key of the churn approver set on the registry coordinator
+    /// * `signature_validity_secs` - The number of seconds the signatures are valid for
+    pub fn new(
+        logger: SharedLogger,
+        avs_registry_reader: AvsRegistryChainReader,
+        private_key: &str,
+        signature_validity_secs: u64,
+    ) -> Result<Self, ChurnerError> {
+        let signer =
+            PrivateKeySigner::from_str(private_key).map_err(|_| ChurnerError::InvalidPrivateKey)?;
+        Ok(Self {
+            logger,
+            avs_registry_reader,
+            signer,
+            signature_validity_secs,
+            issued_salts: Arc::new(Mutex::new(HashMap::new())),
+        })
+    }
+
+    /// Get the address of the churn approver
+    pub fn address(&self) -> Address {
+        self.signer.address()
+    }
+
+    /// Validate a churn request against the current stakes and churn ratios, and sign it
+    ///
+    /// A salt is rejected while a request using it is in flight, and once it has been signed,
+    /// until the signature expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The churn request
+    ///
+    /// # Returns
+    ///
+    /// The churn approver signature, to pass to `registerOperatorWithChurn`
+    pub async fn approve(&self, request: &ChurnRequest) -> Result<ChurnResponse, ChurnerError> {
+        self.logger.info(
+            &format!(
+                "Churn request received operator_address: {} , quorum_numbers: {:?}",
+                request.operator_address, request.quorum_numbers
+            ),
+            "eigen-services-churner.approve",
+        );
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| ChurnerError::SystemTime)?
+            .as_secs();
+        self.reserve_salt(request.salt, now)?;
+        let result = self.validate_and_sign(request, now).await;
+
+        let mut issued_salts = self
+            .issued_salts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        match &result {
+            Ok(response) => {
+                issued_salts.insert(request.salt, now + self.signature_validity_secs);
+                self.logger.info(
+                    &format!(
+                        "Churn request approved operator_address: {} , expiry: {}",
+                        request.operator_address, response.expiry
+                    ),
+                    "eigen-services-churner.approve",
+                );
+            }
+            Err(_) => {
+                issued_salts.remove(&request.salt);
+            }
+        }
+        result
+    }
+
+    /// Mark a salt as in flight, forgetting the salts whose signature expired
+    fn reserve_salt(&self, salt: FixedBytes<32>, now: u64) -> Result<(), ChurnerError> {
+        let mut issued_salts = self
+            .issued_salts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        issued_salts.retain(|_, expiry| *expiry >= now);
+        if issued_salts.contains_key(&salt) {
+            return Err(ChurnerError::SaltAlreadyUsed(salt));
+        }
+        issued_salts.insert(salt, u64::MAX);
+        Ok(())
+    }
+
+    /// Validate a churn request and sign it with an expiry relative to `now`
+    async fn validate_and_sign(
+        &self,
+        request: &ChurnRequest,
+        now: u64,
+    ) -> Result<ChurnResponse, ChurnerError> {
+        if self
+            .avs_registry_reader
+            .is_churn_approver_salt_used(request.salt)
+            .await?
+        {
+            return Err(ChurnerError::SaltAlreadyUsed(request.salt));
+        }
+        if request.operators_to_kick.len() != request.quorum_numbers.len() {
+            return Err(ChurnerError::InvalidOperatorsToKick);
+        }
+
+        let operators_per_quorum = self
+            .avs_registry_reader
+            .get_operators_stake_in_quorums_at_current_block(request.quorum_numbers.clone().into())
+            .await?;
+        for ((quorum_number, operators), operator_to_kick) in request
+            .quorum_numbers
+            .iter()
+            .zip(operators_per_quorum)
+            .zip(&request.operators_to_kick)
+        {
+            let operator_set_params = self
+                .avs_registry_reader
+                .get_operator_set_params(*quorum_number)
+                .await?;
+            let quorum = QuorumChurnState {
+                quorum_number: *quorum_number,
+                max_operator_count: operator_set_params.maxOperatorCount,
+                kick_bips_of_operator_stake: operator_set_params.kickBIPsOfOperatorStake,
+                kick_bips_of_total_stake: operator_set_params.kickBIPsOfTotalStake,
+                operators: operators
+                    .into_iter()
+                    .map(|operator| (operator.operator, U256::from(operator.stake)))
+                    .collect(),
+                total_stake: self
+                    .avs_registry_reader
+                    .get_current_total_stake(*quorum_number)
+                    .await?,
+            };
+            let registering_operator_stake = self
+                .avs_registry_reader
+                .weight_of_operator_for_quorum(*quorum_number, request.operator_address)
+                .await?;
+            validate_operator_to_kick(
+                &quorum,
+                request.operator_address,
+                registering_operator_stake,
+                &(*operator_to_kick).into(),
+            )?;
+        }
+
+        let expiry = U256::from(now + self.signature_validity_secs);
+        let digest = self
+            .avs_registry_reader
+            .calculate_operator_churn_approval_digest_hash(
+                request.operator_address,
+                request.operator_id,
+                request
+                    .operators_to_kick
+                    .iter()
+                    .map(|operator_to_kick| (*operator_to_kick).into())
+                    .collect(),
+                request.salt,
+                expiry,
+            )
+            .await?;
+        let signature = self
+            .signer
+            .sign_hash(&digest)
+            .await
+            .map_err(|_| ChurnerError::Signature)?;
+        Ok(ChurnResponse {
+            signature: signature.as_bytes().into(),
+            salt: request.salt,
+            expiry,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::Signature;
+    use eigen_logging::get_test_logger;
+    use eigen_testing_utils::anvil_constants::{
+        get_operator_state_retriever_address, get_registry_coordinator_address, ANVIL_HTTP_URL,
+    };
+
+    const CHURN_APPROVER_PRIVATE_KEY: &str =
+        "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+    async fn build_churn_approver() -> ChurnApprover {
+        let avs_registry_reader = AvsRegistryChainReader::new(
+            get_test_logger(),
+            get_registry_coordinator_address().await,
+            get_operator_state_retriever_address().await,
+            ANVIL_HTTP_URL.to_string(),
+        )
+        .await
+        .unwrap();
+        ChurnApprover::new(
+            get_test_logger(),
+            avs_registry_reader,
+            CHURN_APPROVER_PRIVATE_KEY,
+            3600,
+        )
+        .unwrap()
+    }
+
+    fn churn_request(salt: u8) -> ChurnRequest {
+        ChurnRequest {
+            operator_address: Address::from([0x11; 20]),
+            operator_id: FixedBytes::from([0x22; 32]),
+            quorum_numbers: vec![0],
+            operators_to_kick: vec![OperatorToKick {
+                quorum_number: 0,
+                operator: Address::ZERO,
+            }],
+            salt: FixedBytes::from([salt; 32]),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_approve_quorum_not_full() {
+        let approver = build_churn_approver().await;
+        let request = churn_request(1);
+
+        let response = approver.approve(&request).await.unwrap();
+        assert_eq!(response.salt, request.salt);
+
+        let digest = approver
+            .avs_registry_reader
+            .calculate_operator_churn_approval_digest_hash(
+                request.operator_address,
+                request.operator_id,
+                vec![request.operators_to_kick[0].into()],
+                request.salt,
+                response.expiry,
+            )
+            .await
+            .unwrap();
+        let signature = Signature::try_from(response.signature.as_ref()).unwrap();
+        assert_eq!(
+            signature.recover_address_from_prehash(&digest).unwrap(),
+            approver.address()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_approve_rejects_missing_operators_to_kick() {
+        let approver = build_churn_approver().await;
+        let request = ChurnRequest {
+            operators_to_kick: vec![],
+            ..churn_request(2)
+        };
+
+        let result = approver.approve(&request).await;
+        assert!(matches!(result, Err(ChurnerError::InvalidOperatorsToKick)));
+    }
+
+    #[tokio::test]
+    async fn test_approve_rejects_issued_salt() {
+        let approver = build_churn_approver().await;
+        let request = churn_request(3);
+
+        // concurrent requests with the same salt only get one signature
+        let (first, second) = tokio::join!(approver.approve(&request), approver.approve(&request));
+        assert!(first.is_ok() != second.is_ok());
+        assert!(matches!(
+            first.and(second),
+            Err(ChurnerError::SaltAlreadyUsed(salt)) if salt == request.salt
+        ));
+
+        let result = approver.clone().approve(&request).await;
+        assert!(matches!(result, Err(ChurnerError::SaltAlreadyUsed(_))));
+    }
+}
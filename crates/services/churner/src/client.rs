@@ -0,0 +1,118 @@
+use crate::churner::{ChurnErrorResponse, ChurnRequest, ChurnResponse, OperatorToKick};
+use crate::server::CHURN_ROUTE;
+use async_trait::async_trait;
+use eigen_client_avsregistry::{
+    churn::{new_churn_approver_salt, ChurnApprovalRequest, ChurnApproverSignatureSource},
+    error::AvsRegistryError,
+};
+use eigen_utils::binding::RegistryCoordinator::SignatureWithSaltAndExpiry;
+
+/// [`ChurnApproverSignatureSource`] requesting the signature from a churn approver server
+#[derive(Debug, Clone)]
+pub struct RemoteChurnApprover {
+    url: String,
+    client: reqwest::Client,
+}
+
+impl RemoteChurnApprover {
+    /// Create a new remote churn approver
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The base url of the churn approver server, e.g. `http://localhost:9000`
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl ChurnApproverSignatureSource for RemoteChurnApprover {
+    async fn churn_approver_signature(
+        &self,
+        request: &ChurnApprovalRequest,
+    ) -> Result<SignatureWithSaltAndExpiry, AvsRegistryError> {
+        let churn_request = ChurnRequest {
+            operator_address: request.operator_address,
+            operator_id: request.operator_id,
+            quorum_numbers: request.quorum_numbers.to_vec(),
+            operators_to_kick: request
+                .operator_kick_params
+                .iter()
+                .map(OperatorToKick::from)
+                .collect(),
+            salt: new_churn_approver_salt(request.operator_id),
+        };
+
+        let response = self
+            .client
+            .post(format!("{}{}", self.url.trim_end_matches('/'), CHURN_ROUTE))
+            .json(&churn_request)
+            .send()
+            .await
+            .map_err(|_| AvsRegistryError::ChurnApproverSignature)?;
+        let status = response.status();
+        if !status.is_success() {
+            // keep the reason given by the server, so a request not eligible for churn can be
+            // told apart from a failing server
+            let body = response.text().await.unwrap_or_default();
+            let message = serde_json::from_str::<ChurnErrorResponse>(&body)
+                .map(|response| response.error)
+                .unwrap_or(body);
+            return Err(AvsRegistryError::ChurnApproverRejected {
+                status: status.as_u16(),
+                message,
+            });
+        }
+        let churn_response: ChurnResponse = response
+            .json()
+            .await
+            .map_err(|_| AvsRegistryError::ChurnApproverSignature)?;
+
+        Ok(SignatureWithSaltAndExpiry {
+            signature: churn_response.signature,
+            salt: churn_response.salt,
+            expiry: churn_response.expiry,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Address, Bytes, FixedBytes};
+    use ntex::web::{self, test, App, HttpResponse};
+
+    #[ntex::test]
+    async fn test_churn_approver_signature_rejected() {
+        let server = test::server(|| {
+            App::new().route(
+                CHURN_ROUTE,
+                web::post().to(|| async {
+                    HttpResponse::BadRequest().json(&ChurnErrorResponse {
+                        error: "operator to kick has too much stake".to_string(),
+                    })
+                }),
+            )
+        });
+        let approver = RemoteChurnApprover::new(format!("http://{}", server.addr()));
+        let request = ChurnApprovalRequest {
+            operator_address: Address::from([0x11; 20]),
+            operator_id: FixedBytes::from([0x22; 32]),
+            quorum_numbers: Bytes::from_static(&[0]),
+            operator_kick_params: vec![],
+        };
+
+        let err = approver
+            .churn_approver_signature(&request)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            AvsRegistryError::ChurnApproverRejected { status: 400, message }
+                if message == "operator to kick has too much stake"
+        ));
+    }
+}
@@ -0,0 +1,48 @@
+use alloy_primitives::FixedBytes;
+use eigen_client_avsregistry::error::AvsRegistryError;
+use thiserror::Error;
+
+/// Error returned by the churn approver
+#[derive(Debug, Error)]
+pub enum ChurnerError {
+    /// Invalid churn approver private key
+    #[error("Invalid churn approver private key")]
+    InvalidPrivateKey,
+
+    /// Salt already used by a registration, or already issued by the churn approver
+    #[error("Churn approver salt {0} already used")]
+    SaltAlreadyUsed(FixedBytes<32>),
+
+    /// Operators to kick don't match the quorum numbers
+    #[error("Expected one operator to kick per quorum number")]
+    InvalidOperatorsToKick,
+
+    /// System time is before the unix epoch, so the expiry of the approval can't be computed
+    #[error("System time is before the unix epoch")]
+    SystemTime,
+
+    /// Failed to sign the churn approval
+    #[error("Failed to sign churn approval")]
+    Signature,
+
+    /// AvsRegistry errors
+    #[error("AvsRegistryError: {0}")]
+    AvsRegistry(#[from] AvsRegistryError),
+}
+
+impl ChurnerError {
+    /// Whether the error comes from an invalid request rather than from the approver
+    pub fn is_invalid_request(&self) -> bool {
+        matches!(
+            self,
+            ChurnerError::SaltAlreadyUsed(_)
+                | ChurnerError::InvalidOperatorsToKick
+                | ChurnerError::AvsRegistry(
+                    AvsRegistryError::InvalidOperatorToKick(_)
+                        | AvsRegistryError::InsufficientStakeForChurn(_)
+                        | AvsRegistryError::OperatorToKickStakeTooHigh(_)
+                        | AvsRegistryError::NoOperatorToKick(_)
+                )
+        )
+    }
+}
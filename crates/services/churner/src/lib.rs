@@ -0,0 +1,12 @@
+//! Churn approver service, approving the registrations of operators in full quorums.
+
+#![doc(
+    html_logo_url = "https://github.com/Layr-Labs/eigensdk-rs/assets/91280922/bd13caec-3c00-4afc-839a-b83d2890beb5",
+    issue_tracker_base_url = "https://github.com/Layr-Labs/eigensdk-rs/issues/"
+)]
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+
+pub mod churner;
+pub mod client;
+pub mod error;
+pub mod server;
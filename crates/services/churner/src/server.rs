@@ -0,0 +1,116 @@
+use crate::churner::{ChurnApprover, ChurnErrorResponse, ChurnRequest};
+use ntex::web::{self, App, HttpResponse, HttpServer};
+use std::sync::Arc;
+
+/// Route the churn requests are posted to
+pub const CHURN_ROUTE: &str = "/eigen/churner/churn";
+
+/// Approve a churn request. Responds with the churn approver signature, or a bad request when
+/// the request doesn't meet the churn ratios.
+pub async fn churn(
+    approver: web::types::State<Arc<ChurnApprover>>,
+    request: web::types::Json<ChurnRequest>,
+) -> HttpResponse {
+    match approver.approve(&request).await {
+        Ok(response) => HttpResponse::Ok().json(&response),
+        Err(err) => {
+            let body = ChurnErrorResponse {
+                error: err.to_string(),
+            };
+            if err.is_invalid_request() {
+                HttpResponse::BadRequest().json(&body)
+            } else {
+                HttpResponse::InternalServerError().json(&body)
+            }
+        }
+    }
+}
+
+/// Create the HTTP server of the churn approver
+///
+/// # Arguments
+///
+/// * `approver` - The churn approver
+/// * `ip_port_addr` - The address the server listens on
+pub fn create_server(
+    approver: ChurnApprover,
+    ip_port_addr: String,
+) -> std::io::Result<ntex::server::Server> {
+    let approver = Arc::new(approver);
+    let server = HttpServer::new(move || {
+        App::new()
+            .state(approver.clone())
+            .route(CHURN_ROUTE, web::post().to(churn))
+    })
+    .bind(ip_port_addr.clone())?
+    .run();
+    Ok(server)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::churner::{ChurnResponse, OperatorToKick};
+    use alloy_primitives::{Address, FixedBytes};
+    use eigen_client_avsregistry::reader::AvsRegistryChainReader;
+    use eigen_logging::get_test_logger;
+    use eigen_testing_utils::anvil_constants::{
+        get_operator_state_retriever_address, get_registry_coordinator_address, ANVIL_HTTP_URL,
+    };
+    use ntex::{http, web::test};
+
+    #[tokio::test]
+    async fn test_churn_handler() {
+        let avs_registry_reader = AvsRegistryChainReader::new(
+            get_test_logger(),
+            get_registry_coordinator_address().await,
+            get_operator_state_retriever_address().await,
+            ANVIL_HTTP_URL.to_string(),
+        )
+        .await
+        .unwrap();
+        let approver = ChurnApprover::new(
+            get_test_logger(),
+            avs_registry_reader,
+            "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+            3600,
+        )
+        .unwrap();
+        let app = test::init_service(
+            App::new()
+                .state(Arc::new(approver))
+                .route(CHURN_ROUTE, web::post().to(churn)),
+        )
+        .await;
+
+        let request = ChurnRequest {
+            operator_address: Address::from([0x11; 20]),
+            operator_id: FixedBytes::from([0x22; 32]),
+            quorum_numbers: vec![0],
+            operators_to_kick: vec![OperatorToKick {
+                quorum_number: 0,
+                operator: Address::ZERO,
+            }],
+            salt: FixedBytes::from([0x33; 32]),
+        };
+        let req = test::TestRequest::post()
+            .uri(CHURN_ROUTE)
+            .set_json(&request)
+            .to_request();
+        let resp = app.call(req).await.unwrap();
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        let response: ChurnResponse = serde_json::from_slice(&test::read_body(resp).await).unwrap();
+        assert_eq!(response.salt, request.salt);
+
+        let invalid_request = ChurnRequest {
+            operators_to_kick: vec![],
+            ..request
+        };
+        let req = test::TestRequest::post()
+            .uri(CHURN_ROUTE)
+            .set_json(&invalid_request)
+            .to_request();
+        let resp = app.call(req).await.unwrap();
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,617 @@
+use crate::error::EjectorError;
+use crate::participation::{SigningRate, SigningRateTracker, TaskParticipation};
+use crate::rate_limit::EjectionRateLimiter;
+use alloy_primitives::{Address, Bytes, FixedBytes, TxHash};
+use eigen_client_avsregistry::{admin::AvsRegistryAdminWriter, reader::AvsRegistryReader};
+use eigen_logging::logger::SharedLogger;
+use eigen_services_avsregistry::AvsRegistryService;
+use eigen_types::operator::{QuorumNum, QuorumThreshold};
+use std::collections::{BTreeMap, HashSet};
+use tokio::sync::mpsc::UnboundedReceiver;
+use tokio::time::{Duration, Instant};
+
+/// Configuration of the [`EjectorService`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EjectorConfig {
+    /// Number of the last tasks of a quorum the signing rates of its operators are computed over
+    pub window_size: usize,
+    /// Number of tasks an operator must have been expected to sign before it can be ejected
+    pub min_tasks: usize,
+    /// Signing rate below which an operator is ejected
+    pub sla: QuorumThreshold,
+    /// Maximum number of operators ejected from a quorum within `rate_limit_window`
+    pub max_ejections_per_quorum: usize,
+    pub rate_limit_window: Duration,
+}
+
+impl Default for EjectorConfig {
+    fn default() -> Self {
+        Self {
+            window_size: 100,
+            min_tasks: 100,
+            sla: QuorumThreshold::from_basis_points(8000),
+            max_ejections_per_quorum: 1,
+            rate_limit_window: Duration::from_secs(24 * 60 * 60),
+        }
+    }
+}
+
+/// What the [`EjectorService`] does with the operators below the SLA
+#[derive(Debug)]
+pub enum EjectionMode {
+    /// Only report the operators that would be ejected
+    DryRun,
+    /// Eject the operators. The signer of the admin writer must be the ejector of the registry
+    /// coordinator.
+    Eject(Box<AvsRegistryAdminWriter>),
+}
+
+/// Ejection of an operator from some of its quorums
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ejection {
+    pub operator_id: FixedBytes<32>,
+    pub operator: Address,
+    /// Quorums the operator is ejected from, along with its signing rate in each of them
+    pub signing_rates: BTreeMap<QuorumNum, SigningRate>,
+    /// Hash of the eject transaction, `None` in dry run
+    pub tx_hash: Option<TxHash>,
+}
+
+impl Ejection {
+    /// Get the quorums the operator is ejected from
+    pub fn quorum_numbers(&self) -> Vec<QuorumNum> {
+        self.signing_rates.keys().copied().collect()
+    }
+}
+
+/// Ejector service, tracking the signing rates of the operators from the aggregated responses of
+/// their tasks and ejecting the ones below the SLA
+#[derive(Debug)]
+pub struct EjectorService<A, R> {
+    logger: SharedLogger,
+    avs_registry_service: A,
+    avs_registry_reader: R,
+    mode: EjectionMode,
+    config: EjectorConfig,
+    tracker: SigningRateTracker,
+    rate_limiter: EjectionRateLimiter,
+}
+
+impl<A: AvsRegistryService + Sync, R: AvsRegistryReader + Sync> EjectorService<A, R> {
+    /// Create a new ejector service
+    ///
+    /// # Arguments
+    ///
+    /// * `logger` - The logger
+    /// * `avs_registry_service` - The AVS registry service, used to get the operators of the tasks
+    /// * `avs_registry_reader` - The AVS registry reader, used to get the addresses of the
+    ///   operators to eject
+    /// * `mode` - Whether to eject the operators or only report them
+    /// * `config` - The ejector configuration
+    pub fn new(
+        logger: SharedLogger,
+        avs_registry_service: A,
+        avs_registry_reader: R,
+        mode: EjectionMode,
+        config: EjectorConfig,
+    ) -> Self {
+        Self {
+            logger,
+            avs_registry_service,
+            avs_registry_reader,
+            mode,
+            config,
+            tracker: SigningRateTracker::new(config.window_size),
+            rate_limiter: EjectionRateLimiter::new(
+                config.max_ejections_per_quorum,
+                config.rate_limit_window,
+            ),
+        }
+    }
+
+    /// Get the signing rate tracker
+    pub fn tracker(&self) -> &SigningRateTracker {
+        &self.tracker
+    }
+
+    /// Get the ejection rate limiter
+    pub fn rate_limiter(&self) -> &EjectionRateLimiter {
+        &self.rate_limiter
+    }
+
+    /// Process the tasks received from `task_receiver` until the channel is closed
+    ///
+    /// # Arguments
+    ///
+    /// * `task_receiver` - The receiver of the participations of the aggregated tasks
+    pub async fn start(mut self, mut task_receiver: UnboundedReceiver<TaskParticipation>) {
+        while let Some(task) = task_receiver.recv().await {
+            if let Err(err) = self.process_task(task).await {
+                self.logger.error(
+                    &format!("Failed to process task: {}", err),
+                    "eigen-services-ejector.start",
+                );
+            }
+        }
+    }
+
+    /// Record the participation of the operators in a task, and eject the operators of its
+    /// quorums that fall below the SLA
+    ///
+    /// Operators are ejected lowest signing rate first, up to the rate limit of each quorum.
+    /// An ejection only counts towards the rate limit once its transaction is mined
+    /// successfully; one that fails is logged and retried on the next task.
+    ///
+    /// # Arguments
+    ///
+    /// * `task` - The participation of the operators in the task
+    ///
+    /// # Returns
+    ///
+    /// The ejections made, or that would be made in dry run
+    pub async fn process_task(
+        &mut self,
+        task: TaskParticipation,
+    ) -> Result<Vec<Ejection>, EjectorError> {
+        let operators = self
+            .avs_registry_service
+            .get_operators_avs_state_at_block(task.task_created_block, &task.quorum_numbers)
+            .await?;
+        for quorum_number in &task.quorum_numbers {
+            let quorum_operator_ids: HashSet<FixedBytes<32>> = operators
+                .iter()
+                .filter(|(_, state)| state.stake_per_quorum.contains_key(quorum_number))
+                .map(|(operator_id, _)| *operator_id)
+                .collect();
+            self.tracker.record(
+                *quorum_number,
+                &quorum_operator_ids,
+                &task.non_signer_operator_ids,
+            );
+        }
+
+        let now = Instant::now();
+        let mut operators_to_eject: BTreeMap<FixedBytes<32>, BTreeMap<QuorumNum, SigningRate>> =
+            BTreeMap::new();
+        for quorum_number in &task.quorum_numbers {
+            let mut below_sla: Vec<(FixedBytes<32>, SigningRate)> = self
+                .tracker
+                .signing_rates(*quorum_number)
+                .into_iter()
+                .filter(|(_, rate)| {
+                    rate.tasks >= self.config.min_tasks && !rate.meets(self.config.sla)
+                })
+                .collect();
+            if below_sla.is_empty() {
+                continue;
+            }
+            below_sla.sort_by(|(id_a, rate_a), (id_b, rate_b)| {
+                rate_a.cmp_rate(rate_b).then(id_a.cmp(id_b))
+            });
+
+            let remaining = self.rate_limiter.remaining(*quorum_number, now);
+            if below_sla.len() > remaining {
+                self.logger.warn(
+                    &format!(
+                        "Ejection rate limit reached for quorum {}, {} operators below the SLA are not ejected",
+                        quorum_number,
+                        below_sla.len() - remaining
+                    ),
+                    "eigen-services-ejector.process_task",
+                );
+            }
+            for (operator_id, rate) in below_sla.into_iter().take(remaining) {
+                operators_to_eject
+                    .entry(operator_id)
+                    .or_default()
+                    .insert(*quorum_number, rate);
+            }
+        }
+
+        let mut ejections = vec![];
+        for (operator_id, signing_rates) in operators_to_eject {
+            match self.eject(operator_id, signing_rates).await {
+                Ok(ejection) => {
+                    for quorum_number in ejection.signing_rates.keys() {
+                        self.rate_limiter.record(*quorum_number, now);
+                        self.tracker.remove(*quorum_number, &operator_id);
+                    }
+                    ejections.push(ejection);
+                }
+                Err(err) => self.logger.error(
+                    &format!("Failed to eject operator {}: {}", operator_id, err),
+                    "eigen-services-ejector.process_task",
+                ),
+            }
+        }
+        Ok(ejections)
+    }
+
+    /// Eject an operator from quorums, or only report it in dry run
+    async fn eject(
+        &self,
+        operator_id: FixedBytes<32>,
+        signing_rates: BTreeMap<QuorumNum, SigningRate>,
+    ) -> Result<Ejection, EjectorError> {
+        let operator = self
+            .avs_registry_reader
+            .get_operator_from_id(operator_id.into())
+            .await?;
+        let quorum_numbers: Vec<QuorumNum> = signing_rates.keys().copied().collect();
+
+        let tx_hash = match &self.mode {
+            EjectionMode::DryRun => {
+                self.logger.info(
+                    &format!(
+                        "Dry run, would eject operator {} ({}) from quorums {:?}, signing rates: {:?}",
+                        operator, operator_id, quorum_numbers, signing_rates
+                    ),
+                    "eigen-services-ejector.eject",
+                );
+                None
+            }
+            EjectionMode::Eject(admin_writer) => {
+                // the admin writer only returns once the transaction is mined successfully
+                let tx_hash = admin_writer
+                    .eject_operator(operator, Bytes::from(quorum_numbers.clone()))
+                    .await?
+                    .tx_hash();
+                self.logger.info(
+                    &format!(
+                        "Ejected operator {} ({}) from quorums {:?}, signing rates: {:?}, tx_hash: {}",
+                        operator, operator_id, quorum_numbers, signing_rates, tx_hash
+                    ),
+                    "eigen-services-ejector.eject",
+                );
+                Some(tx_hash)
+            }
+        };
+        Ok(Ejection {
+            operator_id,
+            operator,
+            signing_rates,
+            tx_hash,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_network::TransactionBuilder;
+    use alloy_primitives::U256;
+    use alloy_rpc_types::{TransactionReceipt, TransactionRequest};
+    use alloy_signer_local::PrivateKeySigner;
+    use async_trait::async_trait;
+    use eigen_chainio_txmanager::{
+        simple_tx_manager::{SimpleTxManager, TxManagerError},
+        tx_manager::TxManager,
+    };
+    use eigen_client_avsregistry::{
+        error::AvsRegistryError, reader::AvsRegistryChainReader, writer::AvsRegistryChainWriter,
+    };
+    use eigen_crypto_bls::BlsKeyPair;
+    use eigen_logging::get_test_logger;
+    use eigen_services_avsregistry::fake_avs_registry_service::FakeAvsRegistryService;
+    use eigen_testing_utils::anvil_constants::{
+        get_operator_state_retriever_address, get_registry_coordinator_address, ANVIL_HTTP_URL,
+    };
+    use eigen_types::test::TestOperator;
+    use eigen_utils::binding::OperatorStateRetriever;
+    use std::collections::HashMap;
+    use std::str::FromStr;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    const PRIVATE_KEY_1: &str =
+        "13710126902690889134622698668747132666439281256983827313388062967626731803599";
+    const PRIVATE_KEY_2: &str =
+        "14610126902690889134622698668747132666439281256983827313388062967626731803500";
+    const PRIVATE_KEY_3: &str =
+        "15610126902690889134622698668747132666439281256983827313388062967626731803501";
+    const BLOCK_NUMBER: u32 = 1;
+    /// Deployer of the anvil contracts, owner of the registry coordinator
+    const OWNER_PRIVATE_KEY: &str =
+        "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+    /// Anvil account 8, registered as an operator with EigenLayer in the anvil state
+    const OPERATOR_PRIVATE_KEY: &str =
+        "dbda1821b80551c9d65939329250298aa3472ba22feea921c0cf5d620ea67b97";
+    const OPERATOR_BLS_PRIVATE_KEY: &str =
+        "16610126902690889134622698668747132666439281256983827313388062967626731803502";
+
+    /// Tx manager sending the transactions with too little gas, so that they revert once mined
+    #[derive(Debug)]
+    struct OutOfGasTxManager(SimpleTxManager);
+
+    #[async_trait]
+    impl TxManager for OutOfGasTxManager {
+        async fn send_tx(
+            &self,
+            tx: &mut TransactionRequest,
+        ) -> Result<TransactionReceipt, TxManagerError> {
+            tx.set_gas_limit(30_000);
+            self.0.send_tx(tx).await
+        }
+
+        fn sender(&self) -> Address {
+            TxManager::sender(&self.0)
+        }
+    }
+
+    /// Reader deriving the address of an operator from its id, failing the first `failures`
+    /// lookups
+    #[derive(Debug)]
+    struct FakeOperatorAddressReader {
+        failures: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl AvsRegistryReader for FakeOperatorAddressReader {
+        async fn get_operators_stake_in_quorums_at_block(
+            &self,
+            _block_number: u32,
+            _quorum_numbers: Bytes,
+        ) -> Result<Vec<Vec<OperatorStateRetriever::Operator>>, AvsRegistryError> {
+            unimplemented!()
+        }
+
+        async fn get_check_signatures_indices(
+            &self,
+            _reference_block_number: u32,
+            _quorum_numbers: Vec<u8>,
+            _non_signer_operator_ids: Vec<FixedBytes<32>>,
+        ) -> Result<OperatorStateRetriever::CheckSignaturesIndices, AvsRegistryError> {
+            unimplemented!()
+        }
+
+        async fn get_operator_from_id(
+            &self,
+            operator_id: [u8; 32],
+        ) -> Result<Address, AvsRegistryError> {
+            if self
+                .failures
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |failures| {
+                    failures.checked_sub(1)
+                })
+                .is_ok()
+            {
+                return Err(AvsRegistryError::GetOperatorFromId);
+            }
+            Ok(Address::from_slice(&operator_id[12..]))
+        }
+    }
+
+    fn operator_id(id: u64) -> FixedBytes<32> {
+        U256::from(id).into()
+    }
+
+    fn build_ejector(
+        config: EjectorConfig,
+        failures: usize,
+    ) -> EjectorService<FakeAvsRegistryService, FakeOperatorAddressReader> {
+        let test_operators = [PRIVATE_KEY_1, PRIVATE_KEY_2, PRIVATE_KEY_3]
+            .iter()
+            .enumerate()
+            .map(|(i, private_key)| TestOperator {
+                operator_id: operator_id(i as u64 + 1),
+                stake_per_quorum: HashMap::from([(0u8, U256::from(100)), (1u8, U256::from(200))]),
+                bls_keypair: BlsKeyPair::new(private_key.to_string()).unwrap(),
+            })
+            .collect();
+        EjectorService::new(
+            get_test_logger(),
+            FakeAvsRegistryService::new(BLOCK_NUMBER.into(), test_operators),
+            FakeOperatorAddressReader {
+                failures: AtomicUsize::new(failures),
+            },
+            EjectionMode::DryRun,
+            config,
+        )
+    }
+
+    fn task(task_index: u32, non_signers: &[u64]) -> TaskParticipation {
+        TaskParticipation {
+            task_index,
+            task_created_block: BLOCK_NUMBER,
+            quorum_numbers: vec![0, 1],
+            non_signer_operator_ids: non_signers.iter().map(|id| operator_id(*id)).collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_process_task_dry_run_ejects_below_sla() {
+        let mut ejector = build_ejector(
+            EjectorConfig {
+                window_size: 4,
+                min_tasks: 4,
+                sla: QuorumThreshold::from_basis_points(7500),
+                max_ejections_per_quorum: 10,
+                rate_limit_window: Duration::from_secs(60),
+            },
+            0,
+        );
+
+        // operator 2 misses half the tasks, operator 3 a quarter of them
+        for (task_index, non_signers) in [&[2, 3][..], &[2], &[]].into_iter().enumerate() {
+            let ejections = ejector
+                .process_task(task(task_index as u32, non_signers))
+                .await
+                .unwrap();
+            assert!(ejections.is_empty());
+        }
+        let ejections = ejector.process_task(task(3, &[])).await.unwrap();
+
+        assert_eq!(ejections.len(), 1);
+        let ejection = &ejections[0];
+        assert_eq!(ejection.operator_id, operator_id(2));
+        assert_eq!(
+            ejection.operator,
+            Address::from_slice(&operator_id(2)[12..])
+        );
+        assert_eq!(ejection.quorum_numbers(), vec![0, 1]);
+        assert_eq!(
+            ejection.signing_rates[&0],
+            SigningRate {
+                signed: 2,
+                tasks: 4
+            }
+        );
+        assert_eq!(ejection.tx_hash, None);
+        // the ejected operator is no longer tracked
+        assert_eq!(ejector.tracker().signing_rate(0, &operator_id(2)), None);
+    }
+
+    #[tokio::test]
+    async fn test_process_task_rate_limited_per_quorum() {
+        let mut ejector = build_ejector(
+            EjectorConfig {
+                window_size: 2,
+                min_tasks: 2,
+                sla: QuorumThreshold::from_basis_points(10000),
+                max_ejections_per_quorum: 1,
+                rate_limit_window: Duration::from_secs(60),
+            },
+            0,
+        );
+
+        ejector.process_task(task(0, &[1, 2])).await.unwrap();
+        let ejections = ejector.process_task(task(1, &[2])).await.unwrap();
+
+        // operator 2 has the lowest signing rate, operator 1 is left for the next window
+        assert_eq!(ejections.len(), 1);
+        assert_eq!(ejections[0].operator_id, operator_id(2));
+        assert!(ejector
+            .process_task(task(2, &[1]))
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_process_task_failed_ejection_not_recorded() {
+        let mut ejector = build_ejector(
+            EjectorConfig {
+                window_size: 2,
+                min_tasks: 2,
+                sla: QuorumThreshold::from_basis_points(10000),
+                max_ejections_per_quorum: 1,
+                rate_limit_window: Duration::from_secs(60),
+            },
+            1,
+        );
+
+        ejector.process_task(task(0, &[2])).await.unwrap();
+        let ejections = ejector.process_task(task(1, &[])).await.unwrap();
+
+        // the failed ejection neither uses up the rate limit nor stops tracking the operator
+        assert!(ejections.is_empty());
+        assert_eq!(
+            ejector.tracker().signing_rate(0, &operator_id(2)),
+            Some(SigningRate {
+                signed: 1,
+                tasks: 2
+            })
+        );
+        let ejections = ejector.process_task(task(2, &[2])).await.unwrap();
+        assert_eq!(ejections.len(), 1);
+        assert_eq!(ejections[0].operator_id, operator_id(2));
+    }
+
+    #[tokio::test]
+    async fn test_process_task_reverted_ejection_not_recorded() {
+        let registry_coordinator = get_registry_coordinator_address().await;
+        let operator_state_retriever = get_operator_state_retriever_address().await;
+        let operator = PrivateKeySigner::from_str(OPERATOR_PRIVATE_KEY)
+            .unwrap()
+            .address();
+        let avs_reader = AvsRegistryChainReader::new(
+            get_test_logger(),
+            registry_coordinator,
+            operator_state_retriever,
+            ANVIL_HTTP_URL.to_string(),
+        )
+        .await
+        .unwrap();
+        if !avs_reader.is_operator_registered(operator).await.unwrap() {
+            AvsRegistryChainWriter::build_avs_registry_chain_writer(
+                get_test_logger(),
+                ANVIL_HTTP_URL.to_string(),
+                OPERATOR_PRIVATE_KEY.to_string(),
+                registry_coordinator,
+                operator_state_retriever,
+            )
+            .await
+            .unwrap()
+            .register_operator_in_quorum_with_avs_registry_coordinator(
+                BlsKeyPair::new(OPERATOR_BLS_PRIVATE_KEY.to_string()).unwrap(),
+                FixedBytes::from([0x40; 32]),
+                U256::MAX,
+                Bytes::from([0]),
+                "".into(),
+            )
+            .await
+            .unwrap();
+        }
+
+        let admin_writer = AvsRegistryAdminWriter::build_avs_registry_admin_writer(
+            get_test_logger(),
+            ANVIL_HTTP_URL.to_string(),
+            OWNER_PRIVATE_KEY.to_string(),
+            registry_coordinator,
+        )
+        .await
+        .unwrap();
+        admin_writer
+            .set_ejector(admin_writer.signer_address())
+            .await
+            .unwrap();
+        let tx_manager =
+            SimpleTxManager::new(get_test_logger(), 1.0, OWNER_PRIVATE_KEY, ANVIL_HTTP_URL)
+                .unwrap();
+        let admin_writer = admin_writer.with_tx_manager(Arc::new(OutOfGasTxManager(tx_manager)));
+
+        // the fake reader maps the operator id back to the operator address
+        let operator_id = operator.into_word();
+        let test_operator = TestOperator {
+            operator_id,
+            stake_per_quorum: HashMap::from([(0u8, U256::from(100))]),
+            bls_keypair: BlsKeyPair::new(OPERATOR_BLS_PRIVATE_KEY.to_string()).unwrap(),
+        };
+        let mut ejector = EjectorService::new(
+            get_test_logger(),
+            FakeAvsRegistryService::new(BLOCK_NUMBER.into(), vec![test_operator]),
+            FakeOperatorAddressReader {
+                failures: AtomicUsize::new(0),
+            },
+            EjectionMode::Eject(Box::new(admin_writer)),
+            EjectorConfig {
+                window_size: 2,
+                min_tasks: 2,
+                sla: QuorumThreshold::from_basis_points(10000),
+                max_ejections_per_quorum: 1,
+                rate_limit_window: Duration::from_secs(60),
+            },
+        );
+        let missed_task = |task_index| TaskParticipation {
+            task_index,
+            task_created_block: BLOCK_NUMBER,
+            quorum_numbers: vec![0],
+            non_signer_operator_ids: HashSet::from([operator_id]),
+        };
+
+        ejector.process_task(missed_task(0)).await.unwrap();
+        let ejections = ejector.process_task(missed_task(1)).await.unwrap();
+
+        // the eject transaction reverted, so neither the rate limiter nor the tracker changed
+        assert!(ejections.is_empty());
+        assert_eq!(ejector.rate_limiter().ejection_count(0), 0);
+        assert_eq!(
+            ejector.tracker().signing_rate(0, &operator_id),
+            Some(SigningRate {
+                signed: 0,
+                tasks: 2
+            })
+        );
+        assert!(avs_reader.is_operator_registered(operator).await.unwrap());
+    }
+}
@@ -0,0 +1,14 @@
+use eigen_client_avsregistry::error::AvsRegistryError;
+use thiserror::Error;
+
+/// Error returned by the ejector
+#[derive(Debug, Error)]
+pub enum EjectorError {
+    /// Failed to compute the operator id of a non signer
+    #[error("Failed to compute operator id from g1 public key")]
+    OperatorId,
+
+    /// AvsRegistry errors
+    #[error("AvsRegistryError: {0}")]
+    AvsRegistry(#[from] AvsRegistryError),
+}
@@ -0,0 +1,228 @@
+use crate::error::EjectorError;
+use alloy_primitives::{FixedBytes, U256};
+use eigen_services_blsaggregation::bls_agg::BlsAggregationServiceResponse;
+use eigen_types::{
+    avs::TaskIndex,
+    operator::{operator_id_from_g1_pub_key, QuorumNum, QuorumThreshold},
+};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Signers of a task, as reported by its aggregated response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskParticipation {
+    pub task_index: TaskIndex,
+    /// Block the task was created at, its operators are the ones registered at this block
+    pub task_created_block: u32,
+    pub quorum_numbers: Vec<QuorumNum>,
+    /// Operators of the task that didn't sign its aggregated response
+    pub non_signer_operator_ids: HashSet<FixedBytes<32>>,
+}
+
+impl TaskParticipation {
+    /// Build the participation of a task from its aggregated response
+    ///
+    /// # Arguments
+    ///
+    /// * `response` - The aggregated response of the task
+    /// * `task_created_block` - The block the task was created at
+    /// * `quorum_numbers` - The quorums of the task
+    pub fn from_aggregation_response(
+        response: &BlsAggregationServiceResponse,
+        task_created_block: u32,
+        quorum_numbers: Vec<QuorumNum>,
+    ) -> Result<Self, EjectorError> {
+        let non_signer_operator_ids = response
+            .non_signers_pub_keys_g1
+            .iter()
+            .map(|pub_key| {
+                operator_id_from_g1_pub_key(pub_key.clone())
+                    .map(FixedBytes::from)
+                    .map_err(|_| EjectorError::OperatorId)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            task_index: response.task_index,
+            task_created_block,
+            quorum_numbers,
+            non_signer_operator_ids,
+        })
+    }
+}
+
+/// Number of tasks an operator signed among the ones it was expected to sign
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SigningRate {
+    pub signed: usize,
+    pub tasks: usize,
+}
+
+impl SigningRate {
+    /// Whether the signing rate meets `sla`
+    pub fn meets(&self, sla: QuorumThreshold) -> bool {
+        sla.is_met(U256::from(self.signed), U256::from(self.tasks))
+    }
+
+    /// Compare two signing rates, without rounding
+    pub fn cmp_rate(&self, other: &SigningRate) -> Ordering {
+        (self.signed * other.tasks).cmp(&(other.signed * self.tasks))
+    }
+}
+
+/// Tracks whether the operators of each quorum signed the last `window_size` tasks of the quorum
+#[derive(Debug, Clone)]
+pub struct SigningRateTracker {
+    window_size: usize,
+    /// Whether each operator signed the last tasks of the quorum, oldest first
+    history: HashMap<QuorumNum, HashMap<FixedBytes<32>, VecDeque<bool>>>,
+}
+
+impl SigningRateTracker {
+    /// Create a new tracker
+    ///
+    /// # Arguments
+    ///
+    /// * `window_size` - The number of tasks the signing rates are computed over, at least 1
+    pub fn new(window_size: usize) -> Self {
+        Self {
+            window_size: window_size.max(1),
+            history: HashMap::new(),
+        }
+    }
+
+    /// Record a task of a quorum
+    ///
+    /// The operators that left the quorum are no longer tracked.
+    ///
+    /// # Arguments
+    ///
+    /// * `quorum_number` - The quorum of the task
+    /// * `operator_ids` - The operators registered in the quorum when the task was created
+    /// * `non_signer_operator_ids` - The operators that didn't sign the task
+    pub fn record(
+        &mut self,
+        quorum_number: QuorumNum,
+        operator_ids: &HashSet<FixedBytes<32>>,
+        non_signer_operator_ids: &HashSet<FixedBytes<32>>,
+    ) {
+        let quorum_history = self.history.entry(quorum_number).or_default();
+        quorum_history.retain(|operator_id, _| operator_ids.contains(operator_id));
+        for operator_id in operator_ids {
+            let operator_history = quorum_history.entry(*operator_id).or_default();
+            operator_history.push_back(!non_signer_operator_ids.contains(operator_id));
+            if operator_history.len() > self.window_size {
+                operator_history.pop_front();
+            }
+        }
+    }
+
+    /// Get the signing rate of an operator in a quorum
+    pub fn signing_rate(
+        &self,
+        quorum_number: QuorumNum,
+        operator_id: &FixedBytes<32>,
+    ) -> Option<SigningRate> {
+        self.history
+            .get(&quorum_number)?
+            .get(operator_id)
+            .map(signing_rate)
+    }
+
+    /// Get the signing rates of the operators of a quorum
+    pub fn signing_rates(&self, quorum_number: QuorumNum) -> HashMap<FixedBytes<32>, SigningRate> {
+        self.history
+            .get(&quorum_number)
+            .map(|quorum_history| {
+                quorum_history
+                    .iter()
+                    .map(|(operator_id, history)| (*operator_id, signing_rate(history)))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Stop tracking an operator in a quorum, e.g. once it has been ejected from it
+    pub fn remove(&mut self, quorum_number: QuorumNum, operator_id: &FixedBytes<32>) {
+        if let Some(quorum_history) = self.history.get_mut(&quorum_number) {
+            quorum_history.remove(operator_id);
+        }
+    }
+}
+
+fn signing_rate(history: &VecDeque<bool>) -> SigningRate {
+    SigningRate {
+        signed: history.iter().filter(|signed| **signed).count(),
+        tasks: history.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn operator_ids(ids: &[u8]) -> HashSet<FixedBytes<32>> {
+        ids.iter().map(|id| FixedBytes::from([*id; 32])).collect()
+    }
+
+    #[test]
+    fn test_signing_rate_sliding_window() {
+        let mut tracker = SigningRateTracker::new(3);
+        let operators = operator_ids(&[1, 2]);
+        let operator_1 = FixedBytes::from([1; 32]);
+        let operator_2 = FixedBytes::from([2; 32]);
+
+        tracker.record(0, &operators, &operator_ids(&[1]));
+        tracker.record(0, &operators, &operator_ids(&[1]));
+        tracker.record(0, &operators, &operator_ids(&[]));
+        assert_eq!(
+            tracker.signing_rate(0, &operator_1),
+            Some(SigningRate {
+                signed: 1,
+                tasks: 3
+            })
+        );
+
+        // the oldest task leaves the window
+        tracker.record(0, &operators, &operator_ids(&[2]));
+        assert_eq!(
+            tracker.signing_rate(0, &operator_1),
+            Some(SigningRate {
+                signed: 2,
+                tasks: 3
+            })
+        );
+        assert_eq!(
+            tracker.signing_rate(0, &operator_2),
+            Some(SigningRate {
+                signed: 2,
+                tasks: 3
+            })
+        );
+        assert_eq!(tracker.signing_rate(1, &operator_1), None);
+    }
+
+    #[test]
+    fn test_signing_rate_tracker_drops_operators_leaving_quorum() {
+        let mut tracker = SigningRateTracker::new(10);
+        let operator_1 = FixedBytes::from([1; 32]);
+
+        tracker.record(0, &operator_ids(&[1, 2]), &operator_ids(&[1]));
+        tracker.record(0, &operator_ids(&[2]), &operator_ids(&[]));
+        assert_eq!(tracker.signing_rate(0, &operator_1), None);
+        assert_eq!(tracker.signing_rates(0).len(), 1);
+
+        tracker.remove(0, &FixedBytes::from([2; 32]));
+        assert!(tracker.signing_rates(0).is_empty());
+    }
+
+    #[test]
+    fn test_signing_rate_meets_sla() {
+        let sla = QuorumThreshold::from_basis_points(8000);
+        let rate = |signed, tasks| SigningRate { signed, tasks };
+
+        assert!(rate(8, 10).meets(sla));
+        assert!(!rate(7, 10).meets(sla));
+        assert_eq!(rate(1, 3).cmp_rate(&rate(2, 6)), Ordering::Equal);
+        assert_eq!(rate(1, 3).cmp_rate(&rate(1, 2)), Ordering::Less);
+    }
+}
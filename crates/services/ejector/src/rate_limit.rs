@@ -0,0 +1,87 @@
+use eigen_types::operator::QuorumNum;
+use std::collections::{HashMap, VecDeque};
+use tokio::time::{Duration, Instant};
+
+/// Limits the number of operators ejected from each quorum within a time window
+#[derive(Debug, Clone)]
+pub struct EjectionRateLimiter {
+    max_ejections: usize,
+    window: Duration,
+    /// Times of the ejections from each quorum within the window, oldest first
+    ejections: HashMap<QuorumNum, VecDeque<Instant>>,
+}
+
+impl EjectionRateLimiter {
+    /// Create a new rate limiter
+    ///
+    /// # Arguments
+    ///
+    /// * `max_ejections` - The maximum number of operators ejected from a quorum within `window`
+    /// * `window` - The time window
+    pub fn new(max_ejections: usize, window: Duration) -> Self {
+        Self {
+            max_ejections,
+            window,
+            ejections: HashMap::new(),
+        }
+    }
+
+    /// Get the number of operators that can still be ejected from a quorum at `now`
+    pub fn remaining(&mut self, quorum_number: QuorumNum, now: Instant) -> usize {
+        let ejections = self.ejections.entry(quorum_number).or_default();
+        while ejections
+            .front()
+            .is_some_and(|ejected_at| now.saturating_duration_since(*ejected_at) >= self.window)
+        {
+            ejections.pop_front();
+        }
+        self.max_ejections.saturating_sub(ejections.len())
+    }
+
+    /// Get the number of ejections from a quorum recorded within the window, as of the last
+    /// call to [`Self::remaining`]
+    pub fn ejection_count(&self, quorum_number: QuorumNum) -> usize {
+        self.ejections.get(&quorum_number).map_or(0, VecDeque::len)
+    }
+
+    /// Record the ejection of an operator from a quorum at `now`
+    pub fn record(&mut self, quorum_number: QuorumNum, now: Instant) {
+        self.ejections
+            .entry(quorum_number)
+            .or_default()
+            .push_back(now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limiter_per_quorum() {
+        let mut rate_limiter = EjectionRateLimiter::new(2, Duration::from_secs(60));
+        let start = Instant::now();
+
+        assert_eq!(rate_limiter.remaining(0, start), 2);
+        rate_limiter.record(0, start);
+        rate_limiter.record(0, start + Duration::from_secs(30));
+        assert_eq!(
+            rate_limiter.remaining(0, start + Duration::from_secs(30)),
+            0
+        );
+        assert_eq!(
+            rate_limiter.remaining(1, start + Duration::from_secs(30)),
+            2
+        );
+
+        // the first ejection leaves the window
+        assert_eq!(
+            rate_limiter.remaining(0, start + Duration::from_secs(60)),
+            1
+        );
+        assert_eq!(
+            rate_limiter.remaining(0, start + Duration::from_secs(90)),
+            2
+        );
+    }
+}
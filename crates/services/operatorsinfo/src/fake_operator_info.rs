@@ -1,22 +1,37 @@
-use alloy_primitives::Address;
+use alloy_primitives::{Address, FixedBytes};
 use async_trait::async_trait;
 use eigen_crypto_bls::BlsKeyPair;
-use eigen_types::operator::{OperatorInfo, OperatorPubKeys};
+use eigen_types::operator::{operator_id_from_g1_pub_key, OperatorInfo, OperatorPubKeys};
 
-use crate::{operator_info::OperatorInfoService, operatorsinfo_inmemory::OperatorInfoServiceError};
+use crate::{
+    operator_info::{OperatorInfoService, OperatorRegistrationStatus},
+    operatorsinfo_inmemory::OperatorInfoServiceError,
+};
 
 pub struct FakeOperatorInfoService {
     pub pubkeys: OperatorInfo,
+    pub operator_address: Address,
 }
 
 impl FakeOperatorInfoService {
-    pub fn new(pubkeys: BlsKeyPair) -> Self {
+    pub fn new(pubkeys: BlsKeyPair, operator_address: Address) -> Self {
         Self {
             pubkeys: OperatorInfo {
                 pub_keys: Some(OperatorPubKeys::from(pubkeys)),
+                socket: None,
             },
+            operator_address,
         }
     }
+
+    /// Id of the fake operator, derived from its G1 public key
+    fn operator_id(&self) -> Option<FixedBytes<32>> {
+        self.pubkeys
+            .pub_keys
+            .as_ref()
+            .and_then(|keys| operator_id_from_g1_pub_key(keys.g1_pub_key.clone()).ok())
+            .map(FixedBytes)
+    }
 }
 
 #[async_trait]
@@ -27,4 +42,46 @@ impl OperatorInfoService for FakeOperatorInfoService {
     ) -> Result<Option<OperatorPubKeys>, OperatorInfoServiceError> {
         Ok(self.pubkeys.pub_keys.clone())
     }
+
+    async fn get_operator_info_by_id(
+        &self,
+        _operator_id: FixedBytes<32>,
+    ) -> Result<Option<OperatorPubKeys>, OperatorInfoServiceError> {
+        Ok(self.pubkeys.pub_keys.clone())
+    }
+
+    async fn get_operator_id(
+        &self,
+        _address: Address,
+    ) -> Result<Option<FixedBytes<32>>, OperatorInfoServiceError> {
+        Ok(self.operator_id())
+    }
+
+    async fn get_operator_address(
+        &self,
+        operator_id: FixedBytes<32>,
+    ) -> Result<Option<Address>, OperatorInfoServiceError> {
+        Ok((self.operator_id() == Some(operator_id)).then_some(self.operator_address))
+    }
+
+    async fn get_operator_socket(
+        &self,
+        _address: Address,
+    ) -> Result<Option<String>, OperatorInfoServiceError> {
+        Ok(self.pubkeys.socket.clone())
+    }
+
+    async fn get_operator_socket_by_id(
+        &self,
+        _operator_id: FixedBytes<32>,
+    ) -> Result<Option<String>, OperatorInfoServiceError> {
+        Ok(self.pubkeys.socket.clone())
+    }
+
+    async fn get_operator_registration_status(
+        &self,
+        _address: Address,
+    ) -> Result<Option<OperatorRegistrationStatus>, OperatorInfoServiceError> {
+        Ok(None)
+    }
 }
@@ -7,4 +7,6 @@
 pub mod fake_operator_info;
 pub mod operator_info;
 pub mod operatorsinfo_inmemory;
+pub mod reorg;
+pub mod storage;
 pub struct OperatorPubKeysService {}
@@ -1,16 +1,30 @@
-use alloy_primitives::Address;
+use alloy_primitives::{Address, FixedBytes};
 use async_trait::async_trait;
 use eigen_types::operator::OperatorPubKeys;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 
 use crate::operatorsinfo_inmemory::OperatorInfoServiceError;
 
+/// Registration status of an operator, as observed from the registry events
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OperatorRegistrationStatus {
+    /// The operator is registered in `quorums`, last updated at `block_number`
+    Registered {
+        quorums: BTreeSet<u8>,
+        block_number: u64,
+    },
+    /// The operator left all its quorums at `block_number`
+    Deregistered { block_number: u64 },
+}
+
 #[async_trait]
 pub trait OperatorInfoService {
-    /// Get the operator info from the operator id
+    /// Get the operator info from the operator address
     ///
     /// # Arguments
     ///
-    /// * `operator_id` - The operator id
+    /// * `address` - The operator address
     ///
     /// # Returns
     ///
@@ -19,4 +33,89 @@ pub trait OperatorInfoService {
         &self,
         address: Address,
     ) -> Result<Option<OperatorPubKeys>, OperatorInfoServiceError>;
+
+    /// Get the operator info from the operator id
+    ///
+    /// # Arguments
+    ///
+    /// * `operator_id` - The operator id
+    ///
+    /// # Returns
+    ///
+    /// The operator public keys
+    async fn get_operator_info_by_id(
+        &self,
+        operator_id: FixedBytes<32>,
+    ) -> Result<Option<OperatorPubKeys>, OperatorInfoServiceError>;
+
+    /// Get the operator id of an operator address
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The operator address
+    ///
+    /// # Returns
+    ///
+    /// The operator id
+    async fn get_operator_id(
+        &self,
+        address: Address,
+    ) -> Result<Option<FixedBytes<32>>, OperatorInfoServiceError>;
+
+    /// Get the operator address of an operator id
+    ///
+    /// # Arguments
+    ///
+    /// * `operator_id` - The operator id
+    ///
+    /// # Returns
+    ///
+    /// The operator address
+    async fn get_operator_address(
+        &self,
+        operator_id: FixedBytes<32>,
+    ) -> Result<Option<Address>, OperatorInfoServiceError>;
+
+    /// Get the socket of an operator from the operator address
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The operator address
+    ///
+    /// # Returns
+    ///
+    /// The latest socket registered by the operator
+    async fn get_operator_socket(
+        &self,
+        address: Address,
+    ) -> Result<Option<String>, OperatorInfoServiceError>;
+
+    /// Get the socket of an operator from the operator id
+    ///
+    /// # Arguments
+    ///
+    /// * `operator_id` - The operator id
+    ///
+    /// # Returns
+    ///
+    /// The latest socket registered by the operator
+    async fn get_operator_socket_by_id(
+        &self,
+        operator_id: FixedBytes<32>,
+    ) -> Result<Option<String>, OperatorInfoServiceError>;
+
+    /// Get the registration status of an operator
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The operator address
+    ///
+    /// # Returns
+    ///
+    /// The registration status of the operator, or `None` if the operator was never seen
+    /// registering.
+    async fn get_operator_registration_status(
+        &self,
+        address: Address,
+    ) -> Result<Option<OperatorRegistrationStatus>, OperatorInfoServiceError>;
 }
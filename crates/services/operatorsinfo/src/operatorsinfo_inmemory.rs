@@ -1,44 +1,169 @@
-use alloy_primitives::{Address, FixedBytes};
+use alloy_primitives::{Address, Bytes, FixedBytes};
 use alloy_provider::Provider;
-use alloy_rpc_types::Filter;
+use alloy_rpc_types::{Filter, Log};
+use alloy_transport::Transport;
 use anyhow::Result;
 use async_trait::async_trait;
+use eigen_chainio_utils::log_scanner::LogScanner;
 use eigen_client_avsregistry::reader::AvsRegistryChainReader;
 use eigen_crypto_bls::{
     alloy_registry_g1_point_to_g1_affine, alloy_registry_g2_point_to_g2_affine, BlsG1Point,
     BlsG2Point,
 };
 use eigen_logging::logger::SharedLogger;
+use eigen_nodeapi::ServiceStatus;
 use eigen_types::operator::{operator_id_from_g1_pub_key, OperatorPubKeys};
 use eigen_utils::{
-    binding::BLSApkRegistry::{self, G1Point, G2Point},
-    get_ws_provider, NEW_PUBKEY_REGISTRATION_EVENT,
+    binding::{
+        BLSApkRegistry::{self, G1Point, G2Point},
+        RegistryCoordinator,
+    },
+    get_provider, get_ws_provider, NEW_PUBKEY_REGISTRATION_EVENT, OPERATOR_ADDED_TO_QUORUMS_EVENT,
+    OPERATOR_DEREGISTERED_EVENT, OPERATOR_REMOVED_FROM_QUORUMS_EVENT, OPERATOR_SOCKET_UPDATE_EVENT,
 };
 use futures_util::StreamExt;
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::Arc,
+    time::Duration,
+};
 use thiserror::Error;
 use tokio::sync::{
     mpsc::{self, UnboundedSender},
     oneshot::{self, Sender},
-    RwLock,
+    watch, RwLock,
 };
 use tokio_util::sync::CancellationToken;
 
-use crate::operator_info::OperatorInfoService;
+use crate::{
+    operator_info::{OperatorInfoService, OperatorRegistrationStatus},
+    reorg::BlockTracker,
+    storage::{OperatorInfoSnapshot, OperatorInfoStorage},
+};
+
+/// Delay before the first websocket reconnection attempt
+const DEFAULT_MIN_RECONNECT_BACKOFF: Duration = Duration::from_secs(1);
+/// Maximum delay between two websocket reconnection attempts
+const DEFAULT_MAX_RECONNECT_BACKOFF: Duration = Duration::from_secs(60);
+/// Number of confirmed blocks between two checkpoints of the state to the storage
+const DEFAULT_CHECKPOINT_INTERVAL: u64 = 100;
+
+/// How the service receives the operator events from the chain
+#[derive(Debug, Clone)]
+pub enum OperatorInfoServiceTransport {
+    /// Subscribe to the events through the given websocket url
+    WebSocket(String),
+    /// Poll the events with `eth_getLogs` through the given http url
+    HttpPolling {
+        url: String,
+        /// Delay between two polls
+        poll_interval: Duration,
+        /// Number of blocks on top of a block before its events are ingested
+        confirmations: u64,
+    },
+}
 
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct OperatorInfoServiceInMemory {
     logger: SharedLogger,
     pub avs_registry_reader: AvsRegistryChainReader,
-    ws: String,
+    transport: OperatorInfoServiceTransport,
     pub_keys: UnboundedSender<OperatorsInfoMessage>,
+    service_status: Arc<watch::Sender<ServiceStatus>>,
+    min_reconnect_backoff: Duration,
+    max_reconnect_backoff: Duration,
+    storage: Option<Arc<dyn OperatorInfoStorage>>,
+    checkpoint_interval: u64,
+    confirmations: u64,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 struct OperatorState {
     operator_info_data: Arc<RwLock<HashMap<Address, OperatorPubKeys>>>,
     operator_addr_to_id: Arc<RwLock<HashMap<Address, FixedBytes<32>>>>,
+    operator_id_to_addr: Arc<RwLock<HashMap<FixedBytes<32>, Address>>>,
+    operator_sockets: Arc<RwLock<HashMap<FixedBytes<32>, String>>>,
+    operator_registration_status: Arc<RwLock<HashMap<Address, OperatorRegistrationStatus>>>,
+    /// Values replaced by the changes of each block, to revert them on reorgs
+    journal: Arc<RwLock<Vec<(u64, JournalEntry)>>>,
+}
+
+/// Value of a state entry before a change
+#[derive(Debug)]
+enum JournalEntry {
+    PubKeys {
+        address: Address,
+        pub_keys: Option<Box<OperatorPubKeys>>,
+        operator_id: Option<FixedBytes<32>>,
+    },
+    Socket {
+        operator_id: FixedBytes<32>,
+        socket: Option<String>,
+    },
+    RegistrationStatus {
+        address: Address,
+        status: Option<OperatorRegistrationStatus>,
+    },
+}
+
+impl OperatorState {
+    /// Undo the changes of the blocks from `block_number` onwards, newest first
+    async fn revert(&self, block_number: u64) {
+        let mut journal = self.journal.write().await;
+        let mut data = self.operator_info_data.write().await;
+        let mut id_map = self.operator_addr_to_id.write().await;
+        let mut addr_map = self.operator_id_to_addr.write().await;
+        let mut sockets = self.operator_sockets.write().await;
+        let mut status_map = self.operator_registration_status.write().await;
+
+        while journal
+            .last()
+            .is_some_and(|(block, _)| *block >= block_number)
+        {
+            let Some((_, entry)) = journal.pop() else {
+                break;
+            };
+            match entry {
+                JournalEntry::PubKeys {
+                    address,
+                    pub_keys,
+                    operator_id,
+                } => {
+                    if let Some(current_id) = id_map.remove(&address) {
+                        addr_map.remove(&current_id);
+                    }
+                    data.remove(&address);
+                    if let Some(pub_keys) = pub_keys {
+                        data.insert(address, *pub_keys);
+                    }
+                    if let Some(operator_id) = operator_id {
+                        id_map.insert(address, operator_id);
+                        addr_map.insert(operator_id, address);
+                    }
+                }
+                JournalEntry::Socket {
+                    operator_id,
+                    socket,
+                } => match socket {
+                    Some(socket) => {
+                        sockets.insert(operator_id, socket);
+                    }
+                    None => {
+                        sockets.remove(&operator_id);
+                    }
+                },
+                JournalEntry::RegistrationStatus { address, status } => match status {
+                    Some(status) => {
+                        status_map.insert(address, status);
+                    }
+                    None => {
+                        status_map.remove(&address);
+                    }
+                },
+            }
+        }
+    }
 }
 
 #[derive(Error, Debug, Clone, PartialEq, Eq)]
@@ -57,13 +182,50 @@ pub enum OperatorInfoServiceError {
 
 #[derive(Debug)]
 enum OperatorsInfoMessage {
-    InsertOperatorInfo(Address, Box<OperatorPubKeys>),
-    #[allow(dead_code)]
-    Remove(Address),
+    /// Operator registered its pub keys at the given block
+    InsertOperatorInfo(Address, Box<OperatorPubKeys>, u64),
+    /// Operator joined the given quorums at the given block
+    AddToQuorums(Address, Bytes, u64),
+    /// Operator left the given quorums at the given block
+    RemoveFromQuorums(Address, Bytes, u64),
+    /// Operator deregistered from the AVS at the given block
+    Deregister(Address, u64),
     Get(
         Address,
         Sender<Result<Option<OperatorPubKeys>, OperatorInfoServiceError>>,
     ),
+    GetById(
+        FixedBytes<32>,
+        Sender<Result<Option<OperatorPubKeys>, OperatorInfoServiceError>>,
+    ),
+    GetOperatorId(
+        Address,
+        Sender<Result<Option<FixedBytes<32>>, OperatorInfoServiceError>>,
+    ),
+    GetOperatorAddress(
+        FixedBytes<32>,
+        Sender<Result<Option<Address>, OperatorInfoServiceError>>,
+    ),
+    /// Operator updated its socket at the given block
+    UpdateSocket(FixedBytes<32>, String, u64),
+    GetSocket(
+        Address,
+        Sender<Result<Option<String>, OperatorInfoServiceError>>,
+    ),
+    GetSocketById(
+        FixedBytes<32>,
+        Sender<Result<Option<String>, OperatorInfoServiceError>>,
+    ),
+    GetRegistrationStatus(
+        Address,
+        Sender<Result<Option<OperatorRegistrationStatus>, OperatorInfoServiceError>>,
+    ),
+    Snapshot(Sender<OperatorInfoSnapshot>),
+    Restore(Box<OperatorInfoSnapshot>),
+    /// Revert the changes of the blocks from the given block onwards
+    Revert(u64),
+    /// Make the changes of the blocks before the given block final
+    Prune(u64),
 }
 
 #[async_trait]
@@ -82,42 +244,362 @@ impl OperatorInfoService for OperatorInfoServiceInMemory {
             .await
             .map_err(|_| OperatorInfoServiceError::ChannelClosed)?
     }
+
+    async fn get_operator_info_by_id(
+        &self,
+        operator_id: FixedBytes<32>,
+    ) -> Result<Option<OperatorPubKeys>, OperatorInfoServiceError> {
+        let (responder_tx, responder_rx) = oneshot::channel();
+
+        let _ = self
+            .pub_keys
+            .send(OperatorsInfoMessage::GetById(operator_id, responder_tx))
+            .map_err(|_| OperatorInfoServiceError::ChannelClosed)?;
+        responder_rx
+            .await
+            .map_err(|_| OperatorInfoServiceError::ChannelClosed)?
+    }
+
+    async fn get_operator_id(
+        &self,
+        address: Address,
+    ) -> Result<Option<FixedBytes<32>>, OperatorInfoServiceError> {
+        let (responder_tx, responder_rx) = oneshot::channel();
+
+        let _ = self
+            .pub_keys
+            .send(OperatorsInfoMessage::GetOperatorId(address, responder_tx))
+            .map_err(|_| OperatorInfoServiceError::ChannelClosed)?;
+        responder_rx
+            .await
+            .map_err(|_| OperatorInfoServiceError::ChannelClosed)?
+    }
+
+    async fn get_operator_address(
+        &self,
+        operator_id: FixedBytes<32>,
+    ) -> Result<Option<Address>, OperatorInfoServiceError> {
+        let (responder_tx, responder_rx) = oneshot::channel();
+
+        let _ = self
+            .pub_keys
+            .send(OperatorsInfoMessage::GetOperatorAddress(
+                operator_id,
+                responder_tx,
+            ))
+            .map_err(|_| OperatorInfoServiceError::ChannelClosed)?;
+        responder_rx
+            .await
+            .map_err(|_| OperatorInfoServiceError::ChannelClosed)?
+    }
+
+    async fn get_operator_socket(
+        &self,
+        address: Address,
+    ) -> Result<Option<String>, OperatorInfoServiceError> {
+        let (responder_tx, responder_rx) = oneshot::channel();
+
+        let _ = self
+            .pub_keys
+            .send(OperatorsInfoMessage::GetSocket(address, responder_tx))
+            .map_err(|_| OperatorInfoServiceError::ChannelClosed)?;
+        responder_rx
+            .await
+            .map_err(|_| OperatorInfoServiceError::ChannelClosed)?
+    }
+
+    async fn get_operator_socket_by_id(
+        &self,
+        operator_id: FixedBytes<32>,
+    ) -> Result<Option<String>, OperatorInfoServiceError> {
+        let (responder_tx, responder_rx) = oneshot::channel();
+
+        let _ = self
+            .pub_keys
+            .send(OperatorsInfoMessage::GetSocketById(
+                operator_id,
+                responder_tx,
+            ))
+            .map_err(|_| OperatorInfoServiceError::ChannelClosed)?;
+        responder_rx
+            .await
+            .map_err(|_| OperatorInfoServiceError::ChannelClosed)?
+    }
+
+    async fn get_operator_registration_status(
+        &self,
+        address: Address,
+    ) -> Result<Option<OperatorRegistrationStatus>, OperatorInfoServiceError> {
+        let (responder_tx, responder_rx) = oneshot::channel();
+
+        let _ = self
+            .pub_keys
+            .send(OperatorsInfoMessage::GetRegistrationStatus(
+                address,
+                responder_tx,
+            ))
+            .map_err(|_| OperatorInfoServiceError::ChannelClosed)?;
+        responder_rx
+            .await
+            .map_err(|_| OperatorInfoServiceError::ChannelClosed)?
+    }
 }
 
 impl OperatorInfoServiceInMemory {
+    /// Create a new service receiving the operator events through a websocket subscription
+    ///
+    /// # Arguments
+    ///
+    /// * `logger` - The logger
+    /// * `avs_registry_chain_reader` - The AVS registry reader
+    /// * `web_socket` - The websocket url of the rpc provider
     pub async fn new(
         logger: SharedLogger,
         avs_registry_chain_reader: AvsRegistryChainReader,
         web_socket: String,
+    ) -> Self {
+        Self::new_with_transport(
+            logger,
+            avs_registry_chain_reader,
+            OperatorInfoServiceTransport::WebSocket(web_socket),
+        )
+        .await
+    }
+
+    /// Create a new service receiving the operator events through the given transport
+    ///
+    /// # Arguments
+    ///
+    /// * `logger` - The logger
+    /// * `avs_registry_chain_reader` - The AVS registry reader
+    /// * `transport` - The transport used to receive the operator events
+    pub async fn new_with_transport(
+        logger: SharedLogger,
+        avs_registry_chain_reader: AvsRegistryChainReader,
+        transport: OperatorInfoServiceTransport,
     ) -> Self {
         let (pubkeys_tx, mut pubkeys_rx) = mpsc::unbounded_channel();
-        let operator_state = OperatorState {
-            operator_info_data: Arc::new(RwLock::new(HashMap::new())),
-            operator_addr_to_id: Arc::new(RwLock::new(HashMap::new())),
-        };
+        let operator_state = OperatorState::default();
 
         tokio::spawn({
             let operator_state = operator_state.clone();
             async move {
                 while let Some(cmd) = pubkeys_rx.recv().await {
                     match cmd {
-                        OperatorsInfoMessage::InsertOperatorInfo(addr, keys) => {
+                        OperatorsInfoMessage::InsertOperatorInfo(addr, keys, block_number) => {
                             let mut data = operator_state.operator_info_data.write().await;
-                            data.insert(addr, *keys.clone());
+                            let previous_keys = data.insert(addr, *keys.clone());
                             let operator_id = operator_id_from_g1_pub_key(keys.g1_pub_key)
                                 .expect("Failed to get operator id from g1 pub key");
                             let mut id_map = operator_state.operator_addr_to_id.write().await;
-                            id_map.insert(addr, alloy_primitives::FixedBytes(operator_id));
+                            let previous_id = id_map.insert(addr, FixedBytes(operator_id));
+                            operator_state.journal.write().await.push((
+                                block_number,
+                                JournalEntry::PubKeys {
+                                    address: addr,
+                                    pub_keys: previous_keys.map(Box::new),
+                                    operator_id: previous_id,
+                                },
+                            ));
+                            let mut addr_map = operator_state.operator_id_to_addr.write().await;
+                            addr_map.insert(FixedBytes(operator_id), addr);
                         }
-                        OperatorsInfoMessage::Remove(addr) => {
-                            let mut data = operator_state.operator_info_data.write().await;
-                            data.remove(&addr);
+                        OperatorsInfoMessage::AddToQuorums(addr, quorum_numbers, block_number) => {
+                            let mut status_map =
+                                operator_state.operator_registration_status.write().await;
+                            let previous_status = status_map.remove(&addr);
+                            operator_state.journal.write().await.push((
+                                block_number,
+                                JournalEntry::RegistrationStatus {
+                                    address: addr,
+                                    status: previous_status.clone(),
+                                },
+                            ));
+                            let mut quorums = match previous_status {
+                                Some(OperatorRegistrationStatus::Registered {
+                                    quorums, ..
+                                }) => quorums,
+                                _ => BTreeSet::new(),
+                            };
+                            quorums.extend(quorum_numbers.iter());
+                            status_map.insert(
+                                addr,
+                                OperatorRegistrationStatus::Registered {
+                                    quorums,
+                                    block_number,
+                                },
+                            );
+                        }
+                        OperatorsInfoMessage::RemoveFromQuorums(
+                            addr,
+                            quorum_numbers,
+                            block_number,
+                        ) => {
+                            let mut status_map =
+                                operator_state.operator_registration_status.write().await;
+                            let previous_status = status_map.remove(&addr);
+                            operator_state.journal.write().await.push((
+                                block_number,
+                                JournalEntry::RegistrationStatus {
+                                    address: addr,
+                                    status: previous_status.clone(),
+                                },
+                            ));
+                            let mut quorums = match previous_status {
+                                Some(OperatorRegistrationStatus::Registered {
+                                    quorums, ..
+                                }) => quorums,
+                                _ => BTreeSet::new(),
+                            };
+                            for quorum_number in quorum_numbers.iter() {
+                                quorums.remove(quorum_number);
+                            }
+                            let status = if quorums.is_empty() {
+                                OperatorRegistrationStatus::Deregistered { block_number }
+                            } else {
+                                OperatorRegistrationStatus::Registered {
+                                    quorums,
+                                    block_number,
+                                }
+                            };
+                            status_map.insert(addr, status);
+                        }
+                        OperatorsInfoMessage::Deregister(addr, block_number) => {
+                            // pub keys stay registered in the BLSApkRegistry, so they are kept
+                            // for lookups at past blocks and for a later re-registration. The
+                            // socket is set again by the coordinator on re-registration.
+                            let mut status_map =
+                                operator_state.operator_registration_status.write().await;
+                            let previous_status = status_map.insert(
+                                addr,
+                                OperatorRegistrationStatus::Deregistered { block_number },
+                            );
+                            let mut journal = operator_state.journal.write().await;
+                            journal.push((
+                                block_number,
+                                JournalEntry::RegistrationStatus {
+                                    address: addr,
+                                    status: previous_status,
+                                },
+                            ));
+                            let id_map = operator_state.operator_addr_to_id.read().await;
+                            if let Some(operator_id) = id_map.get(&addr) {
+                                let mut sockets = operator_state.operator_sockets.write().await;
+                                journal.push((
+                                    block_number,
+                                    JournalEntry::Socket {
+                                        operator_id: *operator_id,
+                                        socket: sockets.remove(operator_id),
+                                    },
+                                ));
+                            }
                         }
                         OperatorsInfoMessage::Get(addr, responder) => {
                             let data = operator_state.operator_info_data.read().await;
                             let result = data.get(&addr).cloned();
                             responder.send(Ok(result)).expect("Failed to send response");
                         }
+                        OperatorsInfoMessage::GetById(operator_id, responder) => {
+                            let addr_map = operator_state.operator_id_to_addr.read().await;
+                            let data = operator_state.operator_info_data.read().await;
+                            let result = addr_map
+                                .get(&operator_id)
+                                .and_then(|addr| data.get(addr))
+                                .cloned();
+                            responder.send(Ok(result)).expect("Failed to send response");
+                        }
+                        OperatorsInfoMessage::GetOperatorId(addr, responder) => {
+                            let id_map = operator_state.operator_addr_to_id.read().await;
+                            let result = id_map.get(&addr).cloned();
+                            responder.send(Ok(result)).expect("Failed to send response");
+                        }
+                        OperatorsInfoMessage::GetOperatorAddress(operator_id, responder) => {
+                            let addr_map = operator_state.operator_id_to_addr.read().await;
+                            let result = addr_map.get(&operator_id).cloned();
+                            responder.send(Ok(result)).expect("Failed to send response");
+                        }
+                        OperatorsInfoMessage::UpdateSocket(operator_id, socket, block_number) => {
+                            let mut sockets = operator_state.operator_sockets.write().await;
+                            let previous_socket = sockets.insert(operator_id, socket);
+                            operator_state.journal.write().await.push((
+                                block_number,
+                                JournalEntry::Socket {
+                                    operator_id,
+                                    socket: previous_socket,
+                                },
+                            ));
+                        }
+                        OperatorsInfoMessage::GetSocket(addr, responder) => {
+                            let id_map = operator_state.operator_addr_to_id.read().await;
+                            let sockets = operator_state.operator_sockets.read().await;
+                            let result = id_map
+                                .get(&addr)
+                                .and_then(|operator_id| sockets.get(operator_id))
+                                .cloned();
+                            responder.send(Ok(result)).expect("Failed to send response");
+                        }
+                        OperatorsInfoMessage::GetSocketById(operator_id, responder) => {
+                            let sockets = operator_state.operator_sockets.read().await;
+                            let result = sockets.get(&operator_id).cloned();
+                            responder.send(Ok(result)).expect("Failed to send response");
+                        }
+                        OperatorsInfoMessage::GetRegistrationStatus(addr, responder) => {
+                            let status_map =
+                                operator_state.operator_registration_status.read().await;
+                            let result = status_map.get(&addr).cloned();
+                            responder.send(Ok(result)).expect("Failed to send response");
+                        }
+                        OperatorsInfoMessage::Snapshot(responder) => {
+                            let snapshot = OperatorInfoSnapshot {
+                                last_processed_block: 0,
+                                operator_info_data: operator_state
+                                    .operator_info_data
+                                    .read()
+                                    .await
+                                    .clone(),
+                                operator_addr_to_id: operator_state
+                                    .operator_addr_to_id
+                                    .read()
+                                    .await
+                                    .clone(),
+                                operator_id_to_addr: operator_state
+                                    .operator_id_to_addr
+                                    .read()
+                                    .await
+                                    .clone(),
+                                operator_sockets: operator_state
+                                    .operator_sockets
+                                    .read()
+                                    .await
+                                    .clone(),
+                                operator_registration_status: operator_state
+                                    .operator_registration_status
+                                    .read()
+                                    .await
+                                    .clone(),
+                            };
+                            responder.send(snapshot).expect("Failed to send response");
+                        }
+                        OperatorsInfoMessage::Restore(snapshot) => {
+                            *operator_state.operator_info_data.write().await =
+                                snapshot.operator_info_data;
+                            *operator_state.operator_addr_to_id.write().await =
+                                snapshot.operator_addr_to_id;
+                            *operator_state.operator_id_to_addr.write().await =
+                                snapshot.operator_id_to_addr;
+                            *operator_state.operator_sockets.write().await =
+                                snapshot.operator_sockets;
+                            *operator_state.operator_registration_status.write().await =
+                                snapshot.operator_registration_status;
+                            operator_state.journal.write().await.clear();
+                        }
+                        OperatorsInfoMessage::Revert(block_number) => {
+                            operator_state.revert(block_number).await;
+                        }
+                        OperatorsInfoMessage::Prune(block_number) => {
+                            let mut journal = operator_state.journal.write().await;
+                            journal.retain(|(block, _)| *block >= block_number);
+                        }
                     }
                 }
             }
@@ -126,119 +608,644 @@ impl OperatorInfoServiceInMemory {
         Self {
             logger,
             avs_registry_reader: avs_registry_chain_reader,
-            ws: web_socket,
+            transport,
             pub_keys: pubkeys_tx,
+            service_status: Arc::new(watch::channel(ServiceStatus::Initializing).0),
+            min_reconnect_backoff: DEFAULT_MIN_RECONNECT_BACKOFF,
+            max_reconnect_backoff: DEFAULT_MAX_RECONNECT_BACKOFF,
+            storage: None,
+            checkpoint_interval: DEFAULT_CHECKPOINT_INTERVAL,
+            confirmations: 0,
         }
     }
 
+    /// Start the service. It runs until `cancellation_token` is cancelled.
+    ///
+    /// With a websocket transport, the state is first filled with the events between
+    /// `start_block` and `end_block` (0 means the current block), then kept up to date through
+    /// subscriptions. If the connection fails or a subscription ends, the service reconnects with
+    /// an exponential backoff and backfills the blocks missed while disconnected.
+    ///
+    /// With an http polling transport, the events are polled from `start_block` up to the latest
+    /// confirmed block, so `end_block` is not used.
+    ///
+    /// The connection state is reported through [`Self::subscribe_service_status`].
     pub async fn start_service(
         &self,
         cancellation_token: &CancellationToken,
         start_block: u64,
         end_block: u64,
     ) -> Result<()> {
-        // Query past operator registrations
-        self.query_past_registered_operator_events_and_fill_db(start_block, end_block)
-            .await
-            .unwrap();
-        let provider = get_ws_provider(&self.ws).await.unwrap();
-        let current_block_number = provider.get_block_number().await.unwrap();
+        match &self.transport {
+            OperatorInfoServiceTransport::WebSocket(ws) => {
+                self.subscribe_events(cancellation_token, ws, start_block, end_block)
+                    .await
+            }
+            OperatorInfoServiceTransport::HttpPolling {
+                url,
+                poll_interval,
+                confirmations,
+            } => {
+                self.poll_events(
+                    cancellation_token,
+                    url,
+                    start_block,
+                    *poll_interval,
+                    *confirmations,
+                )
+                .await
+            }
+        }
+    }
+
+    /// Keep the state up to date through websocket subscriptions, reconnecting on failures
+    async fn subscribe_events(
+        &self,
+        cancellation_token: &CancellationToken,
+        ws: &str,
+        start_block: u64,
+        end_block: u64,
+    ) -> Result<()> {
+        self.service_status
+            .send_replace(ServiceStatus::Initializing);
+        let mut last_synced_block = self.restore_from_storage().await;
+        let mut block_tracker = BlockTracker::new(self.confirmations);
+        let mut backoff = self.min_reconnect_backoff;
+
+        loop {
+            let result = self
+                .sync_and_subscribe(
+                    cancellation_token,
+                    ws,
+                    start_block,
+                    end_block,
+                    &mut last_synced_block,
+                    &mut block_tracker,
+                    &mut backoff,
+                )
+                .await;
+            if let Err(err) = result {
+                self.service_status.send_replace(ServiceStatus::Down);
+                self.logger.warn(
+                    &format!(
+                        "Operators info subscription failed: {:?}, reconnecting in {:?}",
+                        err, backoff
+                    ),
+                    "eigen-services-operatorsinfo.start_service",
+                );
+                tokio::select! {
+                    _ = cancellation_token.cancelled() => {},
+                    _ = tokio::time::sleep(backoff) => {
+                        backoff = std::cmp::min(backoff * 2, self.max_reconnect_backoff);
+                        continue;
+                    },
+                }
+            }
+            break;
+        }
+
+        if let Some(block_number) = last_synced_block {
+            self.checkpoint(block_number).await;
+        }
+        self.logger.info(
+            "Cancellation signal received, stopping the stream.",
+            "eigen-services-operatorsinfo.start_service",
+        );
+        self.service_status.send_replace(ServiceStatus::Down);
+        Ok(())
+    }
 
-        // Subscribe to new pubkey registration events
-        let filter = Filter::new()
-            .event(NEW_PUBKEY_REGISTRATION_EVENT)
-            .from_block(current_block_number);
+    /// Connect to the websocket, subscribe to the operator events and backfill the blocks
+    /// not synced yet. Returns `Ok` once cancelled, or an error when the connection is lost.
+    #[allow(clippy::too_many_arguments)]
+    async fn sync_and_subscribe(
+        &self,
+        cancellation_token: &CancellationToken,
+        ws: &str,
+        start_block: u64,
+        end_block: u64,
+        last_synced_block: &mut Option<u64>,
+        block_tracker: &mut BlockTracker,
+        backoff: &mut Duration,
+    ) -> Result<()> {
+        let provider = get_ws_provider(ws)
+            .await
+            .map_err(|_| OperatorInfoServiceError::WebSocketConnectionError)?;
 
-        let subcription_new_operator_registration_stream =
-            provider.subscribe_logs(&filter).await.unwrap();
-        let mut stream = subcription_new_operator_registration_stream
+        // Subscribe before backfilling, so no event is missed in between
+        let mut stream = provider
+            .subscribe_logs(&self.events_filter())
+            .await
+            .map_err(|_| OperatorInfoServiceError::WebSocketConnectionError)?
+            .into_stream()
+            .fuse();
+        let mut heads = provider
+            .subscribe_blocks()
+            .await
+            .map_err(|_| OperatorInfoServiceError::WebSocketConnectionError)?
             .into_stream()
             .fuse();
 
-        let pub_keys = self.pub_keys.clone();
-        let self_clone = self.clone();
+        let mut head = provider.get_block_number().await?;
+        // Events of the last synced block are replayed, as the connection may have been lost
+        // before all of them were received
+        let (from_block, to_block) = match *last_synced_block {
+            None => (start_block, end_block),
+            Some(block) => (block, head),
+        };
+        self.fill_db_from_logs(
+            &provider,
+            self.events_filter(),
+            from_block,
+            to_block,
+            block_tracker,
+        )
+        .await?;
+        self.commit_blocks(head, block_tracker, last_synced_block)
+            .await;
+
+        self.service_status.send_replace(ServiceStatus::Up);
+        *backoff = self.min_reconnect_backoff;
 
         loop {
             tokio::select! {
-                _ = cancellation_token.cancelled() => {
-                    self.logger.info("Cancellation signal received, stopping the stream.", "eigen-services-operatorsinfo.start_service");
-                    break;
-                },
+                _ = cancellation_token.cancelled() => return Ok(()),
                 log = stream.next() => {
-                    match log {
-                        Some(log) => {
-
-                            let data = log
-                                .log_decode::<BLSApkRegistry::NewPubkeyRegistration>()
-                                .ok();
-
-                            if let Some(new_pub_key_event) = data {
-                                let event_data = new_pub_key_event.data();
-                                let operator_pub_key = OperatorPubKeys {
-                                    g1_pub_key: BlsG1Point::new(alloy_registry_g1_point_to_g1_affine(
-                                        G1Point {
-                                            X: event_data.pubkeyG1.X,
-                                            Y: event_data.pubkeyG1.Y,
-                                        },
-                                    )),
-                                    g2_pub_key: BlsG2Point::new(alloy_registry_g2_point_to_g2_affine(
-                                        G2Point {
-                                            X: event_data.pubkeyG2.X,
-                                            Y: event_data.pubkeyG2.Y,
-                                        },
-                                    )),
-                                };
-                                // Send message
-
-                                self_clone.logger.debug(
-                                    &format!(
-                                        "New pub key found  operator_address : {:?} , operator_pub_keys : {:?}",
-                                        event_data.operator, operator_pub_key
-                                    ),
-                                    "eigen-services-operatorsinfo.start_service",
-                                );
-
-                                let _ = pub_keys.send(OperatorsInfoMessage::InsertOperatorInfo(
-                                    event_data.operator,
-                                    Box::new(operator_pub_key),
-                                ));
-                            }
-                        },
-                        None => {
-                            break;
+                    let Some(log) = log else {
+                        return Err(OperatorInfoServiceError::WebSocketConnectionError.into());
+                    };
+                    head = head.max(log.block_number.unwrap_or_default());
+                    self.track_log(log, block_tracker);
+                },
+                block = heads.next() => {
+                    let Some(block) = block else {
+                        return Err(OperatorInfoServiceError::WebSocketConnectionError.into());
+                    };
+                    if let (Some(number), Some(hash)) = (block.header.number, block.header.hash) {
+                        if let Some(reorged_block) = block_tracker.track_head(number, hash) {
+                            self.revert_blocks(reorged_block);
                         }
+                        // a reorg may lower the head
+                        head = number;
                     }
                 },
             }
+            self.commit_blocks(head, block_tracker, last_synced_block)
+                .await;
         }
-
-        Ok(())
     }
 
-    pub async fn query_past_registered_operator_events_and_fill_db(
+    /// Keep the state up to date by polling the logs of the confirmed blocks over http
+    async fn poll_events(
         &self,
+        cancellation_token: &CancellationToken,
+        url: &str,
         start_block: u64,
-        end_block: u64,
+        poll_interval: Duration,
+        confirmations: u64,
     ) -> Result<()> {
-        let (operator_address, operator_pub_keys) = self
-            .avs_registry_reader
-            .query_existing_registered_operator_pub_keys(start_block, end_block, self.ws.clone())
-            .await?;
-        for (i, address) in operator_address.iter().enumerate() {
-            let message = OperatorsInfoMessage::InsertOperatorInfo(
-                *address,
-                Box::new(operator_pub_keys[i].clone()),
+        self.service_status
+            .send_replace(ServiceStatus::Initializing);
+        let provider = get_provider(url);
+        let mut next_block = match self.restore_from_storage().await {
+            Some(last_processed_block) => last_processed_block + 1,
+            None => start_block,
+        };
+        // polled blocks are already confirmed, so they are committed right away
+        let mut block_tracker = BlockTracker::new(0);
+        let mut last_synced_block = None;
+
+        loop {
+            let result = async {
+                if let Some(reorged_block) = self
+                    .revert_reorged_blocks(&provider, &mut block_tracker)
+                    .await?
+                {
+                    next_block = next_block.min(reorged_block);
+                }
+                let current_block_number = provider.get_block_number().await?;
+                let confirmed_block = current_block_number.saturating_sub(confirmations);
+                if confirmed_block >= next_block {
+                    self.fill_db_from_logs(
+                        &provider,
+                        self.events_filter(),
+                        next_block,
+                        confirmed_block,
+                        &mut block_tracker,
+                    )
+                    .await?;
+                    self.commit_blocks(confirmed_block, &mut block_tracker, &mut last_synced_block)
+                        .await;
+                    next_block = confirmed_block + 1;
+                }
+                Ok::<_, anyhow::Error>(())
+            }
+            .await;
+            match result {
+                Ok(()) => {
+                    self.service_status.send_replace(ServiceStatus::Up);
+                }
+                Err(err) => {
+                    self.service_status.send_replace(ServiceStatus::Down);
+                    self.logger.warn(
+                        &format!("Operators info polling failed: {:?}", err),
+                        "eigen-services-operatorsinfo.poll_events",
+                    );
+                }
+            }
+
+            tokio::select! {
+                _ = cancellation_token.cancelled() => break,
+                _ = tokio::time::sleep(poll_interval) => {},
+            }
+        }
+
+        if let Some(block_number) = last_synced_block {
+            self.checkpoint(block_number).await;
+        }
+        self.logger.info(
+            "Cancellation signal received, stopping the polling.",
+            "eigen-services-operatorsinfo.poll_events",
+        );
+        self.service_status.send_replace(ServiceStatus::Down);
+        Ok(())
+    }
+
+    /// Track a log, reverting the state if it reveals a reorg
+    fn track_log(&self, log: Log, block_tracker: &mut BlockTracker) {
+        if let Some(reorged_block) = block_tracker.track_log(log) {
+            self.revert_blocks(reorged_block);
+        }
+    }
+
+    /// Apply the logs of the blocks confirmed at `head`. The new confirmed height is
+    /// checkpointed on the first commit and then every `checkpoint_interval` blocks, as saving
+    /// rewrites the whole state.
+    async fn commit_blocks(
+        &self,
+        head: u64,
+        block_tracker: &mut BlockTracker,
+        last_synced_block: &mut Option<u64>,
+    ) {
+        for log in block_tracker.commit(head) {
+            if let Some(message) = self
+                .pub_keys_message_from_log(&log)
+                .or_else(|| self.registration_message_from_log(&log))
+            {
+                let _ = self.pub_keys.send(message);
+            }
+        }
+        let _ = self.pub_keys.send(OperatorsInfoMessage::Prune(
+            block_tracker.oldest_tracked_block(),
+        ));
+
+        let confirmed_height = block_tracker.confirmed_height();
+        if let Some(block_number) = confirmed_height {
+            if confirmed_height > *last_synced_block {
+                let previous_block = last_synced_block.replace(block_number);
+                let interval = self.checkpoint_interval.max(1);
+                if previous_block.map_or(true, |previous| {
+                    block_number / interval > previous / interval
+                }) {
+                    self.checkpoint(block_number).await;
+                }
+            }
+        }
+    }
+
+    /// Revert the state built from the blocks from `block_number` onwards
+    fn revert_blocks(&self, block_number: u64) {
+        self.logger.warn(
+            &format!(
+                "Reorg detected, reverting operators info from block {}",
+                block_number
+            ),
+            "eigen-services-operatorsinfo.revert_blocks",
+        );
+        let _ = self
+            .pub_keys
+            .send(OperatorsInfoMessage::Revert(block_number));
+    }
+
+    /// Compare the committed blocks with the chain, and revert the ones that were reorged
+    ///
+    /// # Returns
+    ///
+    /// The block to resume from if a reorg was found
+    async fn revert_reorged_blocks<T, P>(
+        &self,
+        provider: &P,
+        block_tracker: &mut BlockTracker,
+    ) -> Result<Option<u64>>
+    where
+        T: Transport + Clone,
+        P: Provider<T>,
+    {
+        let mut reorged_block = None;
+        for (number, hash) in block_tracker.committed_blocks().collect::<Vec<_>>() {
+            let block = provider.get_block_by_number(number.into(), false).await?;
+            if block.and_then(|block| block.header.hash) == Some(hash) {
+                break;
+            }
+            reorged_block = Some(number);
+        }
+        let Some(reorged_block) = reorged_block else {
+            return Ok(None);
+        };
+
+        // the blocks between the last valid committed block and the reorged one had no logs
+        // before the reorg, but may have some now
+        let resume_block = block_tracker
+            .committed_blocks()
+            .find(|(number, _)| *number < reorged_block)
+            .map_or(block_tracker.oldest_tracked_block(), |(number, _)| {
+                number + 1
+            });
+        block_tracker.revert(resume_block);
+        self.revert_blocks(resume_block);
+        Ok(Some(resume_block))
+    }
+
+    /// Restore the state from the storage, if any
+    ///
+    /// # Returns
+    ///
+    /// The last block processed by the restored state, or `None` if nothing was restored
+    async fn restore_from_storage(&self) -> Option<u64> {
+        let storage = self.storage.as_ref()?;
+        match storage.load().await {
+            Ok(Some(snapshot)) => {
+                let last_processed_block = snapshot.last_processed_block;
+                self.logger.info(
+                    &format!(
+                        "Restored operators info state up to block {}",
+                        last_processed_block
+                    ),
+                    "eigen-services-operatorsinfo.restore_from_storage",
+                );
+                let _ = self
+                    .pub_keys
+                    .send(OperatorsInfoMessage::Restore(Box::new(snapshot)));
+                Some(last_processed_block)
+            }
+            Ok(None) => None,
+            Err(err) => {
+                // the chain is the source of truth, so fall back to a full sync
+                self.logger.warn(
+                    &format!("Failed to restore operators info state: {:?}", err),
+                    "eigen-services-operatorsinfo.restore_from_storage",
+                );
+                None
+            }
+        }
+    }
+
+    /// Save the current state to the storage, if any, as processed up to `block_number`
+    async fn checkpoint(&self, block_number: u64) {
+        let Some(storage) = &self.storage else {
+            return;
+        };
+        let (responder_tx, responder_rx) = oneshot::channel();
+        if self
+            .pub_keys
+            .send(OperatorsInfoMessage::Snapshot(responder_tx))
+            .is_err()
+        {
+            return;
+        }
+        let Ok(mut snapshot) = responder_rx.await else {
+            return;
+        };
+        snapshot.last_processed_block = block_number;
+        if let Err(err) = storage.save(&snapshot).await {
+            self.logger.warn(
+                &format!("Failed to save operators info state: {:?}", err),
+                "eigen-services-operatorsinfo.checkpoint",
             );
+        }
+    }
+
+    /// Persist the state to `storage` while the service runs, and resume from the last
+    /// processed block saved there on start. Without a storage, the state only lives in memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `storage` - The storage to checkpoint the state to
+    pub fn with_storage(mut self, storage: Arc<dyn OperatorInfoStorage>) -> Self {
+        self.storage = Some(storage);
+        self
+    }
+
+    /// Checkpoint the state to the storage every `checkpoint_interval` confirmed blocks instead
+    /// of the default 100. The state is also checkpointed when the service stops.
+    ///
+    /// # Arguments
+    ///
+    /// * `checkpoint_interval` - The number of blocks between two checkpoints, 1 to checkpoint
+    ///   every block
+    pub fn with_checkpoint_interval(mut self, checkpoint_interval: u64) -> Self {
+        self.checkpoint_interval = checkpoint_interval;
+        self
+    }
+
+    /// Wait for `confirmations` blocks on top of a block before applying the events of the
+    /// websocket subscription. The http polling transport takes its own confirmations.
+    ///
+    /// # Arguments
+    ///
+    /// * `confirmations` - The number of confirmations
+    pub fn with_confirmations(mut self, confirmations: u64) -> Self {
+        self.confirmations = confirmations;
+        self
+    }
+
+    /// Subscribe to the connection status of the service, to be reported as the status of a
+    /// service registered in an [`eigen_nodeapi::NodeApi`]
+    pub fn subscribe_service_status(&self) -> watch::Receiver<ServiceStatus> {
+        self.service_status.subscribe()
+    }
+
+    /// Get the current connection status of the service
+    pub fn service_status(&self) -> ServiceStatus {
+        self.service_status.borrow().clone()
+    }
+
+    /// Set the bounds of the exponential backoff used to reconnect the websocket
+    ///
+    /// # Arguments
+    ///
+    /// * `min_backoff` - The delay before the first reconnection attempt
+    /// * `max_backoff` - The maximum delay between two reconnection attempts
+    pub fn with_reconnect_backoff(mut self, min_backoff: Duration, max_backoff: Duration) -> Self {
+        self.min_reconnect_backoff = min_backoff;
+        self.max_reconnect_backoff = max_backoff;
+        self
+    }
+
+    /// Decode a pub key registration or socket update log into the message updating the state
+    fn pub_keys_message_from_log(&self, log: &Log) -> Option<OperatorsInfoMessage> {
+        if let Ok(new_pub_key_event) = log.log_decode::<BLSApkRegistry::NewPubkeyRegistration>() {
+            let event_data = new_pub_key_event.data();
+            let operator_pub_key = OperatorPubKeys {
+                g1_pub_key: BlsG1Point::new(alloy_registry_g1_point_to_g1_affine(G1Point {
+                    X: event_data.pubkeyG1.X,
+                    Y: event_data.pubkeyG1.Y,
+                })),
+                g2_pub_key: BlsG2Point::new(alloy_registry_g2_point_to_g2_affine(G2Point {
+                    X: event_data.pubkeyG2.X,
+                    Y: event_data.pubkeyG2.Y,
+                })),
+            };
             self.logger.debug(
                 &format!(
                     "New pub key found  operator_address : {:?} , operator_pub_keys : {:?}",
-                    operator_address, operator_pub_keys
+                    event_data.operator, operator_pub_key
+                ),
+                "eigen-services-operatorsinfo.start_service",
+            );
+            Some(OperatorsInfoMessage::InsertOperatorInfo(
+                event_data.operator,
+                Box::new(operator_pub_key),
+                log.block_number.unwrap_or_default(),
+            ))
+        } else if let Ok(socket_update_event) =
+            log.log_decode::<RegistryCoordinator::OperatorSocketUpdate>()
+        {
+            let event_data = socket_update_event.data();
+            self.logger.debug(
+                &format!(
+                    "Socket update found  operator_id : {:?} , socket : {:?}",
+                    event_data.operatorId, event_data.socket
                 ),
-                "eigen-services-operatorsinfo.query_past_registered_operator_events_and_fill_db",
+                "eigen-services-operatorsinfo.start_service",
             );
-            let _ = self.pub_keys.send(message);
+            Some(OperatorsInfoMessage::UpdateSocket(
+                event_data.operatorId,
+                event_data.socket.clone(),
+                log.block_number.unwrap_or_default(),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Filter matching all the operator events of this AVS
+    fn events_filter(&self) -> Filter {
+        Filter::new()
+            .events([
+                NEW_PUBKEY_REGISTRATION_EVENT,
+                OPERATOR_SOCKET_UPDATE_EVENT,
+                OPERATOR_ADDED_TO_QUORUMS_EVENT,
+                OPERATOR_REMOVED_FROM_QUORUMS_EVENT,
+                OPERATOR_DEREGISTERED_EVENT,
+            ])
+            .address(vec![
+                self.avs_registry_reader.bls_apk_registry_address(),
+                self.avs_registry_reader.registry_coordinator_address(),
+            ])
+    }
+
+    /// Decode a registration or deregistration log into the message updating the operator status
+    fn registration_message_from_log(&self, log: &Log) -> Option<OperatorsInfoMessage> {
+        let block_number = log.block_number.unwrap_or_default();
+        let message = if let Ok(event) = log.log_decode::<BLSApkRegistry::OperatorAddedToQuorums>()
+        {
+            let data = event.data();
+            OperatorsInfoMessage::AddToQuorums(
+                data.operator,
+                data.quorumNumbers.clone(),
+                block_number,
+            )
+        } else if let Ok(event) = log.log_decode::<BLSApkRegistry::OperatorRemovedFromQuorums>() {
+            let data = event.data();
+            OperatorsInfoMessage::RemoveFromQuorums(
+                data.operator,
+                data.quorumNumbers.clone(),
+                block_number,
+            )
+        } else if let Ok(event) = log.log_decode::<RegistryCoordinator::OperatorDeregistered>() {
+            OperatorsInfoMessage::Deregister(event.data().operator, block_number)
+        } else {
+            return None;
+        };
+        self.logger.debug(
+            &format!("Registration update found : {:?}", message),
+            "eigen-services-operatorsinfo.registration_message_from_log",
+        );
+        Some(message)
+    }
+
+    /// Track the logs matching `filter` in the given block range (`end_block` 0 means the
+    /// current block). They are applied once committed.
+    async fn fill_db_from_logs<T, P>(
+        &self,
+        provider: &P,
+        filter: Filter,
+        start_block: u64,
+        end_block: u64,
+        block_tracker: &mut BlockTracker,
+    ) -> Result<u64>
+    where
+        T: Transport + Clone,
+        P: Provider<T>,
+    {
+        let end_block = if end_block == 0 {
+            provider.get_block_number().await?
+        } else {
+            end_block
+        };
+
+        let logs = LogScanner::new()
+            .scan(provider, &filter, start_block, end_block)
+            .await?;
+        self.logger.debug(
+            &format!(
+                "num_transaction_logs : {} , from_block: {start_block} , to_block: {end_block}",
+                logs.len()
+            ),
+            "eigen-services-operatorsinfo.fill_db_from_logs",
+        );
+        for log in logs {
+            self.track_log(log, block_tracker);
         }
 
+        Ok(end_block)
+    }
+
+    /// Fill the state with the operator events between `start_block` and `end_block`
+    /// (0 means the current block)
+    pub async fn query_past_registered_operator_events_and_fill_db(
+        &self,
+        start_block: u64,
+        end_block: u64,
+    ) -> Result<()> {
+        let mut block_tracker = BlockTracker::new(0);
+        let end_block = match &self.transport {
+            OperatorInfoServiceTransport::WebSocket(ws) => {
+                let provider = get_ws_provider(ws).await?;
+                self.fill_db_from_logs(
+                    &provider,
+                    self.events_filter(),
+                    start_block,
+                    end_block,
+                    &mut block_tracker,
+                )
+                .await?
+            }
+            OperatorInfoServiceTransport::HttpPolling { url, .. } => {
+                self.fill_db_from_logs(
+                    &get_provider(url),
+                    self.events_filter(),
+                    start_block,
+                    end_block,
+                    &mut block_tracker,
+                )
+                .await?
+            }
+        };
+        self.commit_blocks(end_block, &mut block_tracker, &mut None)
+            .await;
+
         Ok(())
     }
 }
@@ -246,6 +1253,7 @@ impl OperatorInfoServiceInMemory {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::storage::FileOperatorInfoStorage;
     use alloy_primitives::{address, Bytes, U256};
     use alloy_signer_local::PrivateKeySigner;
     use eigen_client_avsregistry::writer::AvsRegistryChainWriter;
@@ -263,6 +1271,85 @@ mod tests {
     use std::str::FromStr;
     use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+    #[tokio::test]
+    async fn test_operator_state_revert() {
+        let state = OperatorState::default();
+        let address = address!("f39fd6e51aad88f6f4ce6ab8827279cfffb92266");
+        let operator_id = FixedBytes::from([1u8; 32]);
+        let registered = OperatorRegistrationStatus::Registered {
+            quorums: BTreeSet::from([0]),
+            block_number: 10,
+        };
+        state
+            .operator_sockets
+            .write()
+            .await
+            .insert(operator_id, "localhost:8080".to_string());
+        state
+            .operator_registration_status
+            .write()
+            .await
+            .insert(address, registered.clone());
+        *state.journal.write().await = vec![
+            (
+                10,
+                JournalEntry::Socket {
+                    operator_id,
+                    socket: None,
+                },
+            ),
+            (
+                10,
+                JournalEntry::RegistrationStatus {
+                    address,
+                    status: None,
+                },
+            ),
+        ];
+        // block 11 deregisters the operator
+        state.operator_sockets.write().await.remove(&operator_id);
+        state.operator_registration_status.write().await.insert(
+            address,
+            OperatorRegistrationStatus::Deregistered { block_number: 11 },
+        );
+        state.journal.write().await.extend([
+            (
+                11,
+                JournalEntry::RegistrationStatus {
+                    address,
+                    status: Some(registered.clone()),
+                },
+            ),
+            (
+                11,
+                JournalEntry::Socket {
+                    operator_id,
+                    socket: Some("localhost:8080".to_string()),
+                },
+            ),
+        ]);
+
+        state.revert(11).await;
+        assert_eq!(
+            state
+                .operator_registration_status
+                .read()
+                .await
+                .get(&address),
+            Some(&registered)
+        );
+        assert_eq!(
+            state.operator_sockets.read().await.get(&operator_id),
+            Some(&"localhost:8080".to_string())
+        );
+        assert_eq!(state.journal.read().await.len(), 2);
+
+        state.revert(10).await;
+        assert!(state.operator_registration_status.read().await.is_empty());
+        assert!(state.operator_sockets.read().await.is_empty());
+        assert!(state.journal.read().await.is_empty());
+    }
+
     #[tokio::test]
     #[serial]
     async fn test_query_past_registered_operator_events_and_fill_db() {
@@ -304,8 +1391,41 @@ mod tests {
         let address = address!("90f79bf6eb2c4f870365e785982e1f101e93b906");
         let operator_info = operators_info_service_in_memory
             .get_operator_info(address)
-            .await;
-        assert!(operator_info.unwrap().is_some());
+            .await
+            .unwrap();
+        assert!(operator_info.is_some());
+
+        let operator_id = operators_info_service_in_memory
+            .get_operator_id(address)
+            .await
+            .unwrap()
+            .unwrap();
+        let operator_address = operators_info_service_in_memory
+            .get_operator_address(operator_id)
+            .await
+            .unwrap();
+        assert_eq!(operator_address, Some(address));
+
+        let operator_info_by_id = operators_info_service_in_memory
+            .get_operator_info_by_id(operator_id)
+            .await
+            .unwrap();
+        assert_eq!(operator_info_by_id, operator_info);
+
+        let operator_socket = operators_info_service_in_memory
+            .get_operator_socket(address)
+            .await
+            .unwrap();
+        assert_eq!(operator_socket, Some("socket".to_string()));
+
+        let status = operators_info_service_in_memory
+            .get_operator_registration_status(address)
+            .await
+            .unwrap();
+        assert!(matches!(
+            status,
+            Some(OperatorRegistrationStatus::Registered { quorums, .. }) if quorums.contains(&0)
+        ));
     }
 
     #[tokio::test]
@@ -358,6 +1478,12 @@ mod tests {
             .get_operator_info(address)
             .await;
         assert!(operator_info.unwrap().is_some());
+
+        let operator_socket = operators_info_service_in_memory
+            .get_operator_socket(address)
+            .await
+            .unwrap();
+        assert_eq!(operator_socket, Some("socket".to_string()));
     }
 
     #[tokio::test]
@@ -423,6 +1549,273 @@ mod tests {
         assert!(operator_info_2.unwrap().is_some());
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_query_past_deregistered_operator_events_and_fill_db() {
+        let anvil_ws_url = "ws://localhost:8545";
+        let anvil_http_url = "http://localhost:8545";
+        let test_logger = get_test_logger();
+        let pvt_key = "0x8b3a350cf5c34c9194ca85829a2df0ec3153be0318b5e2d3348e872092edffba";
+        register_operator(
+            pvt_key,
+            "13710126902690889134622698668747132666439281256983827313388062967626731803599",
+        )
+        .await;
+
+        let avs_registry_writer = AvsRegistryChainWriter::build_avs_registry_chain_writer(
+            test_logger.clone(),
+            anvil_http_url.to_string(),
+            pvt_key.to_string(),
+            get_registry_coordinator_address().await,
+            get_operator_state_retriever_address().await,
+        )
+        .await
+        .unwrap();
+        avs_registry_writer
+            .deregister_operator(Bytes::from_str("0x00").unwrap())
+            .await
+            .unwrap();
+
+        let avs_registry_chain_reader = AvsRegistryChainReader::new(
+            test_logger.clone(),
+            get_registry_coordinator_address().await,
+            get_operator_state_retriever_address().await,
+            anvil_http_url.to_string(),
+        )
+        .await
+        .unwrap();
+
+        let operators_info_service_in_memory = OperatorInfoServiceInMemory::new(
+            test_logger.clone(),
+            avs_registry_chain_reader,
+            anvil_ws_url.to_string(),
+        )
+        .await;
+
+        operators_info_service_in_memory
+            .query_past_registered_operator_events_and_fill_db(
+                0,
+                get_provider(anvil_http_url)
+                    .get_block_number()
+                    .await
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let address = address!("9965507d1a55bcc2695c58ba16fb37d819b0a4dc");
+        let status = operators_info_service_in_memory
+            .get_operator_registration_status(address)
+            .await
+            .unwrap();
+        assert!(matches!(
+            status,
+            Some(OperatorRegistrationStatus::Deregistered { .. })
+        ));
+        // pub keys are kept for lookups at past blocks
+        let operator_info = operators_info_service_in_memory
+            .get_operator_info(address)
+            .await
+            .unwrap();
+        assert!(operator_info.is_some());
+
+        let never_registered = operators_info_service_in_memory
+            .get_operator_registration_status(Address::ZERO)
+            .await
+            .unwrap();
+        assert_eq!(never_registered, None);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_start_service_reports_service_status() {
+        let anvil_ws_url = "ws://localhost:8545";
+        let anvil_http_url = "http://localhost:8545";
+        let test_logger = get_test_logger();
+        let avs_registry_chain_reader = AvsRegistryChainReader::new(
+            test_logger.clone(),
+            get_registry_coordinator_address().await,
+            get_operator_state_retriever_address().await,
+            anvil_http_url.to_string(),
+        )
+        .await
+        .unwrap();
+        let operators_info_service_in_memory = OperatorInfoServiceInMemory::new(
+            test_logger.clone(),
+            avs_registry_chain_reader,
+            anvil_ws_url.to_string(),
+        )
+        .await;
+        let status = operators_info_service_in_memory.subscribe_service_status();
+        assert_eq!(*status.borrow(), ServiceStatus::Initializing);
+
+        let clone_operators_info = operators_info_service_in_memory.clone();
+        let token = CancellationToken::new();
+        let cancel_token = token.clone();
+        let handle =
+            tokio::spawn(async move { clone_operators_info.start_service(&token, 0, 0).await });
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        assert_eq!(*status.borrow(), ServiceStatus::Up);
+
+        cancel_token.cancel();
+        handle.await.unwrap().unwrap();
+        assert_eq!(*status.borrow(), ServiceStatus::Down);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_start_service_retries_connection_until_cancelled() {
+        let anvil_http_url = "http://localhost:8545";
+        let test_logger = get_test_logger();
+        let avs_registry_chain_reader = AvsRegistryChainReader::new(
+            test_logger.clone(),
+            get_registry_coordinator_address().await,
+            get_operator_state_retriever_address().await,
+            anvil_http_url.to_string(),
+        )
+        .await
+        .unwrap();
+        // nothing listens on this port, so every connection attempt fails
+        let operators_info_service_in_memory = OperatorInfoServiceInMemory::new(
+            test_logger.clone(),
+            avs_registry_chain_reader,
+            "ws://localhost:1".to_string(),
+        )
+        .await
+        .with_reconnect_backoff(Duration::from_millis(10), Duration::from_millis(50));
+
+        let clone_operators_info = operators_info_service_in_memory.clone();
+        let token = CancellationToken::new();
+        let cancel_token = token.clone();
+        let handle =
+            tokio::spawn(async move { clone_operators_info.start_service(&token, 0, 0).await });
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        assert_eq!(
+            operators_info_service_in_memory.service_status(),
+            ServiceStatus::Down
+        );
+        assert!(!handle.is_finished());
+
+        cancel_token.cancel();
+        handle.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_start_service_http_polling() {
+        let anvil_http_url = "http://localhost:8545";
+        let test_logger = get_test_logger();
+        let avs_registry_chain_reader = AvsRegistryChainReader::new(
+            test_logger.clone(),
+            get_registry_coordinator_address().await,
+            get_operator_state_retriever_address().await,
+            anvil_http_url.to_string(),
+        )
+        .await
+        .unwrap();
+        let operators_info_service_in_memory = OperatorInfoServiceInMemory::new_with_transport(
+            test_logger.clone(),
+            avs_registry_chain_reader,
+            OperatorInfoServiceTransport::HttpPolling {
+                url: anvil_http_url.to_string(),
+                poll_interval: Duration::from_millis(100),
+                confirmations: 0,
+            },
+        )
+        .await;
+        let clone_operators_info = operators_info_service_in_memory.clone();
+
+        let token = CancellationToken::new();
+        let cancel_token = token.clone();
+        tokio::spawn(async move {
+            let _ = clone_operators_info.start_service(&token, 0, 0).await;
+        });
+        register_operator(
+            "0x92db14e403b83dfe3df233f83dfa3a0d7096f21ca9b0d6d6b8d88b2b4ec1564e",
+            "9876543210123456789012345678901234567890123456789012345678901234567890123456",
+        )
+        .await;
+        tokio::time::sleep(Duration::from_secs(1)).await;
+
+        cancel_token.cancel();
+
+        let address = address!("976ea74026e726554db657fa54763abd0c3a0aa9");
+        let operator_info = operators_info_service_in_memory
+            .get_operator_info(address)
+            .await
+            .unwrap();
+        assert!(operator_info.is_some());
+        let operator_socket = operators_info_service_in_memory
+            .get_operator_socket(address)
+            .await
+            .unwrap();
+        assert_eq!(operator_socket, Some("socket".to_string()));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_start_service_resumes_from_storage() {
+        let anvil_http_url = "http://localhost:8545";
+        let test_logger = get_test_logger();
+        let dir = tempfile::tempdir().unwrap();
+        let storage = Arc::new(FileOperatorInfoStorage::new(
+            dir.path().join("operators.json"),
+        ));
+        let transport = OperatorInfoServiceTransport::HttpPolling {
+            url: anvil_http_url.to_string(),
+            poll_interval: Duration::from_millis(100),
+            confirmations: 0,
+        };
+        let avs_registry_chain_reader = AvsRegistryChainReader::new(
+            test_logger.clone(),
+            get_registry_coordinator_address().await,
+            get_operator_state_retriever_address().await,
+            anvil_http_url.to_string(),
+        )
+        .await
+        .unwrap();
+        let operators_info_service_in_memory = OperatorInfoServiceInMemory::new_with_transport(
+            test_logger.clone(),
+            avs_registry_chain_reader.clone(),
+            transport.clone(),
+        )
+        .await
+        .with_storage(storage.clone());
+
+        let token = CancellationToken::new();
+        let cancel_token = token.clone();
+        let handle = tokio::spawn(async move {
+            operators_info_service_in_memory
+                .start_service(&token, 0, 0)
+                .await
+        });
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        cancel_token.cancel();
+        handle.await.unwrap().unwrap();
+
+        let snapshot = storage.load().await.unwrap().unwrap();
+        let current_block_number = get_provider(anvil_http_url)
+            .get_block_number()
+            .await
+            .unwrap();
+        assert_eq!(snapshot.last_processed_block, current_block_number);
+        let (address, pub_keys) = snapshot.operator_info_data.iter().next().unwrap();
+
+        // a new service restores the saved state before polling any new block
+        let restored_service = OperatorInfoServiceInMemory::new_with_transport(
+            test_logger.clone(),
+            avs_registry_chain_reader,
+            transport,
+        )
+        .await
+        .with_storage(storage);
+        let token = CancellationToken::new();
+        token.cancel();
+        restored_service.start_service(&token, 0, 0).await.unwrap();
+        let operator_info = restored_service.get_operator_info(*address).await.unwrap();
+        assert_eq!(operator_info.as_ref(), Some(pub_keys));
+    }
+
     pub async fn register_operator(pvt_key: &str, bls_key: &str) {
         let anvil_http_url = "http://localhost:8545";
 
@@ -444,7 +1837,8 @@ mod tests {
             el_chain_reader,
             anvil_http_url.to_string(),
             pvt_key.to_string(),
-        );
+        )
+        .unwrap();
 
         let operator_details = Operator::new(
             signer.address(),
@@ -0,0 +1,311 @@
+use alloy_primitives::B256;
+use alloy_rpc_types::Log;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Number of committed blocks below the confirmed height whose hashes are kept to detect reorgs
+pub const MAX_REORG_DEPTH: u64 = 128;
+
+/// Tracks the blocks of the ingested logs to detect reorgs, and holds back the logs of the
+/// blocks that don't have enough confirmations yet.
+///
+/// Logs are first tracked with [`BlockTracker::track_log`], then handed back in order by
+/// [`BlockTracker::commit`] once their block is confirmed. When a reorg is detected, the
+/// tracker forgets the reorged blocks and reports the first one, so the caller can revert the
+/// state it built from their logs.
+#[derive(Debug, Default)]
+pub struct BlockTracker {
+    confirmations: u64,
+    /// Hash and log indexes of the committed blocks with logs, within the reorg window
+    committed: BTreeMap<u64, (B256, BTreeSet<u64>)>,
+    /// Hash and logs of the blocks waiting for confirmations
+    pending: BTreeMap<u64, (B256, Vec<Log>)>,
+    /// Highest block whose logs were all committed
+    confirmed_height: Option<u64>,
+}
+
+impl BlockTracker {
+    /// Create a new tracker
+    ///
+    /// # Arguments
+    ///
+    /// * `confirmations` - Number of blocks on top of a block before its logs are committed
+    pub fn new(confirmations: u64) -> Self {
+        Self {
+            confirmations,
+            ..Default::default()
+        }
+    }
+
+    /// Track a log. Logs already committed are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `log` - The log, with its block number and hash
+    ///
+    /// # Returns
+    ///
+    /// The first reorged block if the log shows that committed blocks were reorged
+    pub fn track_log(&mut self, log: Log) -> Option<u64> {
+        let (Some(number), Some(hash)) = (log.block_number, log.block_hash) else {
+            return None;
+        };
+        let log_index = log.log_index.unwrap_or_default();
+
+        if log.removed {
+            if let Some((pending_hash, logs)) = self.pending.get_mut(&number) {
+                if *pending_hash == hash {
+                    logs.retain(|pending_log| pending_log.log_index != log.log_index);
+                }
+            }
+            return match self.committed.get(&number) {
+                Some((committed_hash, _)) if *committed_hash == hash => {
+                    self.revert(number);
+                    Some(number)
+                }
+                _ => None,
+            };
+        }
+
+        let mut reorged_block = None;
+        match self.committed.get(&number) {
+            Some((committed_hash, _)) if *committed_hash != hash => {
+                self.revert(number);
+                reorged_block = Some(number);
+            }
+            Some((_, log_indexes)) if log_indexes.contains(&log_index) => return None,
+            _ => {}
+        }
+
+        match self.pending.get_mut(&number) {
+            Some((pending_hash, logs)) if *pending_hash == hash => {
+                if logs
+                    .iter()
+                    .all(|pending_log| pending_log.log_index != log.log_index)
+                {
+                    logs.push(log);
+                }
+            }
+            Some(_) => {
+                // the block was replaced before being committed, its logs and the ones of the
+                // following blocks are stale
+                self.pending.split_off(&number);
+                self.pending.insert(number, (hash, vec![log]));
+            }
+            None => {
+                self.pending.insert(number, (hash, vec![log]));
+            }
+        }
+        reorged_block
+    }
+
+    /// Track a new chain head
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The block number of the head
+    /// * `hash` - The block hash of the head
+    ///
+    /// # Returns
+    ///
+    /// The first reorged block if the head replaces a committed block
+    pub fn track_head(&mut self, number: u64, hash: B256) -> Option<u64> {
+        if matches!(self.pending.get(&number), Some((pending_hash, _)) if *pending_hash != hash) {
+            self.pending.split_off(&number);
+        }
+        match self.committed.get(&number) {
+            Some((committed_hash, _)) if *committed_hash != hash => {
+                self.revert(number);
+                Some(number)
+            }
+            _ => None,
+        }
+    }
+
+    /// Commit the blocks confirmed at `head`
+    ///
+    /// # Arguments
+    ///
+    /// * `head` - The current chain head
+    ///
+    /// # Returns
+    ///
+    /// The logs of the newly committed blocks, in the order they were emitted
+    pub fn commit(&mut self, head: u64) -> Vec<Log> {
+        let confirmed = head.saturating_sub(self.confirmations);
+        let still_pending = self.pending.split_off(&(confirmed + 1));
+        let confirmed_blocks = std::mem::replace(&mut self.pending, still_pending);
+
+        let mut committed_logs = vec![];
+        for (number, (hash, mut logs)) in confirmed_blocks {
+            logs.sort_by_key(|log| log.log_index);
+            let (_, log_indexes) = self
+                .committed
+                .entry(number)
+                .or_insert_with(|| (hash, BTreeSet::new()));
+            log_indexes.extend(logs.iter().map(|log| log.log_index.unwrap_or_default()));
+            committed_logs.extend(logs);
+        }
+
+        if self
+            .confirmed_height
+            .map_or(true, |height| confirmed > height)
+        {
+            self.confirmed_height = Some(confirmed);
+        }
+        let oldest_tracked_block = self.oldest_tracked_block();
+        self.committed = self.committed.split_off(&oldest_tracked_block);
+        committed_logs
+    }
+
+    /// Forget the blocks from `block_number` onwards
+    ///
+    /// # Arguments
+    ///
+    /// * `block_number` - The first reorged block
+    pub fn revert(&mut self, block_number: u64) {
+        self.committed.split_off(&block_number);
+        self.pending.split_off(&block_number);
+        if self
+            .confirmed_height
+            .is_some_and(|height| height >= block_number)
+        {
+            self.confirmed_height = block_number.checked_sub(1);
+        }
+    }
+
+    /// Get the committed blocks with logs that are still in the reorg window, newest first
+    pub fn committed_blocks(&self) -> impl Iterator<Item = (u64, B256)> + '_ {
+        self.committed
+            .iter()
+            .rev()
+            .map(|(number, (hash, _))| (*number, *hash))
+    }
+
+    /// Get the highest block whose logs were all committed
+    pub fn confirmed_height(&self) -> Option<u64> {
+        self.confirmed_height
+    }
+
+    /// Get the oldest block that can still be reverted. Changes of older blocks are final.
+    pub fn oldest_tracked_block(&self) -> u64 {
+        self.confirmed_height
+            .unwrap_or_default()
+            .saturating_sub(MAX_REORG_DEPTH)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(block_number: u64, block_hash: u8, log_index: u64) -> Log {
+        Log {
+            block_number: Some(block_number),
+            block_hash: Some(B256::repeat_byte(block_hash)),
+            log_index: Some(log_index),
+            ..Default::default()
+        }
+    }
+
+    fn removed(log: Log) -> Log {
+        Log {
+            removed: true,
+            ..log
+        }
+    }
+
+    fn indexes(logs: &[Log]) -> Vec<(u64, u64)> {
+        logs.iter()
+            .map(|log| (log.block_number.unwrap(), log.log_index.unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_commit_waits_for_confirmations() {
+        let mut tracker = BlockTracker::new(2);
+        assert_eq!(tracker.track_log(log(10, 1, 1)), None);
+        assert_eq!(tracker.track_log(log(10, 1, 0)), None);
+        assert_eq!(tracker.track_log(log(11, 2, 0)), None);
+
+        assert!(tracker.commit(11).is_empty());
+        assert_eq!(indexes(&tracker.commit(12)), vec![(10, 0), (10, 1)]);
+        assert_eq!(tracker.confirmed_height(), Some(10));
+        assert_eq!(indexes(&tracker.commit(13)), vec![(11, 0)]);
+    }
+
+    #[test]
+    fn test_committed_logs_are_not_committed_twice() {
+        let mut tracker = BlockTracker::new(0);
+        tracker.track_log(log(10, 1, 0));
+        assert_eq!(tracker.commit(10).len(), 1);
+
+        // replayed after a reconnection, along with a log missed before
+        tracker.track_log(log(10, 1, 0));
+        tracker.track_log(log(10, 1, 1));
+        assert_eq!(indexes(&tracker.commit(10)), vec![(10, 1)]);
+    }
+
+    #[test]
+    fn test_removed_log_of_pending_block() {
+        let mut tracker = BlockTracker::new(2);
+        tracker.track_log(log(10, 1, 0));
+        assert_eq!(tracker.track_log(removed(log(10, 1, 0))), None);
+        assert!(tracker.commit(12).is_empty());
+    }
+
+    #[test]
+    fn test_removed_log_of_committed_block() {
+        let mut tracker = BlockTracker::new(0);
+        tracker.track_log(log(10, 1, 0));
+        tracker.track_log(log(11, 2, 0));
+        tracker.commit(11);
+
+        assert_eq!(tracker.track_log(removed(log(11, 2, 0))), Some(11));
+        assert_eq!(tracker.confirmed_height(), Some(10));
+        assert_eq!(
+            tracker.committed_blocks().collect::<Vec<_>>(),
+            vec![(10, B256::repeat_byte(1))]
+        );
+    }
+
+    #[test]
+    fn test_log_with_new_hash_reverts_committed_block() {
+        let mut tracker = BlockTracker::new(0);
+        tracker.track_log(log(10, 1, 0));
+        tracker.track_log(log(11, 2, 0));
+        tracker.commit(11);
+
+        assert_eq!(tracker.track_log(log(10, 3, 0)), Some(10));
+        assert_eq!(tracker.confirmed_height(), Some(9));
+        assert_eq!(indexes(&tracker.commit(10)), vec![(10, 0)]);
+        assert_eq!(
+            tracker.committed_blocks().collect::<Vec<_>>(),
+            vec![(10, B256::repeat_byte(3))]
+        );
+    }
+
+    #[test]
+    fn test_head_with_new_hash_drops_pending_and_reverts_committed_blocks() {
+        let mut tracker = BlockTracker::new(1);
+        tracker.track_log(log(10, 1, 0));
+        tracker.track_log(log(11, 2, 0));
+        tracker.commit(11);
+
+        assert_eq!(tracker.track_head(11, B256::repeat_byte(4)), None);
+        assert!(tracker.commit(12).is_empty());
+
+        assert_eq!(tracker.track_head(10, B256::repeat_byte(5)), Some(10));
+        assert_eq!(tracker.committed_blocks().count(), 0);
+    }
+
+    #[test]
+    fn test_committed_blocks_are_pruned_outside_reorg_window() {
+        let mut tracker = BlockTracker::new(0);
+        tracker.track_log(log(10, 1, 0));
+        tracker.commit(10);
+        tracker.commit(10 + MAX_REORG_DEPTH + 1);
+
+        assert_eq!(tracker.committed_blocks().count(), 0);
+        assert_eq!(tracker.oldest_tracked_block(), 11);
+    }
+}
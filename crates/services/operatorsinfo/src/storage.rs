@@ -0,0 +1,152 @@
+use alloy_primitives::{Address, FixedBytes};
+use async_trait::async_trait;
+use eigen_types::operator::OperatorPubKeys;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt::Debug, path::PathBuf};
+use thiserror::Error;
+
+use crate::operator_info::OperatorRegistrationStatus;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum OperatorInfoStorageError {
+    #[error("failed to read operators info storage: {0}")]
+    Read(String),
+    #[error("failed to write operators info storage: {0}")]
+    Write(String),
+    #[error("failed to decode operators info storage: {0}")]
+    Decode(String),
+}
+
+/// State of the operators info service, as persisted by an [`OperatorInfoStorage`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OperatorInfoSnapshot {
+    /// Last block whose events are all included in the snapshot
+    pub last_processed_block: u64,
+    pub operator_info_data: HashMap<Address, OperatorPubKeys>,
+    pub operator_addr_to_id: HashMap<Address, FixedBytes<32>>,
+    pub operator_id_to_addr: HashMap<FixedBytes<32>, Address>,
+    pub operator_sockets: HashMap<FixedBytes<32>, String>,
+    pub operator_registration_status: HashMap<Address, OperatorRegistrationStatus>,
+}
+
+/// Storage the operators info service checkpoints its state to, so it can resume from the last
+/// processed block instead of replaying all the events.
+#[async_trait]
+pub trait OperatorInfoStorage: Debug + Send + Sync {
+    /// Load the last saved snapshot
+    ///
+    /// # Returns
+    ///
+    /// The last saved snapshot, or `None` if nothing was saved yet
+    async fn load(&self) -> Result<Option<OperatorInfoSnapshot>, OperatorInfoStorageError>;
+
+    /// Save a snapshot, replacing the previous one
+    ///
+    /// # Arguments
+    ///
+    /// * `snapshot` - The snapshot to save
+    async fn save(&self, snapshot: &OperatorInfoSnapshot) -> Result<(), OperatorInfoStorageError>;
+}
+
+/// [`OperatorInfoStorage`] saving the snapshot as a json file
+#[derive(Debug, Clone)]
+pub struct FileOperatorInfoStorage {
+    path: PathBuf,
+}
+
+impl FileOperatorInfoStorage {
+    /// Create a new file storage
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the json file. It is created on the first save.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[async_trait]
+impl OperatorInfoStorage for FileOperatorInfoStorage {
+    async fn load(&self) -> Result<Option<OperatorInfoSnapshot>, OperatorInfoStorageError> {
+        let content = match tokio::fs::read(&self.path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(OperatorInfoStorageError::Read(e.to_string())),
+        };
+        serde_json::from_slice(&content)
+            .map(Some)
+            .map_err(|e| OperatorInfoStorageError::Decode(e.to_string()))
+    }
+
+    async fn save(&self, snapshot: &OperatorInfoSnapshot) -> Result<(), OperatorInfoStorageError> {
+        let content = serde_json::to_vec(snapshot)
+            .map_err(|e| OperatorInfoStorageError::Write(e.to_string()))?;
+        // write to a temporary file first, so a crash never leaves a truncated snapshot
+        let tmp_path = self.path.with_extension("tmp");
+        tokio::fs::write(&tmp_path, content)
+            .await
+            .map_err(|e| OperatorInfoStorageError::Write(e.to_string()))?;
+        tokio::fs::rename(&tmp_path, &self.path)
+            .await
+            .map_err(|e| OperatorInfoStorageError::Write(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+    use eigen_crypto_bls::BlsKeyPair;
+    use std::collections::BTreeSet;
+
+    #[tokio::test]
+    async fn test_file_storage_save_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = FileOperatorInfoStorage::new(dir.path().join("operators.json"));
+        assert_eq!(storage.load().await.unwrap(), None);
+
+        let address = address!("f39fd6e51aad88f6f4ce6ab8827279cfffb92266");
+        let operator_id = FixedBytes::from([1u8; 32]);
+        let key_pair = BlsKeyPair::new(
+            "12248929636257230549931416853095037629726205319386239410403476017439825112537"
+                .to_string(),
+        )
+        .unwrap();
+        let snapshot = OperatorInfoSnapshot {
+            last_processed_block: 42,
+            operator_info_data: HashMap::from([(address, OperatorPubKeys::from(key_pair))]),
+            operator_addr_to_id: HashMap::from([(address, operator_id)]),
+            operator_id_to_addr: HashMap::from([(operator_id, address)]),
+            operator_sockets: HashMap::from([(operator_id, "localhost:8080".to_string())]),
+            operator_registration_status: HashMap::from([(
+                address,
+                OperatorRegistrationStatus::Registered {
+                    quorums: BTreeSet::from([0, 1]),
+                    block_number: 40,
+                },
+            )]),
+        };
+        storage.save(&snapshot).await.unwrap();
+        assert_eq!(storage.load().await.unwrap(), Some(snapshot.clone()));
+
+        let updated_snapshot = OperatorInfoSnapshot {
+            last_processed_block: 43,
+            ..snapshot
+        };
+        storage.save(&updated_snapshot).await.unwrap();
+        assert_eq!(storage.load().await.unwrap(), Some(updated_snapshot));
+    }
+
+    #[tokio::test]
+    async fn test_file_storage_load_invalid_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("operators.json");
+        tokio::fs::write(&path, "not json").await.unwrap();
+
+        let storage = FileOperatorInfoStorage::new(path);
+        assert!(matches!(
+            storage.load().await,
+            Err(OperatorInfoStorageError::Decode(_))
+        ));
+    }
+}
@@ -3,7 +3,9 @@ use ark_serialize::{CanonicalSerialize, SerializationError};
 use eigen_crypto_bls::{BlsG1Point, BlsG2Point, BlsKeyPair};
 use ethers::{types::U64, utils::keccak256};
 use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use thiserror::Error;
 
 const MAX_NUMBER_OF_QUORUMS: usize = 192;
 
@@ -23,7 +25,7 @@ pub fn bitmap_to_quorum_ids(quorum_bitmaps: U256) -> Vec<u8> {
     quorum_ids
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct OperatorPubKeys {
     pub g1_pub_key: BlsG1Point,
     pub g2_pub_key: BlsG2Point,
@@ -111,6 +113,8 @@ pub type QuorumNum = u8;
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct OperatorInfo {
     pub pub_keys: Option<OperatorPubKeys>,
+    /// The socket the operator advertises on the registry coordinator, if any
+    pub socket: Option<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -136,6 +140,147 @@ pub struct QuorumAvsState {
     pub block_num: u32,
 }
 
+/// Registration status of an operator, as stored by the registry coordinator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum OperatorStatus {
+    /// The operator never registered with the AVS
+    NeverRegistered = 0,
+    /// The operator is registered in at least one quorum
+    Registered = 1,
+    /// The operator deregistered from all its quorums
+    Deregistered = 2,
+}
+
+/// Strategy whose delegated shares count towards the stake of a quorum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StrategyParams {
+    pub strategy: Address,
+    /// Weight of one share of the strategy, scaled by 1e18
+    pub multiplier: U256,
+}
+
+/// Entry of a stake history, valid from `update_block_number` until `next_update_block_number`
+/// (0 while it is the latest one)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StakeUpdate {
+    pub update_block_number: u32,
+    pub next_update_block_number: u32,
+    pub stake: U256,
+}
+
+/// Entry of an operator's quorum bitmap history, valid from `update_block_number` until
+/// `next_update_block_number` (0 while it is the latest one)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuorumBitmapUpdate {
+    pub update_block_number: u32,
+    pub next_update_block_number: u32,
+    /// Bitmap of the quorums the operator is registered for
+    pub quorum_bitmap: U256,
+}
+
+impl QuorumBitmapUpdate {
+    /// Get the quorums the operator is registered for during this update
+    pub fn quorum_numbers(&self) -> Vec<QuorumNum> {
+        bitmap_to_quorum_ids(self.quorum_bitmap)
+    }
+}
+
+/// Configuration of a quorum, as set on the registry coordinator and the stake registry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuorumConfig {
+    pub quorum_number: QuorumNum,
+    /// Maximum number of operators registered for the quorum
+    pub max_operator_count: u32,
+    /// Stake an incoming operator needs relative to the churned one, in basis points
+    pub kick_bips_of_operator_stake: u16,
+    /// Stake below which an operator can be churned, relative to the total stake, in basis
+    /// points
+    pub kick_bips_of_total_stake: u16,
+    /// Minimum stake an operator needs to register for the quorum
+    pub minimum_stake: U256,
+    pub strategy_params: Vec<StrategyParams>,
+    /// Updates of the total stake of the quorum, oldest first
+    pub total_stake_history: Vec<StakeUpdate>,
+}
+
 pub type QuorumThresholdPercentage = u8;
 
 pub type QuorumThresholdPercentages = Vec<QuorumThresholdPercentage>;
+
+/// Denominator of a [`QuorumThreshold`] built from a [`QuorumThresholdPercentage`].
+pub const PERCENTAGE_DENOMINATOR: u64 = 100;
+
+/// Denominator of a [`QuorumThreshold`] built from basis points, e.g. `6667` is 66.67%.
+pub const BASIS_POINTS_DENOMINATOR: u64 = 10_000;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum QuorumThresholdError {
+    #[error("quorum threshold denominator must not be zero")]
+    ZeroDenominator,
+}
+
+/// Fraction of the total stake of a quorum that has to sign a response, stored as
+/// `numerator / denominator`.
+///
+/// The check uses the same integer arithmetic as the contracts do with their
+/// `THRESHOLD_DENOMINATOR`, so a response that meets the threshold off-chain also meets it
+/// on-chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct QuorumThreshold {
+    numerator: u64,
+    denominator: u64,
+}
+
+pub type QuorumThresholds = Vec<QuorumThreshold>;
+
+impl QuorumThreshold {
+    /// Creates a threshold of `numerator / denominator`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `QuorumThresholdError::ZeroDenominator` if `denominator` is zero.
+    pub fn new(numerator: u64, denominator: u64) -> Result<Self, QuorumThresholdError> {
+        if denominator == 0 {
+            return Err(QuorumThresholdError::ZeroDenominator);
+        }
+        Ok(Self {
+            numerator,
+            denominator,
+        })
+    }
+
+    /// Creates a threshold expressed in basis points (hundredths of a percent).
+    pub fn from_basis_points(basis_points: u16) -> Self {
+        Self {
+            numerator: basis_points.into(),
+            denominator: BASIS_POINTS_DENOMINATOR,
+        }
+    }
+
+    pub fn numerator(&self) -> u64 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> u64 {
+        self.denominator
+    }
+
+    /// Returns `true` if `signed_stake / total_stake >= numerator / denominator`.
+    ///
+    /// Computed as `signed_stake * denominator >= total_stake * numerator`, without any
+    /// rounding.
+    pub fn is_met(&self, signed_stake: U256, total_stake: U256) -> bool {
+        signed_stake.saturating_mul(U256::from(self.denominator))
+            >= total_stake.saturating_mul(U256::from(self.numerator))
+    }
+}
+
+impl From<QuorumThresholdPercentage> for QuorumThreshold {
+    fn from(percentage: QuorumThresholdPercentage) -> Self {
+        Self {
+            numerator: percentage.into(),
+            denominator: PERCENTAGE_DENOMINATOR,
+        }
+    }
+}
@@ -4,6 +4,7 @@ use alloy_sol_types::sol;
 sol!(
     #[allow(missing_docs)]
     #[sol(rpc)]
+    #[derive(Debug)]
     StakeRegistry,
     "../../crates/contracts/bindings/utils/json/StakeRegistry.json"
 );
@@ -56,6 +57,7 @@ sol!(
 sol!(
     #[allow(missing_docs)]
     #[sol(rpc)]
+    #[derive(Debug)]
     DelegationManager,
     "../../crates/contracts/bindings/utils/json/DelegationManager.json"
 );
@@ -80,6 +82,7 @@ sol!(
 sol!(
     #[allow(missing_docs)]
     #[sol(rpc)]
+    #[derive(Debug)]
     IERC20,
     "../../crates/contracts/bindings/utils/json/IERC20.json"
 );
@@ -88,6 +91,7 @@ sol!(
 sol!(
     #[allow(missing_docs)]
     #[sol(rpc)]
+    #[derive(Debug)]
     StrategyManager,
     "../../crates/contracts/bindings/utils/json/StrategyManager.json"
 );
@@ -104,10 +108,21 @@ sol!(
 sol!(
     #[allow(missing_docs)]
     #[sol(rpc)]
+    #[derive(Debug)]
     ECDSAStakeRegistry,
     "../../crates/contracts/bindings/utils/json/ECDSAStakeRegistry.json"
 );
 
+// https://github.com/Layr-Labs/eigenlayer-contracts/blob/mainnet/src/contracts/interfaces/IPauserRegistry.sol
+sol!(
+    #[allow(missing_docs)]
+    #[sol(rpc)]
+    interface IPauserRegistry {
+        function isPauser(address pauser) external view returns (bool);
+        function unpauser() external view returns (address);
+    }
+);
+
 sol!(
     #[allow(missing_docs)]
     #[sol(rpc)]
@@ -5,6 +5,7 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 
 pub mod binding;
+pub mod revert;
 use alloy_json_rpc::RpcError;
 use alloy_network::{Ethereum, EthereumWallet};
 use alloy_provider::{
@@ -13,10 +14,25 @@ use alloy_provider::{
 };
 use alloy_pubsub::PubSubFrontend;
 use alloy_signer_local::PrivateKeySigner;
-use alloy_transport::TransportErrorKind;
+use alloy_transport::{BoxTransport, TransportErrorKind};
 use alloy_transport_http::{Client, Http};
 use reqwest::Url;
 
+/// Provider shared by the chain readers and writers. Its clones share the same connection.
+///
+/// Any alloy [`RootProvider`] can be shared after boxing its transport with
+/// [`RootProvider::boxed`], e.g. a WS or IPC provider, or one whose transport has retry
+/// and metrics layers.
+pub type SharedProvider = RootProvider<BoxTransport>;
+
+/// Build a provider signing its transactions with the private key `key`
+///
+/// # Panics
+///
+/// If `key` is not a valid private key or `rpc_url` is not a valid url
+#[deprecated(
+    note = "panics on an invalid key; parse it into a `PrivateKeySigner` and use `get_shared_wallet_provider` instead"
+)]
 #[allow(clippy::type_complexity)]
 pub fn get_signer(
     key: String,
@@ -34,11 +50,31 @@ pub fn get_signer(
     Ethereum,
 > {
     let signer: PrivateKeySigner = key.parse().expect("failed to generate wallet ");
-    let wallet = EthereumWallet::from(signer);
+    get_wallet_provider(EthereumWallet::from(signer), rpc_url)
+}
+
+/// Build a provider signing its transactions with `wallet`, which can hold any
+/// [`TxSigner`](alloy_network::TxSigner): a local key, an AWS KMS key, a Web3Signer, etc.
+#[allow(clippy::type_complexity)]
+pub fn get_wallet_provider(
+    wallet: EthereumWallet,
+    rpc_url: &str,
+) -> FillProvider<
+    JoinFill<
+        JoinFill<
+            JoinFill<JoinFill<alloy_provider::Identity, GasFiller>, NonceFiller>,
+            ChainIdFiller,
+        >,
+        WalletFiller<EthereumWallet>,
+    >,
+    RootProvider<Http<Client>>,
+    Http<Client>,
+    Ethereum,
+> {
     let url = Url::parse(rpc_url).expect("Wrong rpc url");
     ProviderBuilder::new()
         .with_recommended_fillers()
-        .wallet(wallet.clone())
+        .wallet(wallet)
         .on_http(url)
 }
 
@@ -57,6 +93,43 @@ pub fn get_provider(
         .on_http(url)
 }
 
+/// Build a [`SharedProvider`] connected to `rpc_url` over http.
+///
+/// Nonces, gas and chain id are not filled by this provider, the tx manager of the writers
+/// fills and signs the transactions.
+///
+/// # Errors
+///
+/// * `url::ParseError` - if `rpc_url` is not a valid url
+pub fn get_shared_provider(rpc_url: &str) -> Result<SharedProvider, url::ParseError> {
+    let url = Url::parse(rpc_url)?;
+    Ok(RootProvider::new_http(url).boxed())
+}
+
+/// Wrap a [`SharedProvider`] with the recommended fillers and a wallet signing its
+/// transactions, reusing the connection of the shared provider.
+#[allow(clippy::type_complexity)]
+pub fn get_shared_wallet_provider(
+    wallet: EthereumWallet,
+    provider: SharedProvider,
+) -> FillProvider<
+    JoinFill<
+        JoinFill<
+            JoinFill<JoinFill<alloy_provider::Identity, GasFiller>, NonceFiller>,
+            ChainIdFiller,
+        >,
+        WalletFiller<EthereumWallet>,
+    >,
+    SharedProvider,
+    BoxTransport,
+    Ethereum,
+> {
+    ProviderBuilder::new()
+        .with_recommended_fillers()
+        .wallet(wallet)
+        .on_provider(provider)
+}
+
 #[allow(clippy::type_complexity)]
 pub async fn get_ws_provider(
     rpc_url: &str,
@@ -68,3 +141,16 @@ pub async fn get_ws_provider(
 /// Emitted when a new pubkey is registered
 pub const NEW_PUBKEY_REGISTRATION_EVENT: &str =
     "NewPubkeyRegistration(address,(uint256,uint256),(uint256[2],uint256[2]))";
+
+/// Emitted when an operator updates its socket
+pub const OPERATOR_SOCKET_UPDATE_EVENT: &str = "OperatorSocketUpdate(bytes32,string)";
+
+/// Emitted when an operator is added to quorums
+pub const OPERATOR_ADDED_TO_QUORUMS_EVENT: &str = "OperatorAddedToQuorums(address,bytes32,bytes)";
+
+/// Emitted when an operator is removed from quorums
+pub const OPERATOR_REMOVED_FROM_QUORUMS_EVENT: &str =
+    "OperatorRemovedFromQuorums(address,bytes32,bytes)";
+
+/// Emitted when an operator deregisters from the registry coordinator
+pub const OPERATOR_DEREGISTERED_EVENT: &str = "OperatorDeregistered(address,bytes32)";
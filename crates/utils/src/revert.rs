@@ -0,0 +1,294 @@
+use crate::binding::ECDSAStakeRegistry;
+use alloy_json_rpc::RpcError;
+use alloy_primitives::{Bytes, FixedBytes, U256};
+use alloy_sol_types::{Panic, Revert, SolError};
+use alloy_transport::TransportError;
+use std::fmt;
+
+/// Error code of the rpc providers for a reverted `eth_call` or `eth_estimateGas`
+const EXECUTION_REVERTED_CODE: i64 = 3;
+
+/// Reason of a contract revert, decoded from the revert data
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RevertReason {
+    /// `Error(string)`, from a `require` or a `revert` with a message
+    Message(String),
+    /// `Panic(uint256)`, from a failed `assert`, an arithmetic overflow, etc.
+    Panic(U256),
+    /// A custom error declared in the ABI of the contract
+    CustomError(&'static str),
+    /// Revert data that doesn't match any error of the contract, e.g. an empty revert
+    Unknown(Bytes),
+}
+
+impl fmt::Display for RevertReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RevertReason::Message(message) => write!(f, "{message}"),
+            RevertReason::Panic(code) => write!(f, "{}", Panic::from(*code)),
+            RevertReason::CustomError(name) => write!(f, "{name}"),
+            RevertReason::Unknown(data) if data.is_empty() => write!(f, "no revert data"),
+            RevertReason::Unknown(data) => write!(f, "unknown error {data}"),
+        }
+    }
+}
+
+/// Revert of a call to one of the contracts of [`crate::binding`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractRevert {
+    /// Name of the reverted contract
+    pub contract: &'static str,
+    /// Selector of the error, `None` if the revert data is shorter than a selector
+    pub selector: Option<FixedBytes<4>>,
+    /// The decoded reason
+    pub reason: RevertReason,
+}
+
+impl fmt::Display for ContractRevert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.selector {
+            Some(selector) => write!(
+                f,
+                "{} reverted with {} ({selector})",
+                self.contract, self.reason
+            ),
+            None => write!(f, "{} reverted with {}", self.contract, self.reason),
+        }
+    }
+}
+
+impl std::error::Error for ContractRevert {}
+
+impl ContractRevert {
+    /// Decode the revert data of a call to `contract`
+    ///
+    /// # Arguments
+    ///
+    /// * `contract` - The name of the contract, as in [`crate::binding`]
+    /// * `data` - The revert data
+    ///
+    /// # Returns
+    ///
+    /// * `ContractRevert` - The revert, with [`RevertReason::Unknown`] if the data is neither an
+    ///   `Error(string)`, a `Panic(uint256)` nor a custom error of the contract
+    pub fn decode(contract: &'static str, data: &[u8]) -> Self {
+        let selector = data
+            .get(..4)
+            .map(|selector| FixedBytes::from_slice(selector));
+        let reason = match selector.map(|selector| selector.0) {
+            Some(Revert::SELECTOR) => Revert::abi_decode(data, true)
+                .map(|revert| RevertReason::Message(revert.reason))
+                .ok(),
+            Some(Panic::SELECTOR) => Panic::abi_decode(data, true)
+                .map(|panic| RevertReason::Panic(panic.code))
+                .ok(),
+            Some(selector) => custom_error_name(contract, selector).map(RevertReason::CustomError),
+            None => None,
+        };
+        Self {
+            contract,
+            selector,
+            reason: reason.unwrap_or_else(|| RevertReason::Unknown(Bytes::copy_from_slice(data))),
+        }
+    }
+
+    /// Decode the revert of a failed call to `contract`
+    ///
+    /// # Arguments
+    ///
+    /// * `contract` - The name of the contract, as in [`crate::binding`]
+    /// * `err` - The error of the call
+    ///
+    /// # Returns
+    ///
+    /// * `Option<ContractRevert>` - The revert, `None` if the call failed for another reason
+    pub fn from_call_error(contract: &'static str, err: &alloy_contract::Error) -> Option<Self> {
+        match err {
+            alloy_contract::Error::TransportError(err) => {
+                revert_data(err).map(|data| Self::decode(contract, &data))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Get the revert data of a failed `eth_call` or `eth_estimateGas`
+///
+/// # Arguments
+///
+/// * `err` - The error returned by the provider
+///
+/// # Returns
+///
+/// * `Option<Bytes>` - The revert data, `None` if the request failed for another reason than a
+///   revert
+pub fn revert_data(err: &TransportError) -> Option<Bytes> {
+    let RpcError::ErrorResp(payload) = err else {
+        return None;
+    };
+    if payload.code != EXECUTION_REVERTED_CODE && !payload.message.contains("revert") {
+        return None;
+    }
+    Some(
+        payload
+            .data
+            .as_ref()
+            .and_then(|data| data.get().trim_matches('"').parse().ok())
+            .unwrap_or_default(),
+    )
+}
+
+/// Find the name of the error with `selector` among the custom errors of a contract
+macro_rules! custom_error_name {
+    ($selector:expr, $contract:ident, [$($error:ident),* $(,)?]) => {
+        [$((<$contract::$error as SolError>::SELECTOR, stringify!($error))),*]
+            .into_iter()
+            .find(|(error_selector, _)| *error_selector == $selector)
+            .map(|(_, name)| name)
+    };
+}
+
+/// Get the name of the custom error of `contract` with `selector`
+fn custom_error_name(contract: &str, selector: [u8; 4]) -> Option<&'static str> {
+    match contract {
+        "ECDSAStakeRegistry" => custom_error_name!(
+            selector,
+            ECDSAStakeRegistry,
+            [
+                InsufficientSignedStake,
+                InsufficientWeight,
+                InvalidLength,
+                InvalidQuorum,
+                InvalidSignature,
+                InvalidSignedWeight,
+                InvalidThreshold,
+                LengthMismatch,
+                MustUpdateAllOperators,
+                NotSorted,
+                OperatorAlreadyRegistered,
+                OperatorNotRegistered,
+            ]
+        ),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_rpc::ErrorPayload;
+
+    fn error_response(code: i64, message: &str, data: Option<&str>) -> TransportError {
+        RpcError::ErrorResp(ErrorPayload {
+            code,
+            message: message.to_string(),
+            data: data
+                .map(|data| serde_json::value::RawValue::from_string(data.to_string()))
+                .transpose()
+                .unwrap(),
+        })
+    }
+
+    #[test]
+    fn test_decode_revert_message() {
+        let reason =
+            "RegistryCoordinator._registerOperator: operator already registered for some quorums";
+        let data = Revert::from(reason).abi_encode();
+
+        let revert = ContractRevert::decode("RegistryCoordinator", &data);
+        assert_eq!(revert.selector, Some(FixedBytes(Revert::SELECTOR)));
+        assert_eq!(revert.reason, RevertReason::Message(reason.to_string()));
+        assert_eq!(
+            revert.to_string(),
+            format!("RegistryCoordinator reverted with {reason} (0x08c379a0)")
+        );
+    }
+
+    #[test]
+    fn test_decode_panic() {
+        let data = Panic::from(U256::from(0x11)).abi_encode();
+
+        let revert = ContractRevert::decode("StakeRegistry", &data);
+        assert_eq!(revert.reason, RevertReason::Panic(U256::from(0x11)));
+        assert_eq!(
+            revert.to_string(),
+            "StakeRegistry reverted with panic: arithmetic underflow or overflow (0x11) (0x4e487b71)"
+        );
+    }
+
+    #[test]
+    fn test_decode_custom_error() {
+        let data = ECDSAStakeRegistry::OperatorAlreadyRegistered {}.abi_encode();
+
+        let revert = ContractRevert::decode("ECDSAStakeRegistry", &data);
+        assert_eq!(
+            revert.reason,
+            RevertReason::CustomError("OperatorAlreadyRegistered")
+        );
+        assert_eq!(
+            revert.selector,
+            Some(FixedBytes(
+                ECDSAStakeRegistry::OperatorAlreadyRegistered::SELECTOR
+            ))
+        );
+
+        // the selector is only known to the contract declaring the error
+        let revert = ContractRevert::decode("RegistryCoordinator", &data);
+        assert_eq!(revert.reason, RevertReason::Unknown(data.into()));
+    }
+
+    #[test]
+    fn test_decode_empty_revert() {
+        let revert = ContractRevert::decode("AVSDirectory", &[]);
+        assert_eq!(revert.selector, None);
+        assert_eq!(revert.reason, RevertReason::Unknown(Bytes::new()));
+        assert_eq!(
+            revert.to_string(),
+            "AVSDirectory reverted with no revert data"
+        );
+    }
+
+    #[test]
+    fn test_from_call_error() {
+        let data = Revert::from("StakeRegistry.quorumExists: quorum does not exist").abi_encode();
+        let err = alloy_contract::Error::TransportError(error_response(
+            3,
+            "execution reverted: StakeRegistry.quorumExists: quorum does not exist",
+            Some(&format!("\"{}\"", Bytes::from(data))),
+        ));
+
+        let revert = ContractRevert::from_call_error("StakeRegistry", &err).unwrap();
+        assert_eq!(
+            revert.reason,
+            RevertReason::Message("StakeRegistry.quorumExists: quorum does not exist".to_string())
+        );
+        assert_eq!(
+            ContractRevert::from_call_error(
+                "StakeRegistry",
+                &alloy_contract::Error::TransportError(RpcError::NullResp)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_revert_data() {
+        assert_eq!(
+            revert_data(&error_response(
+                3,
+                "execution reverted: not registered",
+                Some("\"0x08c379a0\"")
+            )),
+            Some(Bytes::from_static(&[0x08, 0xc3, 0x79, 0xa0]))
+        );
+        assert_eq!(
+            revert_data(&error_response(-32000, "execution reverted", None)),
+            Some(Bytes::new())
+        );
+        assert_eq!(
+            revert_data(&error_response(-32000, "header not found", None)),
+            None
+        );
+        assert_eq!(revert_data(&RpcError::NullResp), None);
+    }
+}
@@ -78,7 +78,7 @@ async fn main() -> Result<()> {
         el_chain_reader,
         "https://ethereum-holesky.blockpi.network/v1/rpc/public".to_string(),
         "bead471191bea97fc3aeac36c9d74c895e8a6242602e144e43152f96219e96e8".to_string(),
-    );
+    )?;
 
     let wallet = PrivateKeySigner::from_str(
         "bead471191bea97fc3aeac36c9d74c895e8a6242602e144e43152f96219e96e8",
@@ -82,12 +82,12 @@ pub async fn register_operator(pvt_key: &str, bls_key: &str) {
     );
     let signer = PrivateKeySigner::from_str(pvt_key).unwrap();
 
-    let el_chain_writer = ELChainWriter::new(
+    let el_chain_writer = ELChainWriter::new_with_signer(
         delegation_manager_address,
         strategy_manager_address,
         el_chain_reader,
         anvil_http_url.to_string(),
-        pvt_key.to_string(),
+        signer.clone(),
     );
 
     let operator_details = Operator::new(
@@ -103,10 +103,10 @@ pub async fn register_operator(pvt_key: &str, bls_key: &str) {
         .await
         .unwrap();
 
-    let avs_registry_writer = AvsRegistryChainWriter::build_avs_registry_chain_writer(
+    let avs_registry_writer = AvsRegistryChainWriter::build_avs_registry_chain_writer_with_signer(
         get_test_logger(),
         anvil_http_url.to_string(),
-        pvt_key.to_string(),
+        signer.clone(),
         get_registry_coordinator_address().await,
         get_operator_state_retriever_address().await,
     )
@@ -16,6 +16,10 @@ pub mod m2_holesky_constants;
 #[allow(clippy::unwrap_used)]
 pub mod anvil_constants;
 
+/// Multicall3 deployment on anvil
+#[allow(clippy::unwrap_used)]
+pub mod multicall;
+
 /// Test data read from JSON files, used for compliance testing.
 pub mod test_data;
 